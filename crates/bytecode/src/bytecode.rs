@@ -191,11 +191,27 @@ impl Bytecode {
     pub fn is_empty(&self) -> bool {
         self.len() == 0
     }
+
+    /// Recomputes the analysis of the original bytes and checks that it is consistent
+    /// with the cached one.
+    ///
+    /// For legacy bytecode this checks the jump table and padding, EOF and EIP-7702 bytecode
+    /// is decoded again. As this is expensive, it is meant for debug assertions.
+    pub fn verify_analysis(&self) -> bool {
+        match self {
+            Self::LegacyAnalyzed(analyzed) => analyzed.verify_analysis(),
+            Self::Eof(eof) => Eof::decode(eof.raw().clone()).is_ok_and(|decoded| decoded == **eof),
+            Self::Eip7702(eip7702) => Eip7702Bytecode::new_raw(eip7702.raw().clone())
+                .is_ok_and(|decoded| decoded == *eip7702),
+        }
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::{Bytecode, Eof};
+    use crate::{opcode, JumpTable};
+    use primitives::Bytes;
     use std::sync::Arc;
 
     #[test]
@@ -215,4 +231,33 @@ mod tests {
             panic!("Original bytecode is not Eof");
         }
     }
+
+    #[test]
+    fn verify_analysis_detects_corruption() {
+        let code = Bytes::from(vec![
+            opcode::PUSH1,
+            opcode::JUMPDEST,
+            opcode::JUMPDEST,
+            opcode::STOP,
+        ]);
+        let bytecode = Bytecode::new_legacy(code.clone());
+        assert!(bytecode.verify_analysis());
+
+        // Marks the `PUSH1` immediate as a valid jump destination.
+        let mut padded = code.to_vec();
+        padded.resize(code.len() + 33, 0);
+        let corrupted = unsafe {
+            Bytecode::new_analyzed(padded.into(), code.len(), JumpTable::from_slice(&[0b0110]))
+        };
+        assert!(!corrupted.verify_analysis());
+
+        let fixed = unsafe {
+            Bytecode::new_analyzed(
+                bytecode.bytecode().clone(),
+                code.len(),
+                JumpTable::from_slice(&[0b0100]),
+            )
+        };
+        assert!(fixed.verify_analysis());
+    }
 }
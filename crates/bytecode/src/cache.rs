@@ -0,0 +1,159 @@
+use crate::{Bytecode, BytecodeDecodeError};
+use primitives::{Bytes, HashMap, B256};
+
+/// Least recently used cache of analyzed [`Bytecode`] keyed by code hash.
+///
+/// Loading raw code with [`Bytecode::new_raw`] analyzes it every time. Databases that
+/// fetch raw code can use this cache so that hot contracts are analyzed only once.
+#[derive(Clone, Debug)]
+pub struct BytecodeCache {
+    /// Maximum number of cached entries.
+    capacity: usize,
+    /// Cached bytecode with the tick of its last use.
+    entries: HashMap<B256, (Bytecode, u64)>,
+    /// Monotonic counter used to track recency.
+    tick: u64,
+    /// Number of analyses performed on cache misses.
+    analyses: u64,
+}
+
+impl Default for BytecodeCache {
+    fn default() -> Self {
+        Self::new(Self::DEFAULT_CAPACITY)
+    }
+}
+
+impl BytecodeCache {
+    /// Default maximum number of cached entries.
+    pub const DEFAULT_CAPACITY: usize = 1024;
+
+    /// Creates a new cache holding at most `capacity` entries.
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            capacity,
+            entries: HashMap::default(),
+            tick: 0,
+            analyses: 0,
+        }
+    }
+
+    /// Returns the maximum number of cached entries.
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    /// Returns the number of cached entries.
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Returns `true` if the cache is empty.
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Returns the number of analyses performed on cache misses.
+    pub fn analyses(&self) -> u64 {
+        self.analyses
+    }
+
+    /// Returns the cached bytecode for `code_hash` and marks it as recently used.
+    pub fn get(&mut self, code_hash: &B256) -> Option<Bytecode> {
+        self.tick += 1;
+        let (bytecode, last_used) = self.entries.get_mut(code_hash)?;
+        *last_used = self.tick;
+        Some(bytecode.clone())
+    }
+
+    /// Returns the cached bytecode for `code_hash`, or analyzes `raw` and caches it.
+    ///
+    /// `code_hash` is trusted to be the hash of `raw`.
+    pub fn get_or_analyze(
+        &mut self,
+        code_hash: B256,
+        raw: Bytes,
+    ) -> Result<Bytecode, BytecodeDecodeError> {
+        if let Some(bytecode) = self.get(&code_hash) {
+            return Ok(bytecode);
+        }
+        let bytecode = Bytecode::new_raw_checked(raw)?;
+        self.analyses += 1;
+        self.insert(code_hash, bytecode.clone());
+        Ok(bytecode)
+    }
+
+    /// Inserts analyzed bytecode, evicting the least recently used entry if the cache is full.
+    pub fn insert(&mut self, code_hash: B256, bytecode: Bytecode) {
+        if self.capacity == 0 {
+            return;
+        }
+        if !self.entries.contains_key(&code_hash) && self.entries.len() >= self.capacity {
+            let lru = self
+                .entries
+                .iter()
+                .min_by_key(|(_, (_, last_used))| *last_used)
+                .map(|(hash, _)| *hash);
+            if let Some(lru) = lru {
+                self.entries.remove(&lru);
+            }
+        }
+        self.tick += 1;
+        self.entries.insert(code_hash, (bytecode, self.tick));
+    }
+
+    /// Removes all cached entries.
+    pub fn clear(&mut self) {
+        self.entries.clear();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::opcode;
+    use primitives::keccak256;
+
+    fn code(byte: u8) -> (B256, Bytes) {
+        let raw = Bytes::from(std::vec![
+            opcode::PUSH1,
+            byte,
+            opcode::JUMPDEST,
+            opcode::STOP
+        ]);
+        (keccak256(&raw), raw)
+    }
+
+    #[test]
+    fn analyzes_once() {
+        let mut cache = BytecodeCache::new(4);
+        let (hash, raw) = code(1);
+
+        let first = cache.get_or_analyze(hash, raw.clone()).unwrap();
+        for _ in 0..10 {
+            assert_eq!(cache.get_or_analyze(hash, raw.clone()).unwrap(), first);
+        }
+        assert_eq!(cache.analyses(), 1);
+        assert_eq!(cache.len(), 1);
+    }
+
+    #[test]
+    fn evicts_least_recently_used() {
+        let mut cache = BytecodeCache::new(2);
+        let (hash_a, raw_a) = code(1);
+        let (hash_b, raw_b) = code(2);
+        let (hash_c, raw_c) = code(3);
+
+        cache.get_or_analyze(hash_a, raw_a.clone()).unwrap();
+        cache.get_or_analyze(hash_b, raw_b.clone()).unwrap();
+        // Touch `a` so that `b` becomes least recently used.
+        assert!(cache.get(&hash_a).is_some());
+        cache.get_or_analyze(hash_c, raw_c).unwrap();
+
+        assert_eq!(cache.len(), 2);
+        assert!(cache.get(&hash_b).is_none());
+        cache.get_or_analyze(hash_a, raw_a).unwrap();
+        assert_eq!(cache.analyses(), 3);
+        cache.get_or_analyze(hash_b, raw_b).unwrap();
+        assert_eq!(cache.analyses(), 4);
+    }
+}
@@ -1,7 +1,9 @@
 mod analyzed;
+mod builder;
 mod jump_map;
 mod raw;
 
 pub use analyzed::LegacyAnalyzedBytecode;
+pub use builder::BytecodeBuilder;
 pub use jump_map::JumpTable;
 pub use raw::{analyze_legacy, LegacyRawBytecode};
@@ -1,4 +1,4 @@
-use super::JumpTable;
+use super::{analyze_legacy, JumpTable};
 use bitvec::{bitvec, order::Lsb0};
 use primitives::Bytes;
 use std::sync::Arc;
@@ -62,4 +62,23 @@ impl LegacyAnalyzedBytecode {
     pub fn jump_table(&self) -> &JumpTable {
         &self.jump_table
     }
+
+    /// Recomputes the analysis of the original bytes and checks that it matches the cached one.
+    ///
+    /// Checks the jump table and that the bytecode is padded with zeros. This reanalyzes the
+    /// whole bytecode, so it is meant for debug assertions.
+    pub fn verify_analysis(&self) -> bool {
+        let Some(padding) = self.bytecode.get(self.original_len..) else {
+            return false;
+        };
+        if padding.is_empty() || padding.iter().any(|byte| *byte != 0) {
+            return false;
+        }
+
+        let expected = analyze_legacy(self.original_byte_slice());
+        let cached = &self.jump_table.0;
+        cached.len() >= expected.0.len()
+            && cached[..expected.0.len()] == expected.0[..]
+            && cached[expected.0.len()..].not_any()
+    }
 }
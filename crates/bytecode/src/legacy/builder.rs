@@ -0,0 +1,170 @@
+use crate::{opcode::OpCode, Bytecode};
+use primitives::Bytes;
+use std::vec::Vec;
+
+/// Assembles legacy bytecode from opcodes and their immediates.
+///
+/// Mostly useful to write test fixtures without hand encoding hex.
+///
+/// # Examples
+///
+/// ```
+/// use revm_bytecode::{opcode::OpCode, BytecodeBuilder};
+///
+/// let code = BytecodeBuilder::new()
+///     .push_with_data(OpCode::PUSH1, &[0x01])
+///     .push_with_data(OpCode::PUSH1, &[0x02])
+///     .push(OpCode::ADD)
+///     .push(OpCode::STOP)
+///     .into_bytes();
+/// assert_eq!(&code[..], &[0x60, 0x01, 0x60, 0x02, 0x01, 0x00]);
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct BytecodeBuilder {
+    code: Vec<u8>,
+}
+
+impl BytecodeBuilder {
+    /// Creates an empty builder.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the offset of the next instruction, e.g. to use as a jump destination.
+    pub fn pc(&self) -> usize {
+        self.code.len()
+    }
+
+    /// Appends an opcode without immediates.
+    ///
+    /// `PUSH` opcodes are appended with a zero immediate.
+    ///
+    /// # Panics
+    ///
+    /// Panics if any other opcode takes immediates, use [`BytecodeBuilder::push_with_data`] instead.
+    pub fn push(self, op: OpCode) -> Self {
+        self.push_with_data(op, &[])
+    }
+
+    /// Appends an opcode followed by its immediate.
+    ///
+    /// `PUSH` immediates shorter than the opcode size are left padded with zeros.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `data` is longer than the opcode immediate.
+    pub fn push_with_data(mut self, op: OpCode, data: &[u8]) -> Self {
+        let size = op.info().immediate_size() as usize;
+        assert!(
+            data.len() <= size,
+            "{op} takes {size} immediate bytes, got {}",
+            data.len()
+        );
+        // Only `PUSH` immediates are numbers, other immediates are expected at full size.
+        assert!(
+            op.is_push() || data.len() == size,
+            "{op} takes {size} immediate bytes, got {}",
+            data.len()
+        );
+        self.code.push(op.get());
+        self.code.resize(self.code.len() + size - data.len(), 0);
+        self.code.extend_from_slice(data);
+        self
+    }
+
+    /// Appends the smallest `PUSH` instruction pushing the big-endian `value`.
+    ///
+    /// Leading zeros are stripped, a zero value is pushed with `PUSH0`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `value` does not fit in 32 bytes.
+    pub fn push_value(self, value: &[u8]) -> Self {
+        let start = value.iter().position(|b| *b != 0).unwrap_or(value.len());
+        let value = &value[start..];
+        if value.is_empty() {
+            return self.push(OpCode::PUSH0);
+        }
+        assert!(value.len() <= 32, "value does not fit in 32 bytes");
+        let op = OpCode::new(OpCode::PUSH1.get() + value.len() as u8 - 1).unwrap();
+        self.push_with_data(op, value)
+    }
+
+    /// Returns the assembled bytes.
+    pub fn into_bytes(self) -> Bytes {
+        self.code.into()
+    }
+
+    /// Returns the assembled and analyzed legacy [`Bytecode`].
+    pub fn build(self) -> Bytecode {
+        Bytecode::new_legacy(self.into_bytes())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::opcode;
+
+    #[test]
+    fn assemble_add() {
+        let bytecode = BytecodeBuilder::new()
+            .push_with_data(OpCode::PUSH1, &[0x01])
+            .push_with_data(OpCode::PUSH1, &[0x02])
+            .push(OpCode::ADD)
+            .push(OpCode::STOP)
+            .build();
+        assert_eq!(
+            bytecode.original_byte_slice(),
+            &[
+                opcode::PUSH1,
+                0x01,
+                opcode::PUSH1,
+                0x02,
+                opcode::ADD,
+                opcode::STOP
+            ]
+        );
+    }
+
+    #[test]
+    fn push_sizes_and_offsets() {
+        let builder = BytecodeBuilder::new()
+            .push_value(&[0x00, 0x00])
+            .push_value(&[0x00, 0x01, 0x02])
+            .push_with_data(OpCode::PUSH4, &[0xff]);
+        let dest = builder.pc();
+        assert_eq!(dest, 9);
+        let code = builder
+            .push(OpCode::JUMPDEST)
+            .push_value(&[dest as u8])
+            .push(OpCode::JUMP)
+            .build();
+
+        assert_eq!(
+            code.original_byte_slice(),
+            &[
+                opcode::PUSH0,
+                opcode::PUSH2,
+                0x01,
+                0x02,
+                opcode::PUSH4,
+                0x00,
+                0x00,
+                0x00,
+                0xff,
+                opcode::JUMPDEST,
+                opcode::PUSH1,
+                0x09,
+                opcode::JUMP
+            ]
+        );
+        assert!(code.legacy_jump_table().unwrap().is_valid(dest));
+    }
+
+    #[test]
+    #[should_panic]
+    fn oversized_immediate() {
+        let _ = BytecodeBuilder::new().push_with_data(OpCode::PUSH1, &[0x01, 0x02]);
+    }
+}
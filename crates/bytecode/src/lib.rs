@@ -6,6 +6,7 @@
 extern crate alloc as std;
 
 pub mod bytecode;
+pub mod cache;
 pub mod decode_errors;
 pub mod eip7702;
 pub mod eof;
@@ -15,6 +16,7 @@ pub mod utils;
 
 pub use bitvec;
 pub use bytecode::Bytecode;
+pub use cache::BytecodeCache;
 pub use decode_errors::BytecodeDecodeError;
 pub use eof::{
     verification::{
@@ -23,4 +25,4 @@ pub use eof::{
     },
     Eof, EOF_MAGIC, EOF_MAGIC_BYTES, EOF_MAGIC_HASH,
 };
-pub use legacy::{JumpTable, LegacyAnalyzedBytecode, LegacyRawBytecode};
+pub use legacy::{BytecodeBuilder, JumpTable, LegacyAnalyzedBytecode, LegacyRawBytecode};
@@ -1,6 +1,6 @@
 pub mod blob;
 
-pub use blob::{calc_blob_gasprice, calc_excess_blob_gas, BlobExcessGasAndPrice};
+pub use blob::{calc_blob_gasprice, calc_excess_blob_gas, BlobExcessGasAndPrice, BlobParams};
 
 use auto_impl::auto_impl;
 use primitives::{Address, B256, U256};
@@ -51,6 +51,13 @@ pub trait Block {
     /// [EIP-4844]: https://eips.ethereum.org/EIPS/eip-4844
     fn blob_excess_gas_and_price(&self) -> Option<BlobExcessGasAndPrice>;
 
+    /// The root of the parent beacon block.
+    ///
+    /// Incorporated as part of the Cancun upgrade via [EIP-4788].
+    ///
+    /// [EIP-4788]: https://eips.ethereum.org/EIPS/eip-4788
+    fn parent_beacon_block_root(&self) -> Option<B256>;
+
     /// See [EIP-4844] and [`calc_blob_gasprice`].
     ///
     /// Returns `None` if `Cancun` is not enabled.
@@ -1,7 +1,63 @@
 use specification::eip4844::{
-    BLOB_GASPRICE_UPDATE_FRACTION, MIN_BLOB_GASPRICE, TARGET_BLOB_GAS_PER_BLOCK,
+    BLOB_GASPRICE_UPDATE_FRACTION, GAS_PER_BLOB, MAX_BLOB_GAS_PER_BLOCK, MIN_BLOB_GASPRICE,
+    TARGET_BLOB_GAS_PER_BLOCK,
 };
 
+/// Blob gas schedule used for blob fee computation and blob count validation.
+///
+/// Defaults to the mainnet values of [EIP-4844].
+///
+/// [EIP-4844]: https://eips.ethereum.org/EIPS/eip-4844
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct BlobParams {
+    /// Target consumable blob gas per block
+    pub target_blob_gas_per_block: u64,
+    /// Maximum consumable blob gas per block
+    pub max_blob_gas_per_block: u64,
+    /// Controls the maximum rate of change for blob gas price
+    pub update_fraction: u64,
+}
+
+impl Default for BlobParams {
+    fn default() -> Self {
+        Self {
+            target_blob_gas_per_block: TARGET_BLOB_GAS_PER_BLOCK,
+            max_blob_gas_per_block: MAX_BLOB_GAS_PER_BLOCK,
+            update_fraction: BLOB_GASPRICE_UPDATE_FRACTION,
+        }
+    }
+}
+
+impl BlobParams {
+    /// Returns the maximum number of blobs per block.
+    #[inline]
+    pub const fn max_blob_count(&self) -> u64 {
+        self.max_blob_gas_per_block / GAS_PER_BLOB
+    }
+
+    /// Calculates the `excess_blob_gas` from the parent header's `blob_gas_used` and `excess_blob_gas`.
+    ///
+    /// See [`calc_excess_blob_gas`].
+    #[inline]
+    pub fn calc_excess_blob_gas(
+        &self,
+        parent_excess_blob_gas: u64,
+        parent_blob_gas_used: u64,
+    ) -> u64 {
+        (parent_excess_blob_gas + parent_blob_gas_used)
+            .saturating_sub(self.target_blob_gas_per_block)
+    }
+
+    /// Calculates the blob gas price from the header's excess blob gas field.
+    ///
+    /// See [`calc_blob_gasprice`].
+    #[inline]
+    pub fn calc_blob_gasprice(&self, excess_blob_gas: u64) -> u128 {
+        fake_exponential(MIN_BLOB_GASPRICE, excess_blob_gas, self.update_fraction)
+    }
+}
+
 /// Structure holding block blob excess gas and it calculates blob fee
 ///
 /// Incorporated as part of the Cancun upgrade via [EIP-4844].
@@ -27,6 +83,15 @@ impl BlobExcessGasAndPrice {
             blob_gasprice,
         }
     }
+
+    /// Creates a new instance by calculating the blob gas price with the given [`BlobParams`].
+    pub fn new_with_params(excess_blob_gas: u64, params: &BlobParams) -> Self {
+        let blob_gasprice = params.calc_blob_gasprice(excess_blob_gas);
+        Self {
+            excess_blob_gas,
+            blob_gasprice,
+        }
+    }
 }
 
 /// Calculates the `excess_blob_gas` from the parent header's `blob_gas_used` and `excess_blob_gas`.
@@ -189,4 +254,30 @@ mod tests {
             assert_eq!(actual, expected, "test: {t:?}");
         }
     }
+
+    #[test]
+    fn custom_blob_params() {
+        let params = BlobParams {
+            target_blob_gas_per_block: GAS_PER_BLOB,
+            max_blob_gas_per_block: 2 * GAS_PER_BLOB,
+            update_fraction: 2,
+        };
+        assert_eq!(params.max_blob_count(), 2);
+        assert_eq!(
+            params.calc_excess_blob_gas(0, 2 * GAS_PER_BLOB),
+            GAS_PER_BLOB
+        );
+        assert_eq!(params.calc_blob_gasprice(5), 11);
+        assert_eq!(
+            BlobExcessGasAndPrice::new_with_params(5, &params).blob_gasprice,
+            11
+        );
+
+        let mainnet = BlobParams::default();
+        assert_eq!(mainnet.max_blob_count(), 6);
+        assert_eq!(
+            mainnet.calc_blob_gasprice(50000000),
+            calc_blob_gasprice(50000000)
+        );
+    }
 }
@@ -1,7 +1,8 @@
+use crate::block::BlobParams;
 use auto_impl::auto_impl;
 use core::fmt::Debug;
 use core::hash::Hash;
-use primitives::{TxKind, U256};
+use primitives::{Address, TxKind, U256};
 use specification::hardfork::SpecId;
 
 #[auto_impl(&, &mut, Box, Arc)]
@@ -26,6 +27,71 @@ pub trait Cfg {
     fn is_nonce_check_disabled(&self) -> bool;
 
     fn is_base_fee_check_disabled(&self) -> bool;
+
+    fn is_gas_metering_disabled(&self) -> bool;
+
+    fn is_call_gas_retention_disabled(&self) -> bool;
+
+    /// Whether every account and storage access is priced as cold, as if never warmed.
+    fn is_cold_access_forced(&self) -> bool;
+
+    /// Whether state changes of reverted or halted frames are kept. Not consensus compliant.
+    fn is_state_kept_on_revert(&self) -> bool;
+
+    /// Whether touched empty accounts are kept in the committed state instead of being
+    /// removed by EIP-161. Not consensus compliant.
+    fn is_empty_account_retained(&self) -> bool;
+
+    /// Blob gas schedule of the chain. The blob gas price of the block is validated against it.
+    fn blob_params(&self) -> BlobParams;
+
+    /// Caller of the system calls made at block boundaries.
+    fn system_caller(&self) -> Address;
+
+    /// Gas limit of the system calls made at block boundaries.
+    fn system_call_gas_limit(&self) -> u64;
+
+    /// Quotient of the gas spent that caps the final gas refund.
+    ///
+    /// Refunds are limited to `gas_spent / max_refund_quotient`, a quotient of zero disables them.
+    fn max_refund_quotient(&self) -> u64;
+
+    /// Base cost of `CALL`, `CALLCODE`, `DELEGATECALL`, `STATICCALL` and the EOF call opcodes.
+    ///
+    /// Account access surcharges, value transfer and new account costs are added on top of it.
+    fn call_base_cost(&self) -> u64;
+
+    /// Base cost of `CREATE` and `CREATE2`.
+    ///
+    /// Init code and hashing costs are added on top of it.
+    fn create_base_cost(&self) -> u64;
+
+    /// Gas charged per word by the identity precompile, `None` to follow the spec.
+    fn identity_per_word_gas(&self) -> Option<u64>;
+
+    /// Base gas of the bn254 pairing precompile, `None` to follow the spec.
+    fn bn254_pair_base_gas(&self) -> Option<u64>;
+
+    /// Gas per pairing of the bn254 pairing precompile, `None` to follow the spec.
+    fn bn254_pair_per_point_gas(&self) -> Option<u64>;
+
+    /// Maximum input size of the precompile at `address`, `None` if unbounded.
+    ///
+    /// Calls with a larger input fail with a precompile error without running the precompile.
+    fn precompile_max_input_size(&self, address: &Address) -> Option<usize>;
+
+    /// Maximum size of the transaction input, `None` if unbounded.
+    fn max_calldata_size(&self) -> Option<usize>;
+
+    /// Maximum number of logs a transaction can emit, `None` if unbounded.
+    ///
+    /// A `LOG*` opcode that would exceed it halts the frame.
+    fn max_logs_per_tx(&self) -> Option<usize>;
+
+    /// Nonce used to derive the address of `CREATE` instead of the caller nonce.
+    ///
+    /// The caller nonce is still incremented.
+    fn create_nonce_override(&self) -> Option<u64>;
 }
 
 /// What bytecode analysis to perform
@@ -55,6 +121,51 @@ pub enum CreateScheme {
     },
 }
 
+impl CreateScheme {
+    /// Returns the salt of `CREATE2`, or `None` for `CREATE`.
+    pub fn salt(&self) -> Option<U256> {
+        match self {
+            Self::Create => None,
+            Self::Create2 { salt } => Some(*salt),
+        }
+    }
+}
+
+/// Call scheme.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum CallScheme {
+    /// `CALL`.
+    Call,
+    /// `CALLCODE`
+    CallCode,
+    /// `DELEGATECALL`
+    DelegateCall,
+    /// `STATICCALL`
+    StaticCall,
+    /// `EXTCALL`
+    ExtCall,
+    /// `EXTSTATICCALL`
+    ExtStaticCall,
+    /// `EXTDELEGATECALL`
+    ExtDelegateCall,
+}
+
+impl CallScheme {
+    /// Returns true if it is EOF EXT*CALL.
+    pub fn is_ext(&self) -> bool {
+        matches!(
+            self,
+            Self::ExtCall | Self::ExtStaticCall | Self::ExtDelegateCall
+        )
+    }
+
+    /// Returns true if it is ExtDelegateCall.
+    pub fn is_ext_delegate_call(&self) -> bool {
+        matches!(self, Self::ExtDelegateCall)
+    }
+}
+
 #[auto_impl(&, &mut, Box, Arc)]
 pub trait CfgGetter {
     type Cfg: Cfg;
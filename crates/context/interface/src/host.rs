@@ -5,7 +5,8 @@ pub use dummy::DummyHost;
 
 use crate::{
     journaled_state::{AccountLoad, Eip7702CodeLoad},
-    BlockGetter, CfgGetter, TransactionGetter,
+    transaction::CommonTxFields,
+    Block, BlockGetter, CallScheme, CfgGetter, Transaction, TransactionGetter,
 };
 use auto_impl::auto_impl;
 use primitives::{Address, Bytes, Log, B256, U256};
@@ -20,6 +21,9 @@ pub trait Host: TransactionGetter + BlockGetter + CfgGetter {
     /// Gets the block hash of the given block `number`.
     fn block_hash(&mut self, number: u64) -> Option<B256>;
 
+    /// Gets the `PREVRANDAO` value used by the current execution.
+    fn prevrandao(&mut self) -> Option<B256>;
+
     /// Gets balance of `address` and if the account is cold.
     fn balance(&mut self, address: Address) -> Option<StateLoad<U256>>;
 
@@ -51,12 +55,114 @@ pub trait Host: TransactionGetter + BlockGetter + CfgGetter {
     /// Emits a log owned by `address` with given `LogData`.
     fn log(&mut self, log: Log);
 
+    /// Returns the number of logs emitted so far in the transaction.
+    fn log_count(&self) -> usize;
+
     /// Marks `address` to be deleted, with funds transferred to `target`.
     fn selfdestruct(
         &mut self,
         address: Address,
         target: Address,
     ) -> Option<StateLoad<SelfDestructResult>>;
+
+    /// Returns the origin of the current transaction.
+    fn tx_origin(&self) -> Address {
+        self.tx().common_fields().caller()
+    }
+
+    /// Returns the effective gas price of the current transaction at the block base fee.
+    fn effective_gas_price(&self) -> u128 {
+        self.tx()
+            .effective_gas_price(self.block().basefee() as u128)
+    }
+
+    /// Returns the balance of the executing account `address`, as read by `SELFBALANCE`.
+    ///
+    /// The executing account is always loaded and warm, so no access cost applies. This
+    /// is unaffected by [`Cfg::is_cold_access_forced`](crate::Cfg::is_cold_access_forced).
+    fn self_balance(&mut self, address: Address) -> Option<U256> {
+        self.balance(address).map(|load| load.data)
+    }
+
+    /// Pushes a synthetic `log` to the journal as if the executing contract emitted it.
+    ///
+    /// Meant for inspectors, e.g. from their `step` hook. The log is ordered after the logs
+    /// emitted so far and is discarded if the current frame reverts.
+    fn push_log(&mut self, log: Log) {
+        self.log(log)
+    }
+
+    /// Intercepts a call to `address`, which has no code.
+    ///
+    /// Returns `None` to execute it as a call to an empty account, which succeeds with empty
+    /// output and spends no gas. Custom hosts can return a result instead, e.g. to serve
+    /// virtual precompiles.
+    fn call_empty_account(
+        &mut self,
+        _address: Address,
+        _input: &Bytes,
+        _gas_limit: u64,
+    ) -> Option<EmptyAccountCallResult> {
+        None
+    }
+
+    /// Intercepts a call to the zero address, with `value` transferred from `caller`.
+    ///
+    /// Returns `None` to execute it as a call to any other account. Otherwise the result is used
+    /// instead, whether the zero address has code or not.
+    fn call_zero_address(
+        &mut self,
+        _caller: Address,
+        _value: U256,
+        _input: &Bytes,
+        _gas_limit: u64,
+    ) -> Option<EmptyAccountCallResult> {
+        None
+    }
+
+    /// Returns the frames being executed, from the first frame of the transaction to the
+    /// current one.
+    ///
+    /// Only frames running bytecode are included, calls to precompiles and accounts without
+    /// code are not.
+    fn call_stack(&self) -> &[FrameInfo] {
+        &[]
+    }
+
+    /// Pushes `frame` on the call stack when it starts executing at `depth`.
+    ///
+    /// Frames at `depth` or deeper are dropped first, which clears the stack left over by a
+    /// transaction that failed mid-execution.
+    fn push_call_frame(&mut self, _depth: usize, _frame: FrameInfo) {}
+
+    /// Pops the current frame from the call stack when it finishes executing.
+    fn pop_call_frame(&mut self) {}
+}
+
+/// Frame on the call stack, see [`Host::call_stack`].
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct FrameInfo {
+    /// Address of the executing account, or of the created account for creates.
+    pub address: Address,
+    /// Caller of the frame.
+    pub caller: Address,
+    /// Scheme of the call, `None` for creates.
+    pub scheme: Option<CallScheme>,
+    /// Value of the frame, apparent for `DELEGATECALL`.
+    pub value: U256,
+}
+
+/// Result of a call intercepted by [`Host::call_empty_account`].
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct EmptyAccountCallResult {
+    /// Whether the call succeeded. State changes of the call are reverted otherwise.
+    pub success: bool,
+    /// Gas spent by the call. The call runs out of gas if it exceeds the gas limit.
+    pub gas_used: u64,
+    /// Output of the call.
+    pub output: Bytes,
 }
 
 /// Represents the result of an `sstore` operation.
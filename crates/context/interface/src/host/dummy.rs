@@ -83,6 +83,11 @@ impl<TX: Transaction, BLOCK: Block, CFG: Cfg> Host for DummyHost<BLOCK, TX, CFG>
         Some(B256::ZERO)
     }
 
+    #[inline]
+    fn prevrandao(&mut self) -> Option<B256> {
+        self.block.prevrandao()
+    }
+
     #[inline]
     fn balance(&mut self, _address: Address) -> Option<StateLoad<U256>> {
         Some(Default::default())
@@ -145,6 +150,11 @@ impl<TX: Transaction, BLOCK: Block, CFG: Cfg> Host for DummyHost<BLOCK, TX, CFG>
         self.log.push(log)
     }
 
+    #[inline]
+    fn log_count(&self) -> usize {
+        self.log.len()
+    }
+
     #[inline]
     fn selfdestruct(
         &mut self,
@@ -48,6 +48,9 @@ pub trait Journal {
     /// Logs the log in Journal state.
     fn log(&mut self, log: Log);
 
+    /// Returns the logs emitted so far in the transaction.
+    fn logs(&self) -> &[Log];
+
     /// Marks the account for selfdestruction and transfers all the balance to the target.
     fn selfdestruct(
         &mut self,
@@ -10,12 +10,16 @@ pub mod cfg;
 pub mod errors;
 pub mod host;
 pub mod journaled_state;
+#[cfg(feature = "receipt")]
+pub mod receipt;
 pub mod result;
 pub mod transaction;
 
 pub use block::{Block, BlockGetter};
-pub use cfg::{Cfg, CfgGetter, CreateScheme, TransactTo};
+pub use cfg::{CallScheme, Cfg, CfgGetter, CreateScheme, TransactTo};
 pub use database_interface::{DBErrorMarker, Database, DatabaseGetter};
 pub use errors::ErrorGetter;
 pub use journaled_state::{Journal, JournalDBError, JournalGetter};
+#[cfg(feature = "receipt")]
+pub use receipt::ExecutionReceipt;
 pub use transaction::{Transaction, TransactionGetter, TransactionType};
@@ -0,0 +1,220 @@
+//! Receipt-like view of an [`ExecutionResult`].
+use crate::result::{ExecutionResult, HaltReasonTrait, Output};
+use alloy_rlp::{BufMut, Encodable, Header};
+use primitives::{logs_bloom, Address, Bloom, Bytes, Log};
+use std::vec::Vec;
+
+/// Flat summary of an [`ExecutionResult`] with the fields usually found in a receipt.
+///
+/// Meant as an intermediate type when converting results into types of other crates.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ExecutionReceipt {
+    /// Whether the execution succeeded.
+    ///
+    /// <https://eips.ethereum.org/EIPS/eip-658>
+    pub status: bool,
+    /// Gas used by the execution.
+    pub gas_used: u64,
+    /// Gas used by this and all previous transactions in the block.
+    ///
+    /// Equal to `gas_used` for a receipt converted from a single result.
+    pub cumulative_gas_used: u64,
+    /// Logs emitted by the execution, empty if it failed.
+    pub logs: Vec<Log>,
+    /// Bloom filter over the logs.
+    pub logs_bloom: Bloom,
+    /// Output data, empty if the execution halted.
+    pub output: Bytes,
+    /// Address of the created contract, if any.
+    pub contract_address: Option<Address>,
+}
+
+impl ExecutionReceipt {
+    /// Appends the canonical encoding of the receipt to `out`.
+    ///
+    /// `tx_type` is the EIP-2718 type of the transaction. Receipts of typed transactions are
+    /// prefixed with it, while legacy receipts (type `0`) are plain RLP.
+    pub fn encode_2718(&self, tx_type: u8, out: &mut Vec<u8>) {
+        if tx_type != 0 {
+            out.push(tx_type);
+        }
+        self.encode(out);
+    }
+
+    /// Returns the canonical encoding of the receipt, see [`ExecutionReceipt::encode_2718`].
+    pub fn encoded_2718(&self, tx_type: u8) -> Vec<u8> {
+        let mut out = Vec::new();
+        self.encode_2718(tx_type, &mut out);
+        out
+    }
+
+    fn rlp_payload_length(&self) -> usize {
+        self.status.length()
+            + self.cumulative_gas_used.length()
+            + self.logs_bloom.length()
+            + self.logs.length()
+    }
+}
+
+/// Consensus encoding of the receipt, which only covers the status, the cumulative gas used,
+/// the bloom and the logs.
+impl Encodable for ExecutionReceipt {
+    fn encode(&self, out: &mut dyn BufMut) {
+        Header {
+            list: true,
+            payload_length: self.rlp_payload_length(),
+        }
+        .encode(out);
+        // EIP-658 status, encoded as the integer `1` or `0`.
+        self.status.encode(out);
+        self.cumulative_gas_used.encode(out);
+        self.logs_bloom.encode(out);
+        self.logs.encode(out);
+    }
+
+    fn length(&self) -> usize {
+        let payload_length = self.rlp_payload_length();
+        payload_length + alloy_rlp::length_of_length(payload_length)
+    }
+}
+
+impl<HaltReasonT: HaltReasonTrait> From<ExecutionResult<HaltReasonT>> for ExecutionReceipt {
+    fn from(result: ExecutionResult<HaltReasonT>) -> Self {
+        match result {
+            ExecutionResult::Success {
+                gas_used,
+                logs,
+                output,
+                ..
+            } => {
+                let (output, contract_address) = match output {
+                    Output::Call(data) => (data, None),
+                    Output::Create(data, address) => (data, address),
+                };
+                Self {
+                    status: true,
+                    gas_used,
+                    cumulative_gas_used: gas_used,
+                    logs_bloom: logs_bloom(&logs),
+                    logs,
+                    output,
+                    contract_address,
+                }
+            }
+            ExecutionResult::Revert { gas_used, output } => Self {
+                gas_used,
+                cumulative_gas_used: gas_used,
+                output,
+                ..Default::default()
+            },
+            ExecutionResult::Halt { gas_used, .. } => Self {
+                gas_used,
+                cumulative_gas_used: gas_used,
+                ..Default::default()
+            },
+        }
+    }
+}
+
+impl<HaltReasonT: HaltReasonTrait> From<&ExecutionResult<HaltReasonT>> for ExecutionReceipt {
+    fn from(result: &ExecutionResult<HaltReasonT>) -> Self {
+        result.clone().into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::result::{HaltReason, SuccessReason};
+    use primitives::{bytes, hex, LogData, B256};
+
+    #[test]
+    fn successful_create() {
+        let address = Address::with_last_byte(0xaa);
+        let log = Log {
+            address,
+            data: LogData::new_unchecked(vec![B256::with_last_byte(1)], Bytes::new()),
+        };
+        let result = ExecutionResult::<HaltReason>::Success {
+            reason: SuccessReason::Return,
+            gas_used: 53_000,
+            gas_refunded: 0,
+            logs: vec![log.clone()],
+            log_depths: vec![1],
+            output: Output::Create(bytes!("6000"), Some(address)),
+        };
+
+        assert_eq!(
+            ExecutionReceipt::from(&result),
+            ExecutionReceipt {
+                status: true,
+                gas_used: 53_000,
+                cumulative_gas_used: 53_000,
+                logs_bloom: logs_bloom(core::slice::from_ref(&log)),
+                logs: vec![log],
+                output: bytes!("6000"),
+                contract_address: Some(address),
+            }
+        );
+    }
+
+    #[test]
+    fn reverted_call() {
+        let result = ExecutionResult::<HaltReason>::Revert {
+            gas_used: 21_500,
+            output: bytes!("08c379a0"),
+        };
+
+        assert_eq!(
+            ExecutionReceipt::from(result),
+            ExecutionReceipt {
+                status: false,
+                gas_used: 21_500,
+                cumulative_gas_used: 21_500,
+                logs: Vec::new(),
+                logs_bloom: Bloom::default(),
+                output: bytes!("08c379a0"),
+                contract_address: None,
+            }
+        );
+    }
+
+    #[test]
+    fn encode_legacy_receipt() {
+        let receipt = ExecutionReceipt {
+            cumulative_gas_used: 21_000,
+            ..Default::default()
+        };
+
+        let expected = [&hex!("f9010880825208b90100")[..], &[0; 256], &hex!("c0")].concat();
+        assert_eq!(receipt.encoded_2718(0), expected);
+    }
+
+    #[test]
+    fn encode_typed_receipt() {
+        let logs = vec![Log::new_unchecked(
+            Address::with_last_byte(0x11),
+            vec![B256::with_last_byte(0x2a)],
+            bytes!("ff01"),
+        )];
+        let receipt = ExecutionReceipt {
+            status: true,
+            cumulative_gas_used: 0x1234567,
+            logs_bloom: logs_bloom(&logs),
+            logs,
+            ..Default::default()
+        };
+
+        let expected = [
+            &hex!("02f90147018401234567b90100")[..],
+            receipt.logs_bloom.as_slice(),
+            &hex!(
+                "f83cf83a940000000000000000000000000000000000000011e1a0"
+                "000000000000000000000000000000000000000000000000000000000000002a82ff01"
+            ),
+        ]
+        .concat();
+        assert_eq!(receipt.encoded_2718(2), expected);
+    }
+}
@@ -1,7 +1,7 @@
 use crate::transaction::TransactionError;
 use core::fmt::{self, Debug};
 use database_interface::DBErrorMarker;
-use primitives::{Address, Bytes, Log, U256};
+use primitives::{Address, Bytes, HashMap, Log, U256};
 use specification::eip7702::InvalidAuthorization;
 use state::EvmState;
 use std::{boxed::Box, string::String, vec::Vec};
@@ -22,6 +22,26 @@ pub struct ResultAndState<HaltReasonT: HaltReasonTrait> {
     pub state: EvmState,
 }
 
+impl<HaltReasonT: HaltReasonTrait> ResultAndState<HaltReasonT> {
+    /// Returns the net storage changes as `(original, present)` values keyed by account and slot.
+    ///
+    /// Only slots whose value differs from the value before the transaction are included, so
+    /// reads and intermediate writes are ignored.
+    pub fn storage_changes(&self) -> HashMap<(Address, U256), (U256, U256)> {
+        self.state
+            .iter()
+            .flat_map(|(address, account)| {
+                account.changed_storage_slots().map(move |(slot, value)| {
+                    (
+                        (*address, *slot),
+                        (value.original_value(), value.present_value()),
+                    )
+                })
+            })
+            .collect()
+    }
+}
+
 /// Result of a transaction execution
 #[derive(Clone, Debug, PartialEq, Eq, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
@@ -32,6 +52,13 @@ pub enum ExecutionResult<HaltReasonT: HaltReasonTrait> {
         gas_used: u64,
         gas_refunded: u64,
         logs: Vec<Log>,
+        /// Call stack depth at which each of the `logs` was emitted, the top level frame
+        /// having depth `1`.
+        ///
+        /// Parallel to `logs`: `log_depths[i]` is the depth of `logs[i]`, and `i` is the index
+        /// of the log within the transaction. Both lists must have the same length, so they
+        /// have to be reordered or filtered together.
+        log_depths: Vec<usize>,
         output: Output,
     },
     /// Reverted by `REVERT` opcode that doesn't spend all gas
@@ -106,6 +133,18 @@ impl<HaltReasonT: HaltReasonTrait> ExecutionResult<HaltReasonT> {
         }
     }
 
+    /// Returns the call stack depth at which each of the [`logs`](Self::logs) was emitted.
+    ///
+    /// The depth of a log is at the same position as the log in [`logs`](Self::logs). Logs of
+    /// reverted frames are not included, so that position is the index of the log in the
+    /// transaction.
+    pub fn log_depths(&self) -> &[usize] {
+        match self {
+            Self::Success { log_depths, .. } => log_depths.as_slice(),
+            _ => &[],
+        }
+    }
+
     /// Returns the gas used.
     pub fn gas_used(&self) -> u64 {
         match *self {
@@ -114,6 +153,20 @@ impl<HaltReasonT: HaltReasonTrait> ExecutionResult<HaltReasonT> {
             | Self::Halt { gas_used, .. } => gas_used,
         }
     }
+
+    /// Returns the gas used before the refund is applied.
+    ///
+    /// Only successful executions are refunded, for others this is equal to [`Self::gas_used`].
+    pub fn gas_used_before_refund(&self) -> u64 {
+        match *self {
+            Self::Success {
+                gas_used,
+                gas_refunded,
+                ..
+            } => gas_used + gas_refunded,
+            Self::Revert { gas_used, .. } | Self::Halt { gas_used, .. } => gas_used,
+        }
+    }
 }
 
 /// Output of a transaction execution
@@ -303,13 +356,18 @@ pub enum InvalidTransaction {
     ///
     /// `to` must be present
     BlobCreateTransaction,
-    /// Transaction has more then [`specification::eip4844::MAX_BLOB_NUMBER_PER_BLOCK`] blobs
+    /// Transaction has more blobs than allowed per block, see [`BlobParams`][crate::block::BlobParams]
     TooManyBlobs {
         max: usize,
         have: usize,
     },
     /// Blob transaction contains a versioned hash with an incorrect version
     BlobVersionNotSupported,
+    /// Transaction input is larger than [`Cfg::max_calldata_size`][crate::Cfg::max_calldata_size].
+    CalldataSizeLimit {
+        max: usize,
+        have: usize,
+    },
     /// EOF crate should have `to` address
     EofCrateShouldHaveToAddress,
     /// EIP-7702 is not enabled.
@@ -393,6 +451,9 @@ impl fmt::Display for InvalidTransaction {
                 write!(f, "too many blobs, have {have}, max {max}")
             }
             Self::BlobVersionNotSupported => write!(f, "blob version not supported"),
+            Self::CalldataSizeLimit { max, have } => {
+                write!(f, "calldata size limit exceeded, have {have}, max {max}")
+            }
             Self::EofCrateShouldHaveToAddress => write!(f, "EOF crate should have `to` address"),
             Self::AuthorizationListNotSupported => write!(f, "authorization list not supported"),
             Self::AuthorizationListInvalidFields => {
@@ -416,6 +477,11 @@ pub enum InvalidHeader {
     PrevrandaoNotSet,
     /// `excess_blob_gas` is not set for Cancun and above.
     ExcessBlobGasNotSet,
+    /// `blob_gasprice` of the block is not the one computed with the configured
+    /// [`BlobParams`][crate::block::BlobParams].
+    BlobGasPriceMismatch,
+    /// System call made at a block boundary failed or its contract is not deployed.
+    SystemCallFailed { address: Address },
 }
 
 impl core::error::Error for InvalidHeader {}
@@ -425,6 +491,10 @@ impl fmt::Display for InvalidHeader {
         match self {
             Self::PrevrandaoNotSet => write!(f, "`prevrandao` not set"),
             Self::ExcessBlobGasNotSet => write!(f, "`excess_blob_gas` not set"),
+            Self::BlobGasPriceMismatch => {
+                write!(f, "`blob_gasprice` does not match the blob parameters")
+            }
+            Self::SystemCallFailed { address } => write!(f, "system call to {address} failed"),
         }
     }
 }
@@ -469,6 +539,8 @@ pub enum HaltReason {
     CallNotAllowedInsideStatic,
     OutOfFunds,
     CallTooDeep,
+    /// Execution was stopped by a breakpoint of an inspector.
+    Breakpoint,
 
     /// Aux data overflow, new aux data is larger than [u16] max size.
     EofAuxDataOverflow,
@@ -478,6 +550,8 @@ pub enum HaltReason {
     SubRoutineStackOverflow,
     /// Check for target address validity is only done inside subcall.
     InvalidEXTCALLTarget,
+    /// Transaction emitted more logs than [`Cfg::max_logs_per_tx`](crate::Cfg::max_logs_per_tx).
+    LogLimitExceeded,
 }
 
 #[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
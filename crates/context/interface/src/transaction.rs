@@ -17,7 +17,6 @@ pub use legacy::LegacyTx;
 pub use transaction_type::TransactionType;
 
 use auto_impl::auto_impl;
-use core::cmp::min;
 use core::fmt::Debug;
 use primitives::TxKind;
 use std::boxed::Box;
@@ -125,7 +124,7 @@ pub trait Transaction {
             TransactionType::Custom => unimplemented!("Custom tx not supported"),
         };
 
-        min(max_fee, base_fee.saturating_add(max_priority_fee))
+        eip1559::effective_gas_price(max_fee, max_priority_fee, base_fee)
     }
 
     /// Returns transaction kind.
@@ -1,5 +1,6 @@
 use super::{AccessListTrait, CommonTxFields};
 use auto_impl::auto_impl;
+use core::cmp::min;
 use primitives::TxKind;
 
 #[auto_impl(&, Box, Arc, Rc)]
@@ -25,3 +26,38 @@ pub trait Eip1559CommonTxFields: CommonTxFields {
     /// EIP-1559 access list.
     fn access_list(&self) -> &Self::AccessList;
 }
+
+/// Returns the EIP-1559 effective gas price at `base_fee`.
+///
+/// This is `base_fee + max_priority_fee`, capped at `max_fee`.
+pub fn effective_gas_price(max_fee: u128, max_priority_fee: u128, base_fee: u128) -> u128 {
+    min(max_fee, base_fee.saturating_add(max_priority_fee))
+}
+
+/// Returns the EIP-1559 priority fee per gas paid to the beneficiary at `base_fee`.
+///
+/// This is the part of the [`effective_gas_price`] above `base_fee`.
+pub fn effective_priority_fee(max_fee: u128, max_priority_fee: u128, base_fee: u128) -> u128 {
+    effective_gas_price(max_fee, max_priority_fee, base_fee).saturating_sub(base_fee)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn effective_gas_price_tip_capped() {
+        // The max fee leaves room for the whole tip.
+        assert_eq!(effective_gas_price(100, 2, 10), 12);
+        assert_eq!(effective_priority_fee(100, 2, 10), 2);
+    }
+
+    #[test]
+    fn effective_gas_price_max_fee_capped() {
+        // Only 1 of the tip fits under the max fee.
+        assert_eq!(effective_gas_price(11, 2, 10), 11);
+        assert_eq!(effective_priority_fee(11, 2, 10), 1);
+        // Below the base fee, the transaction is invalid.
+        assert_eq!(effective_priority_fee(9, 2, 10), 0);
+    }
+}
@@ -1,4 +1,4 @@
-use context_interface::block::{BlobExcessGasAndPrice, Block};
+use context_interface::block::{BlobExcessGasAndPrice, BlobParams, Block};
 use primitives::{Address, B256, U256};
 
 /// The block environment
@@ -20,6 +20,14 @@ pub struct BlockEnv {
     ///
     /// [EIP-1559]: https://eips.ethereum.org/EIPS/eip-1559
     pub basefee: u64,
+    /// Forces the effective base fee to zero while keeping the London fee rules
+    ///
+    /// `basefee` is reported as zero to the `BASEFEE` opcode, transaction validation and fee
+    /// accounting, so no base fee is burned.
+    ///
+    /// By default, it is set to `false`.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub zero_basefee: bool,
     /// The difficulty of the block
     ///
     /// Unused after the Paris (AKA the merge) upgrade, and replaced by `prevrandao`.
@@ -41,6 +49,14 @@ pub struct BlockEnv {
     ///
     /// [EIP-4844]: https://eips.ethereum.org/EIPS/eip-4844
     pub blob_excess_gas_and_price: Option<BlobExcessGasAndPrice>,
+    /// The root of the parent beacon block
+    ///
+    /// Written to the beacon roots contract at the start of the block.
+    ///
+    /// Incorporated as part of the Cancun upgrade via [EIP-4788].
+    ///
+    /// [EIP-4788]: https://eips.ethereum.org/EIPS/eip-4788
+    pub parent_beacon_block_root: Option<B256>,
 }
 
 impl BlockEnv {
@@ -49,6 +65,19 @@ impl BlockEnv {
     pub fn set_blob_excess_gas_and_price(&mut self, excess_blob_gas: u64) {
         self.blob_excess_gas_and_price = Some(BlobExcessGasAndPrice::new(excess_blob_gas));
     }
+
+    /// Takes `blob_excess_gas` saves it inside env
+    /// and calculates `blob_fee` with the given [`BlobParams`].
+    pub fn set_blob_excess_gas_and_price_with_params(
+        &mut self,
+        excess_blob_gas: u64,
+        params: &BlobParams,
+    ) {
+        self.blob_excess_gas_and_price = Some(BlobExcessGasAndPrice::new_with_params(
+            excess_blob_gas,
+            params,
+        ));
+    }
 }
 
 impl Block for BlockEnv {
@@ -74,7 +103,11 @@ impl Block for BlockEnv {
 
     #[inline]
     fn basefee(&self) -> u64 {
-        self.basefee
+        if self.zero_basefee {
+            0
+        } else {
+            self.basefee
+        }
     }
 
     #[inline]
@@ -91,6 +124,11 @@ impl Block for BlockEnv {
     fn blob_excess_gas_and_price(&self) -> Option<BlobExcessGasAndPrice> {
         self.blob_excess_gas_and_price
     }
+
+    #[inline]
+    fn parent_beacon_block_root(&self) -> Option<B256> {
+        self.parent_beacon_block_root
+    }
 }
 
 impl Default for BlockEnv {
@@ -101,9 +139,11 @@ impl Default for BlockEnv {
             timestamp: 1,
             gas_limit: u64::MAX,
             basefee: 0,
+            zero_basefee: false,
             difficulty: U256::ZERO,
             prevrandao: Some(B256::ZERO),
             blob_excess_gas_and_price: Some(BlobExcessGasAndPrice::new(0)),
+            parent_beacon_block_root: None,
         }
     }
 }
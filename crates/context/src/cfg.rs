@@ -1,6 +1,9 @@
 pub use context_interface::Cfg;
 
-use interpreter::MAX_CODE_SIZE;
+use context_interface::block::BlobParams;
+
+use interpreter::{gas, MAX_CODE_SIZE};
+use primitives::{Address, HashMap, SYSTEM_ADDRESS, SYSTEM_CALL_GAS_LIMIT};
 use specification::hardfork::SpecId;
 
 /// EVM configuration
@@ -24,6 +27,125 @@ pub struct CfgEnv<SPEC: Into<SpecId> = SpecId> {
     pub limit_contract_code_size: Option<usize>,
     /// Skips the nonce validation against the account's nonce
     pub disable_nonce_check: bool,
+    /// Disables gas metering entirely
+    ///
+    /// Instructions are executed with an unlimited amount of gas and the transaction
+    /// reports zero gas used. Memory and stack limits still apply.
+    ///
+    /// This is useful for pure semantic execution where gas is not relevant.
+    ///
+    /// By default, it is set to `false`.
+    pub disable_gas_metering: bool,
+    /// Disables the EIP-150 rule retaining 1/64 of the remaining gas on calls and creates
+    ///
+    /// All requested gas is forwarded to subcalls, as before the Tangerine Whistle upgrade.
+    ///
+    /// By default, it is set to `false`.
+    pub disable_call_gas_retention: bool,
+    /// Prices every account and storage access as cold, as if it was never warmed
+    ///
+    /// Useful to estimate the worst-case gas usage of a transaction. Transaction access lists
+    /// and precompiles are not warm either.
+    ///
+    /// By default, it is set to `false`.
+    pub force_cold_access: bool,
+    /// Keeps the state changes of frames that revert or halt instead of rolling them back
+    ///
+    /// This is not consensus compliant and is meant for state exploration, e.g. fuzzing. The
+    /// result of the frames is unchanged, only their state changes are kept.
+    ///
+    /// By default, it is set to `false`.
+    pub ignore_revert_state_changes: bool,
+    /// Keeps accounts left empty by the transaction in the committed state
+    ///
+    /// From Spurious Dragon, [EIP-161](https://eips.ethereum.org/EIPS/eip-161) removes touched
+    /// empty accounts on commit. If set, they are marked as retained in the returned state,
+    /// so databases store them as empty accounts and keep their storage instead.
+    /// Not consensus compliant.
+    ///
+    /// By default, it is set to `false`.
+    pub retain_empty_accounts: bool,
+    /// Blob gas schedule used to validate the number of blobs in a transaction
+    ///
+    /// The blob gas price of the block must be computed with the same schedule, otherwise the
+    /// block is rejected.
+    ///
+    /// By default, it is set to the mainnet EIP-4844 values.
+    pub blob_params: BlobParams,
+    /// Caller of the system calls made at block boundaries
+    ///
+    /// The system caller does not pay for gas and its account is left unchanged.
+    ///
+    /// By default, it is set to [`SYSTEM_ADDRESS`].
+    pub system_caller: Address,
+    /// Gas limit of the system calls made at block boundaries
+    ///
+    /// System call gas is not charged to anyone nor counted against the block gas limit.
+    /// Set it to `u64::MAX` to run system calls with unlimited gas.
+    ///
+    /// By default, it is set to [`SYSTEM_CALL_GAS_LIMIT`].
+    pub system_call_gas_limit: u64,
+    /// Quotient of the gas spent that caps the final gas refund
+    ///
+    /// Zero disables gas refunds. If `None`, it is 5 from London ([EIP-3529](https://eips.ethereum.org/EIPS/eip-3529))
+    /// and 2 before.
+    ///
+    /// By default, it is set to `None`.
+    pub max_refund_quotient: Option<u64>,
+    /// Base cost of call-family opcodes
+    ///
+    /// If `None`, it follows the spec, see [`gas::call_base_cost`].
+    ///
+    /// By default, it is set to `None`.
+    pub call_base_cost: Option<u64>,
+    /// Base cost of `CREATE` and `CREATE2`
+    ///
+    /// If `None`, it is [`gas::CREATE`].
+    ///
+    /// By default, it is set to `None`.
+    pub create_base_cost: Option<u64>,
+    /// Gas charged per word by the identity precompile
+    ///
+    /// If `None`, it follows the spec, which charges 3 gas per word.
+    ///
+    /// By default, it is set to `None`.
+    pub identity_per_word_gas: Option<u64>,
+    /// Base gas charged by the bn254 pairing precompile
+    ///
+    /// If `None`, it follows the spec, which charges 100000 gas before Istanbul and 45000 gas
+    /// since.
+    ///
+    /// By default, it is set to `None`.
+    pub bn254_pair_base_gas: Option<u64>,
+    /// Gas charged per pairing by the bn254 pairing precompile
+    ///
+    /// If `None`, it follows the spec, which charges 80000 gas before Istanbul and 34000 gas
+    /// since.
+    ///
+    /// By default, it is set to `None`.
+    pub bn254_pair_per_point_gas: Option<u64>,
+    /// Maximum input size in bytes of precompiles, keyed by precompile address
+    ///
+    /// Calls with a larger input fail with a precompile error before the precompile runs.
+    /// Precompiles without an entry are unbounded, as per spec.
+    ///
+    /// By default, it is empty.
+    pub precompile_max_input_size: HashMap<Address, usize>,
+    /// Maximum size of the transaction input in bytes
+    ///
+    /// Transactions with a larger input are rejected during validation. If `None`, the size
+    /// is unbounded.
+    ///
+    /// By default, it is set to `None`.
+    pub max_calldata_size: Option<usize>,
+    /// Maximum number of logs a transaction can emit
+    ///
+    /// A `LOG*` opcode that would exceed it halts with
+    /// [HaltReason::LogLimitExceeded][context_interface::result::HaltReason::LogLimitExceeded].
+    /// If `None`, the number of logs is unbounded.
+    ///
+    /// By default, it is set to `None`.
+    pub max_logs_per_tx: Option<usize>,
     /// A hard memory limit in bytes beyond which
     /// [OutOfGasError::Memory][context_interface::result::OutOfGasError::Memory] cannot be resized.
     ///
@@ -70,6 +192,13 @@ pub struct CfgEnv<SPEC: Into<SpecId> = SpecId> {
     /// By default, it is set to `false`.
     #[cfg(feature = "optional_no_base_fee")]
     pub disable_base_fee: bool,
+    /// Pins the nonce used to derive `CREATE` addresses
+    ///
+    /// Useful for deterministic test fixtures. The caller nonce is still incremented.
+    ///
+    /// By default, it is set to `None`.
+    #[cfg(feature = "optional_create_nonce")]
+    pub create_nonce_override: Option<u64>,
 }
 
 impl CfgEnv {
@@ -147,6 +276,91 @@ impl<SPEC: Into<SpecId> + Copy> Cfg for CfgEnv<SPEC> {
             }
         }
     }
+
+    fn is_gas_metering_disabled(&self) -> bool {
+        self.disable_gas_metering
+    }
+
+    fn is_call_gas_retention_disabled(&self) -> bool {
+        self.disable_call_gas_retention
+    }
+
+    fn is_cold_access_forced(&self) -> bool {
+        self.force_cold_access
+    }
+
+    fn is_state_kept_on_revert(&self) -> bool {
+        self.ignore_revert_state_changes
+    }
+
+    fn is_empty_account_retained(&self) -> bool {
+        self.retain_empty_accounts
+    }
+
+    fn blob_params(&self) -> BlobParams {
+        self.blob_params
+    }
+
+    fn system_caller(&self) -> Address {
+        self.system_caller
+    }
+
+    fn system_call_gas_limit(&self) -> u64 {
+        self.system_call_gas_limit
+    }
+
+    fn max_refund_quotient(&self) -> u64 {
+        self.max_refund_quotient.unwrap_or_else(|| {
+            if self.spec.into().is_enabled_in(SpecId::LONDON) {
+                5
+            } else {
+                2
+            }
+        })
+    }
+
+    fn call_base_cost(&self) -> u64 {
+        self.call_base_cost
+            .unwrap_or_else(|| gas::call_base_cost(self.spec.into()))
+    }
+
+    fn create_base_cost(&self) -> u64 {
+        self.create_base_cost.unwrap_or(gas::CREATE)
+    }
+
+    fn identity_per_word_gas(&self) -> Option<u64> {
+        self.identity_per_word_gas
+    }
+
+    fn bn254_pair_base_gas(&self) -> Option<u64> {
+        self.bn254_pair_base_gas
+    }
+
+    fn bn254_pair_per_point_gas(&self) -> Option<u64> {
+        self.bn254_pair_per_point_gas
+    }
+
+    fn precompile_max_input_size(&self, address: &Address) -> Option<usize> {
+        self.precompile_max_input_size.get(address).copied()
+    }
+
+    fn max_calldata_size(&self) -> Option<usize> {
+        self.max_calldata_size
+    }
+
+    fn max_logs_per_tx(&self) -> Option<usize> {
+        self.max_logs_per_tx
+    }
+
+    fn create_nonce_override(&self) -> Option<u64> {
+        cfg_if::cfg_if! {
+            if #[cfg(feature = "optional_create_nonce")] {
+                self.create_nonce_override
+            } else {
+                None
+            }
+        }
+    }
 }
 
 impl Default for CfgEnv {
@@ -156,6 +370,23 @@ impl Default for CfgEnv {
             limit_contract_code_size: None,
             spec: SpecId::PRAGUE,
             disable_nonce_check: false,
+            disable_gas_metering: false,
+            disable_call_gas_retention: false,
+            force_cold_access: false,
+            ignore_revert_state_changes: false,
+            retain_empty_accounts: false,
+            blob_params: BlobParams::default(),
+            system_caller: SYSTEM_ADDRESS,
+            system_call_gas_limit: SYSTEM_CALL_GAS_LIMIT,
+            max_refund_quotient: None,
+            call_base_cost: None,
+            create_base_cost: None,
+            identity_per_word_gas: None,
+            bn254_pair_base_gas: None,
+            bn254_pair_per_point_gas: None,
+            precompile_max_input_size: HashMap::default(),
+            max_calldata_size: None,
+            max_logs_per_tx: None,
             #[cfg(feature = "memory_limit")]
             memory_limit: (1 << 32) - 1,
             #[cfg(feature = "optional_balance_check")]
@@ -168,6 +399,8 @@ impl Default for CfgEnv {
             disable_gas_refund: false,
             #[cfg(feature = "optional_no_base_fee")]
             disable_base_fee: false,
+            #[cfg(feature = "optional_create_nonce")]
+            create_nonce_override: None,
         }
     }
 }
@@ -10,9 +10,32 @@ use context_interface::{
 };
 use database_interface::{Database, EmptyDB};
 use derive_where::derive_where;
-use interpreter::{Host, SStoreResult, SelfDestructResult, StateLoad};
-use primitives::{Address, Bytes, Log, B256, BLOCK_HASH_HISTORY, U256};
+use interpreter::{
+    EmptyAccountCallResult, FrameInfo, Host, SStoreResult, SelfDestructResult, StateLoad,
+};
+use primitives::{
+    Address, Bytes, Log, B256, BLOCKHASH_SERVE_WINDOW, BLOCKHASH_STORAGE_ADDRESS,
+    BLOCK_HASH_HISTORY, U256,
+};
 use specification::hardfork::SpecId;
+use std::{sync::Arc, vec::Vec};
+
+/// Source of `PREVRANDAO` values that overrides the block's `prevrandao`.
+///
+/// Useful for deterministic fuzzing where the randomness should come from a seeded source.
+pub type PrevrandaoSource = Arc<dyn Fn() -> B256 + Send + Sync>;
+
+/// Handler of calls to accounts without code, see [`Host::call_empty_account`].
+///
+/// Receives the called address, the input and the gas limit of the call.
+pub type EmptyAccountCallHandler =
+    Arc<dyn Fn(Address, &Bytes, u64) -> Option<EmptyAccountCallResult> + Send + Sync>;
+
+/// Handler of calls to the zero address, see [`Host::call_zero_address`].
+///
+/// Receives the caller, the value transferred, the input and the gas limit of the call.
+pub type ZeroAddressCallHandler =
+    Arc<dyn Fn(Address, U256, &Bytes, u64) -> EmptyAccountCallResult + Send + Sync>;
 
 /// EVM context contains data that EVM needs for execution.
 #[derive_where(Clone, Debug; BLOCK, CFG, CHAIN, TX, DB, JOURNAL, <DB as Database>::Error)]
@@ -36,6 +59,23 @@ pub struct Context<
     pub chain: CHAIN,
     /// Error that happened during execution.
     pub error: Result<(), <DB as Database>::Error>,
+    /// Optional source of `PREVRANDAO` values.
+    ///
+    /// Consulted each time `PREVRANDAO` is read. If not set, the value is read from the block.
+    #[derive_where(skip(Debug))]
+    pub prevrandao_source: Option<PrevrandaoSource>,
+    /// Optional handler of calls to accounts without code.
+    ///
+    /// If not set, such calls succeed with empty output.
+    #[derive_where(skip(Debug))]
+    pub empty_account_call_handler: Option<EmptyAccountCallHandler>,
+    /// Optional handler of calls to the zero address.
+    ///
+    /// If not set, the zero address is called like any other account.
+    #[derive_where(skip(Debug))]
+    pub zero_address_call_handler: Option<ZeroAddressCallHandler>,
+    /// Frames being executed, see [`Host::call_stack`].
+    pub call_stack: Vec<FrameInfo>,
 }
 
 impl Default for Context {
@@ -71,6 +111,10 @@ impl<
             journaled_state,
             chain: Default::default(),
             error: Ok(()),
+            prevrandao_source: None,
+            empty_account_call_handler: None,
+            zero_address_call_handler: None,
+            call_stack: Vec::new(),
         }
     }
 }
@@ -142,6 +186,10 @@ where
             journaled_state: journal,
             chain: self.chain,
             error: Ok(()),
+            prevrandao_source: self.prevrandao_source,
+            empty_account_call_handler: self.empty_account_call_handler,
+            zero_address_call_handler: self.zero_address_call_handler,
+            call_stack: self.call_stack,
         }
     }
 
@@ -160,6 +208,10 @@ where
             journaled_state,
             chain: self.chain,
             error: Ok(()),
+            prevrandao_source: self.prevrandao_source,
+            empty_account_call_handler: self.empty_account_call_handler,
+            zero_address_call_handler: self.zero_address_call_handler,
+            call_stack: self.call_stack,
         }
     }
 
@@ -172,6 +224,10 @@ where
             journaled_state: self.journaled_state,
             chain: self.chain,
             error: Ok(()),
+            prevrandao_source: self.prevrandao_source,
+            empty_account_call_handler: self.empty_account_call_handler,
+            zero_address_call_handler: self.zero_address_call_handler,
+            call_stack: self.call_stack,
         }
     }
 
@@ -187,6 +243,10 @@ where
             journaled_state: self.journaled_state,
             chain: self.chain,
             error: Ok(()),
+            prevrandao_source: self.prevrandao_source,
+            empty_account_call_handler: self.empty_account_call_handler,
+            zero_address_call_handler: self.zero_address_call_handler,
+            call_stack: self.call_stack,
         }
     }
 
@@ -199,6 +259,10 @@ where
             journaled_state: self.journaled_state,
             chain,
             error: Ok(()),
+            prevrandao_source: self.prevrandao_source,
+            empty_account_call_handler: self.empty_account_call_handler,
+            zero_address_call_handler: self.zero_address_call_handler,
+            call_stack: self.call_stack,
         }
     }
 
@@ -215,9 +279,43 @@ where
             journaled_state: self.journaled_state,
             chain: self.chain,
             error: Ok(()),
+            prevrandao_source: self.prevrandao_source,
+            empty_account_call_handler: self.empty_account_call_handler,
+            zero_address_call_handler: self.zero_address_call_handler,
+            call_stack: self.call_stack,
         }
     }
 
+    /// Sets the source of `PREVRANDAO` values, overriding the block's `prevrandao`.
+    #[must_use]
+    pub fn with_prevrandao_source<F>(mut self, source: F) -> Self
+    where
+        F: Fn() -> B256 + Send + Sync + 'static,
+    {
+        self.prevrandao_source = Some(Arc::new(source));
+        self
+    }
+
+    /// Sets the handler of calls to accounts without code.
+    #[must_use]
+    pub fn with_empty_account_call_handler<F>(mut self, handler: F) -> Self
+    where
+        F: Fn(Address, &Bytes, u64) -> Option<EmptyAccountCallResult> + Send + Sync + 'static,
+    {
+        self.empty_account_call_handler = Some(Arc::new(handler));
+        self
+    }
+
+    /// Sets the handler of calls to the zero address, e.g. to serve it as a burn sink.
+    #[must_use]
+    pub fn with_zero_address_call_handler<F>(mut self, handler: F) -> Self
+    where
+        F: Fn(Address, U256, &Bytes, u64) -> EmptyAccountCallResult + Send + Sync + 'static,
+    {
+        self.zero_address_call_handler = Some(Arc::new(handler));
+        self
+    }
+
     /// Modifies the context configuration.
     #[must_use]
     pub fn modify_cfg_chained<F>(mut self, f: F) -> Self
@@ -323,6 +421,25 @@ where
         f(&mut self.journaled_state);
     }
 
+    /// Sets transient storage ([EIP-1153]) value of `address` at `index`.
+    ///
+    /// Transient storage is cleared when the transaction is finalized, so the seeded
+    /// value is only visible within the upcoming transaction.
+    ///
+    /// [EIP-1153]: https://eips.ethereum.org/EIPS/eip-1153
+    pub fn set_transient(&mut self, address: Address, index: U256, value: U256) {
+        self.journaled_state.tstore(address, index, value);
+    }
+
+    /// Returns transient storage ([EIP-1153]) value of `address` at `index`.
+    ///
+    /// Value is zero if the slot was never set or if the transaction was already finalized.
+    ///
+    /// [EIP-1153]: https://eips.ethereum.org/EIPS/eip-1153
+    pub fn get_transient(&mut self, address: Address, index: U256) -> U256 {
+        self.journaled_state.tload(address, index)
+    }
+
     /// Gets code hash of address.
     ///
     /// In case of EOF account it will return `EOF_MAGIC_HASH`
@@ -370,6 +487,24 @@ where
 
         Ok(Eip7702CodeLoad::new_not_delegated(hash, acc.is_cold))
     }
+
+    /// Marks the load as cold if [`Cfg::is_cold_access_forced`].
+    #[inline]
+    fn force_cold<T>(&self, mut load: StateLoad<T>) -> StateLoad<T> {
+        load.is_cold |= self.cfg.is_cold_access_forced();
+        load
+    }
+
+    /// Marks the account and its delegate as cold if [`Cfg::is_cold_access_forced`].
+    #[inline]
+    fn force_cold_code<T>(&self, mut load: Eip7702CodeLoad<T>) -> Eip7702CodeLoad<T> {
+        let forced = self.cfg.is_cold_access_forced();
+        load.state_load.is_cold |= forced;
+        if let Some(is_cold) = &mut load.is_delegate_account_cold {
+            *is_cold |= forced;
+        }
+        load
+    }
 }
 
 impl<BLOCK, TX, CFG, DB, JOURNAL, CHAIN> Host for Context<BLOCK, TX, CFG, DB, JOURNAL, CHAIN>
@@ -401,14 +536,76 @@ where
                 .ok();
         }
 
+        // EIP-2935: Serve historical block hashes from state
+        let spec: SpecId = self.cfg.spec().into();
+        if spec.is_enabled_in(SpecId::PRAGUE) && diff <= BLOCKHASH_SERVE_WINDOW as u64 {
+            // The history is written before the first transaction of the block and committed,
+            // so it is read from the database to leave the access list untouched.
+            let slot = U256::from(requested_number % BLOCKHASH_SERVE_WINDOW as u64);
+            return self
+                .journaled_state
+                .db()
+                .storage(BLOCKHASH_STORAGE_ADDRESS, slot)
+                .map(|value| B256::from(value.to_be_bytes()))
+                .map_err(|e| self.error = Err(e))
+                .ok();
+        }
+
         Some(B256::ZERO)
     }
 
+    fn prevrandao(&mut self) -> Option<B256> {
+        match &self.prevrandao_source {
+            Some(source) => Some(source()),
+            None => self.block.prevrandao(),
+        }
+    }
+
+    fn call_empty_account(
+        &mut self,
+        address: Address,
+        input: &Bytes,
+        gas_limit: u64,
+    ) -> Option<EmptyAccountCallResult> {
+        self.empty_account_call_handler
+            .as_ref()
+            .and_then(|handler| handler(address, input, gas_limit))
+    }
+
+    fn call_zero_address(
+        &mut self,
+        caller: Address,
+        value: U256,
+        input: &Bytes,
+        gas_limit: u64,
+    ) -> Option<EmptyAccountCallResult> {
+        self.zero_address_call_handler
+            .as_ref()
+            .map(|handler| handler(caller, value, input, gas_limit))
+    }
+
+    fn call_stack(&self) -> &[FrameInfo] {
+        &self.call_stack
+    }
+
+    fn push_call_frame(&mut self, depth: usize, frame: FrameInfo) {
+        self.call_stack.truncate(depth);
+        self.call_stack.push(frame);
+    }
+
+    fn pop_call_frame(&mut self) {
+        self.call_stack.pop();
+    }
+
     fn load_account_delegated(&mut self, address: Address) -> Option<AccountLoad> {
         self.journaled_state
             .load_account_delegated(address)
             .map_err(|e| self.error = Err(e))
             .ok()
+            .map(|load| AccountLoad {
+                load: self.force_cold_code(load.load),
+                is_empty: load.is_empty,
+            })
     }
 
     fn balance(&mut self, address: Address) -> Option<StateLoad<U256>> {
@@ -417,16 +614,21 @@ where
             .map_err(|e| self.error = Err(e))
             .map(|acc| acc.map(|a| a.info.balance))
             .ok()
+            .map(|load| self.force_cold(load))
     }
 
     fn code(&mut self, address: Address) -> Option<Eip7702CodeLoad<Bytes>> {
-        self.code(address).map_err(|e| self.error = Err(e)).ok()
+        self.code(address)
+            .map_err(|e| self.error = Err(e))
+            .ok()
+            .map(|load| self.force_cold_code(load))
     }
 
     fn code_hash(&mut self, address: Address) -> Option<Eip7702CodeLoad<B256>> {
         self.code_hash(address)
             .map_err(|e| self.error = Err(e))
             .ok()
+            .map(|load| self.force_cold_code(load))
     }
 
     fn sload(&mut self, address: Address, index: U256) -> Option<StateLoad<U256>> {
@@ -434,6 +636,7 @@ where
             .sload(address, index)
             .map_err(|e| self.error = Err(e))
             .ok()
+            .map(|load| self.force_cold(load))
     }
 
     fn sstore(
@@ -446,6 +649,7 @@ where
             .sstore(address, index, value)
             .map_err(|e| self.error = Err(e))
             .ok()
+            .map(|load| self.force_cold(load))
     }
 
     fn tload(&mut self, address: Address, index: U256) -> U256 {
@@ -460,6 +664,10 @@ where
         self.journaled_state.log(log);
     }
 
+    fn log_count(&self) -> usize {
+        self.journaled_state.logs().len()
+    }
+
     fn selfdestruct(
         &mut self,
         address: Address,
@@ -469,6 +677,7 @@ where
             .selfdestruct(address, target)
             .map_err(|e| self.error = Err(e))
             .ok()
+            .map(|load| self.force_cold(load))
     }
 }
 
@@ -561,3 +770,25 @@ impl<BLOCK: Block, TX, SPEC, DB: Database, JOURNAL: Journal<Database = DB>, CHAI
         self.block = block;
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use core::sync::atomic::{AtomicU8, Ordering};
+
+    #[test]
+    fn prevrandao_source_overrides_block() {
+        let mut context = Context::default();
+        assert_eq!(context.prevrandao(), context.block.prevrandao);
+
+        let counter = Arc::new(AtomicU8::new(0));
+        let source_counter = counter.clone();
+        let mut context = context.with_prevrandao_source(move || {
+            B256::with_last_byte(source_counter.fetch_add(1, Ordering::Relaxed))
+        });
+        for expected in 0..3 {
+            assert_eq!(context.prevrandao(), Some(B256::with_last_byte(expected)));
+        }
+        assert_eq!(counter.load(Ordering::Relaxed), 3);
+    }
+}
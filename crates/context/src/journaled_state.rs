@@ -8,6 +8,8 @@ use primitives::{
     hash_map::Entry, Address, HashMap, HashSet, Log, B256, KECCAK_EMPTY, PRECOMPILE3, U256,
 };
 use specification::hardfork::{SpecId, SpecId::*};
+#[cfg(feature = "debug_warm_access")]
+use state::AccountStatus;
 use state::{Account, EvmState, EvmStorageSlot, TransientStorage};
 
 use core::mem;
@@ -31,6 +33,8 @@ pub struct JournaledState<DB> {
     pub transient_storage: TransientStorage,
     /// Emitted logs
     pub logs: Vec<Log>,
+    /// Call stack depth at which each of the [`logs`](Self::logs) was emitted
+    pub log_depths: Vec<usize>,
     /// The current call stack depth
     pub depth: usize,
     /// The journal of state changes, one for each call
@@ -59,7 +63,7 @@ pub struct JournaledState<DB> {
 impl<DB: Database> Journal for JournaledState<DB> {
     type Database = DB;
     // TODO : Make a struck here.
-    type FinalOutput = (EvmState, Vec<Log>);
+    type FinalOutput = (EvmState, Vec<Log>, Vec<usize>);
 
     fn new(database: DB) -> JournaledState<DB> {
         Self::new(SpecId::LATEST, database)
@@ -102,6 +106,10 @@ impl<DB: Database> Journal for JournaledState<DB> {
         self.log(log)
     }
 
+    fn logs(&self) -> &[Log] {
+        &self.logs
+    }
+
     fn selfdestruct(
         &mut self,
         address: Address,
@@ -198,6 +206,7 @@ impl<DB: Database> Journal for JournaledState<DB> {
         self.state.clear();
         self.transient_storage.clear();
         self.logs.clear();
+        self.log_depths.clear();
         self.journal = vec![vec![]];
         self.depth = 0;
         self.warm_preloaded_addresses.clear();
@@ -219,6 +228,7 @@ impl<DB: Database> Journal for JournaledState<DB> {
             state,
             transient_storage,
             logs,
+            log_depths,
             depth,
             journal,
             // kept, see [Self::new]
@@ -233,8 +243,9 @@ impl<DB: Database> Journal for JournaledState<DB> {
         *depth = 0;
         let state = mem::take(state);
         let logs = mem::take(logs);
+        let log_depths = mem::take(log_depths);
 
-        Ok((state, logs))
+        Ok((state, logs, log_depths))
     }
 }
 
@@ -253,6 +264,7 @@ impl<DB: Database> JournaledState<DB> {
             state: HashMap::default(),
             transient_storage: TransientStorage::default(),
             logs: Vec::new(),
+            log_depths: Vec::new(),
             journal: vec![vec![]],
             depth: 0,
             spec,
@@ -273,6 +285,38 @@ impl<DB: Database> JournaledState<DB> {
         self.spec = spec;
     }
 
+    /// Returns the addresses that are warm in the current transaction.
+    ///
+    /// Accessing them is priced as warm by [EIP-2929](https://eips.ethereum.org/EIPS/eip-2929).
+    /// Accesses of reverted calls are not included.
+    #[cfg(feature = "debug_warm_access")]
+    pub fn warm_addresses(&self) -> HashSet<Address> {
+        self.state
+            .iter()
+            .filter(|(_, account)| !account.status.contains(AccountStatus::Cold))
+            .map(|(address, _)| *address)
+            .chain(self.warm_preloaded_addresses.iter().copied())
+            .collect()
+    }
+
+    /// Returns the storage slots that are warm in the current transaction.
+    ///
+    /// Accessing them is priced as warm by [EIP-2929](https://eips.ethereum.org/EIPS/eip-2929).
+    /// Accesses of reverted calls are not included.
+    #[cfg(feature = "debug_warm_access")]
+    pub fn warm_storage_slots(&self) -> HashSet<(Address, U256)> {
+        self.state
+            .iter()
+            .flat_map(|(address, account)| {
+                account
+                    .storage
+                    .iter()
+                    .filter(|(_, slot)| !slot.is_cold)
+                    .map(|(key, _)| (*address, *key))
+            })
+            .collect()
+    }
+
     /// Mark account as touched as only touched accounts will be added to state.
     /// This is especially important for state clear where touched empty accounts needs to
     /// be removed from state.
@@ -306,6 +350,15 @@ impl<DB: Database> JournaledState<DB> {
             .expect("Account expected to be loaded") // Always assume that acc is already loaded
     }
 
+    /// Returns the current working copy of the [Account] for the given address.
+    ///
+    /// It includes the changes of the transaction that are not yet committed, and of frames
+    /// that did not return yet. Returns `None` if the account was not loaded.
+    #[inline]
+    pub fn account_pending(&self, address: Address) -> Option<&Account> {
+        self.state.get(&address)
+    }
+
     /// Set code and its hash to the account.
     ///
     /// Note: Assume account is warm and that hash is calculated from code.
@@ -634,6 +687,7 @@ impl<DB: Database> JournaledState<DB> {
             });
 
         self.logs.truncate(checkpoint.log_i);
+        self.log_depths.truncate(checkpoint.log_i);
         self.journal.truncate(checkpoint.journal_i);
     }
 
@@ -977,6 +1031,7 @@ impl<DB: Database> JournaledState<DB> {
     #[inline]
     pub fn log(&mut self, log: Log) {
         self.logs.push(log);
+        self.log_depths.push(self.depth);
     }
 }
 
@@ -1045,3 +1100,117 @@ pub enum JournalEntry {
     /// Revert: Revert to previous bytecode.
     CodeChange { address: Address },
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use database::InMemoryDB;
+    use primitives::Bytes;
+    use state::AccountInfo;
+
+    #[test]
+    fn account_pending_includes_uncommitted_changes() {
+        let sender = Address::with_last_byte(0x01);
+        let receiver = Address::with_last_byte(0x02);
+
+        let mut db = InMemoryDB::default();
+        db.insert_account_info(sender, AccountInfo::from_balance(U256::from(100)));
+        let mut journal = JournaledState::new(SpecId::CANCUN, db);
+        assert!(journal.account_pending(sender).is_none());
+
+        journal.load_account(sender).unwrap();
+        journal.checkpoint();
+        assert_eq!(
+            journal.transfer(&sender, &receiver, U256::from(30)),
+            Ok(None)
+        );
+
+        let balance = |address| journal.account_pending(address).unwrap().info.balance;
+        assert_eq!(balance(sender), U256::from(70));
+        assert_eq!(balance(receiver), U256::from(30));
+    }
+
+    #[test]
+    fn log_depths_skip_reverted_frames() {
+        let log = |byte| Log::new_unchecked(Address::with_last_byte(byte), vec![], Bytes::new());
+        let mut journal = JournaledState::new(SpecId::CANCUN, InMemoryDB::default());
+
+        journal.checkpoint();
+        journal.log(log(1));
+        journal.checkpoint();
+        journal.log(log(2));
+        let inner = journal.checkpoint();
+        journal.log(log(3));
+        journal.checkpoint_revert(inner);
+        journal.log(log(4));
+        journal.checkpoint_commit();
+        journal.log(log(5));
+        journal.checkpoint_commit();
+
+        let (_, logs, log_depths) = journal.finalize().unwrap();
+        let emitters = logs.iter().map(|log| log.address[19]).collect::<Vec<_>>();
+        assert_eq!(emitters, vec![1, 2, 4, 5]);
+        assert_eq!(log_depths, vec![1, 2, 2, 1]);
+        assert!(journal.log_depths.is_empty());
+    }
+}
+
+#[cfg(all(test, feature = "debug_warm_access"))]
+mod warm_access_tests {
+    use super::*;
+    use database::InMemoryDB;
+    use state::AccountInfo;
+
+    #[test]
+    fn warm_sets_grow_with_accesses() {
+        let caller = Address::with_last_byte(0x01);
+        let contract = Address::with_last_byte(0xaa);
+        let callee = Address::with_last_byte(0xbb);
+
+        let mut db = InMemoryDB::default();
+        db.insert_account_info(contract, AccountInfo::from_balance(U256::from(1)));
+        db.insert_account_info(callee, AccountInfo::from_balance(U256::from(1)));
+        let mut journal = JournaledState::new(SpecId::CANCUN, db);
+        journal.warm_preloaded_addresses.insert(caller);
+        assert_eq!(journal.warm_addresses(), HashSet::from_iter([caller]));
+
+        // Call into the contract.
+        journal.load_account(contract).unwrap();
+        journal.checkpoint();
+        assert_eq!(
+            journal.warm_addresses(),
+            HashSet::from_iter([caller, contract])
+        );
+        assert!(journal.warm_storage_slots().is_empty());
+
+        // The same slot loaded twice is warmed once.
+        for _ in 0..2 {
+            journal.sload(contract, U256::from(1)).unwrap();
+            assert_eq!(
+                journal.warm_storage_slots(),
+                HashSet::from_iter([(contract, U256::from(1))])
+            );
+        }
+        journal.sload(contract, U256::from(2)).unwrap();
+        assert_eq!(journal.warm_storage_slots().len(), 2);
+
+        // Nested call warms the callee, until it reverts.
+        journal.load_account(callee).unwrap();
+        let nested = journal.checkpoint();
+        journal.sload(callee, U256::from(1)).unwrap();
+        assert_eq!(
+            journal.warm_addresses(),
+            HashSet::from_iter([caller, contract, callee])
+        );
+        assert_eq!(journal.warm_storage_slots().len(), 3);
+
+        // The callee was warmed by the caller, its storage access is reverted.
+        journal.checkpoint_revert(nested);
+        assert_eq!(
+            journal.warm_addresses(),
+            HashSet::from_iter([caller, contract, callee])
+        );
+        assert_eq!(journal.warm_storage_slots().len(), 2);
+        journal.checkpoint_commit();
+    }
+}
@@ -57,6 +57,18 @@ pub trait Database {
 pub trait DatabaseCommit {
     /// Commit changes to the database.
     fn commit(&mut self, changes: HashMap<Address, Account>);
+
+    /// Commit changes of the accounts matching `filter` to the database.
+    ///
+    /// Changes of accounts that do not match the filter are dropped.
+    fn commit_selective(
+        &mut self,
+        mut changes: HashMap<Address, Account>,
+        filter: impl Fn(&Address) -> bool,
+    ) {
+        changes.retain(|address, _| filter(address));
+        self.commit(changes)
+    }
 }
 
 /// EVM database interface.
@@ -0,0 +1,146 @@
+//! Applies precomputed state changes onto a database that may have diverged.
+use database_interface::{Database, DatabaseCommit};
+use primitives::{Address, U256};
+use state::EvmState;
+use std::vec::Vec;
+
+/// Base value assumed by a state diff that differs from the database.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum StateConflict {
+    /// The diff assumed the account did not exist or had no code and nonce, but it does.
+    Account {
+        /// Address of the account.
+        address: Address,
+    },
+    /// The diff assumed another original value of the storage slot.
+    Storage {
+        /// Address of the account.
+        address: Address,
+        /// Storage slot.
+        slot: U256,
+        /// Original value assumed by the diff.
+        expected: U256,
+        /// Value found in the database.
+        found: U256,
+    },
+}
+
+/// Commits `state` to `db` if the base values it was computed on match the database.
+///
+/// The original value of every storage slot of the diff is compared with the database, and
+/// accounts created or loaded as not existing by the diff must still be empty. On any
+/// mismatch nothing is committed and the conflicts are returned.
+pub fn apply_checked<DB: Database + DatabaseCommit>(
+    db: &mut DB,
+    state: EvmState,
+) -> Result<Vec<StateConflict>, DB::Error> {
+    let mut conflicts = Vec::new();
+    for (address, account) in &state {
+        if !account.is_touched() {
+            continue;
+        }
+        let info = db.basic(*address)?;
+        if account.is_created() || account.is_loaded_as_not_existing() {
+            let is_occupied = info.is_some_and(|info| {
+                info.nonce != 0 || !(info.is_empty_code_hash() || info.code_hash.is_zero())
+            });
+            let is_existing =
+                account.is_loaded_as_not_existing() && info.is_some_and(|info| !info.is_empty());
+            if is_occupied || is_existing {
+                conflicts.push(StateConflict::Account { address: *address });
+            }
+            // Storage of new accounts is assumed empty.
+            continue;
+        }
+        for (slot, value) in &account.storage {
+            let found = db.storage(*address, *slot)?;
+            if found != value.original_value() {
+                conflicts.push(StateConflict::Storage {
+                    address: *address,
+                    slot: *slot,
+                    expected: value.original_value(),
+                    found,
+                });
+            }
+        }
+    }
+
+    if conflicts.is_empty() {
+        db.commit(state);
+    }
+    Ok(conflicts)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::InMemoryDB;
+    use state::{Account, AccountInfo, EvmStorageSlot};
+
+    #[test]
+    fn reports_diverged_slots() {
+        let address = Address::with_last_byte(0xaa);
+        let mut base = InMemoryDB::default();
+        base.insert_account_info(address, AccountInfo::from_balance(U256::from(1)));
+        base.insert_account_storage(address, U256::from(1), U256::from(5))
+            .unwrap();
+
+        // Diff computed on the base: slot 1 from 5 to 6, slot 2 from 0 to 7.
+        let mut account = Account::from(AccountInfo::from_balance(U256::from(1)));
+        account.mark_touch();
+        account.storage.insert(
+            U256::from(1),
+            EvmStorageSlot::new_changed(U256::from(5), U256::from(6)),
+        );
+        account.storage.insert(
+            U256::from(2),
+            EvmStorageSlot::new_changed(U256::ZERO, U256::from(7)),
+        );
+        let state = EvmState::from_iter([(address, account)]);
+
+        let mut diverged = base.clone();
+        diverged
+            .insert_account_storage(address, U256::from(2), U256::from(3))
+            .unwrap();
+        assert_eq!(
+            apply_checked(&mut diverged, state.clone()).unwrap(),
+            vec![StateConflict::Storage {
+                address,
+                slot: U256::from(2),
+                expected: U256::ZERO,
+                found: U256::from(3),
+            }]
+        );
+        // Nothing was committed.
+        assert_eq!(
+            diverged.storage(address, U256::from(1)).unwrap(),
+            U256::from(5)
+        );
+
+        assert!(apply_checked(&mut base, state).unwrap().is_empty());
+        assert_eq!(base.storage(address, U256::from(1)).unwrap(), U256::from(6));
+        assert_eq!(base.storage(address, U256::from(2)).unwrap(), U256::from(7));
+    }
+
+    #[test]
+    fn reports_occupied_created_account() {
+        let address = Address::with_last_byte(0xaa);
+        let mut db = InMemoryDB::default();
+        db.insert_account_info(
+            address,
+            AccountInfo {
+                nonce: 1,
+                ..Default::default()
+            },
+        );
+
+        let mut account = Account::default();
+        account.mark_touch();
+        account.mark_created();
+        let state = EvmState::from_iter([(address, account)]);
+        assert_eq!(
+            apply_checked(&mut db, state).unwrap(),
+            vec![StateConflict::Account { address }]
+        );
+    }
+}
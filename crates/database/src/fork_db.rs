@@ -0,0 +1,163 @@
+//! Forking database that lazily fetches remote state through a user supplied [`ForkFetcher`].
+use crate::CacheDB;
+use core::error::Error;
+use database_interface::{DBErrorMarker, DatabaseRef};
+use primitives::{Address, B256, U256};
+use state::{AccountInfo, Bytecode};
+
+/// [Database][database_interface::Database] forking the state of a remote node.
+///
+/// Every account, storage slot, contract and block hash is fetched at most once and then served
+/// from the [`CacheDB`]. Accounts that do not exist on the remote node are cached as empty, so
+/// their storage is never fetched.
+pub type ForkDB<F> = CacheDB<ForkSource<F>>;
+
+impl<F: ForkFetcher> ForkDB<F> {
+    /// Creates a new forking database reading remote state through `fetcher`.
+    pub fn fork(fetcher: F) -> Self {
+        CacheDB::new(ForkSource::new(fetcher))
+    }
+}
+
+/// Fetches remote state for a [`ForkDB`].
+///
+/// Implement it on top of the HTTP or RPC client of your choice, usually with every request
+/// pinned to the forked block.
+pub trait ForkFetcher {
+    /// The fetcher error type.
+    type Error: DBErrorMarker + Error;
+
+    /// Fetches the account info, or `None` if the account does not exist.
+    ///
+    /// Returning the account code here avoids a separate [`ForkFetcher::fetch_code`] request.
+    fn fetch_account(&self, address: Address) -> Result<Option<AccountInfo>, Self::Error>;
+
+    /// Fetches the value of the account storage slot.
+    fn fetch_storage(&self, address: Address, index: U256) -> Result<U256, Self::Error>;
+
+    /// Fetches the contract code by its hash.
+    fn fetch_code(&self, code_hash: B256) -> Result<Bytecode, Self::Error>;
+
+    /// Fetches the hash of the block with the given number.
+    fn fetch_block_hash(&self, number: u64) -> Result<B256, Self::Error>;
+}
+
+/// Uncached [`DatabaseRef`] reading state through a [`ForkFetcher`].
+///
+/// Wrap it in a [`CacheDB`], see [`ForkDB`].
+#[derive(Clone, Debug, Default)]
+pub struct ForkSource<F> {
+    /// The fetcher used to load remote state.
+    pub fetcher: F,
+}
+
+impl<F> ForkSource<F> {
+    /// Creates a new source reading remote state through `fetcher`.
+    pub fn new(fetcher: F) -> Self {
+        Self { fetcher }
+    }
+}
+
+impl<F: ForkFetcher> DatabaseRef for ForkSource<F> {
+    type Error = F::Error;
+
+    fn basic_ref(&self, address: Address) -> Result<Option<AccountInfo>, Self::Error> {
+        self.fetcher.fetch_account(address)
+    }
+
+    fn code_by_hash_ref(&self, code_hash: B256) -> Result<Bytecode, Self::Error> {
+        self.fetcher.fetch_code(code_hash)
+    }
+
+    fn storage_ref(&self, address: Address, index: U256) -> Result<U256, Self::Error> {
+        self.fetcher.fetch_storage(address, index)
+    }
+
+    fn block_hash_ref(&self, number: u64) -> Result<B256, Self::Error> {
+        self.fetcher.fetch_block_hash(number)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use core::{cell::RefCell, convert::Infallible};
+    use database_interface::Database;
+    use primitives::HashMap;
+
+    /// Serves a single existing account and counts the requests per address.
+    #[derive(Default)]
+    struct MockFetcher {
+        existing: Address,
+        account_requests: RefCell<HashMap<Address, usize>>,
+        storage_requests: RefCell<HashMap<(Address, U256), usize>>,
+        block_hash_requests: RefCell<HashMap<u64, usize>>,
+    }
+
+    impl ForkFetcher for MockFetcher {
+        type Error = Infallible;
+
+        fn fetch_account(&self, address: Address) -> Result<Option<AccountInfo>, Self::Error> {
+            *self
+                .account_requests
+                .borrow_mut()
+                .entry(address)
+                .or_default() += 1;
+            Ok((address == self.existing).then(|| AccountInfo::from_balance(U256::from(10))))
+        }
+
+        fn fetch_storage(&self, address: Address, index: U256) -> Result<U256, Self::Error> {
+            *self
+                .storage_requests
+                .borrow_mut()
+                .entry((address, index))
+                .or_default() += 1;
+            Ok(index + U256::from(1))
+        }
+
+        fn fetch_code(&self, _code_hash: B256) -> Result<Bytecode, Self::Error> {
+            Ok(Bytecode::default())
+        }
+
+        fn fetch_block_hash(&self, number: u64) -> Result<B256, Self::Error> {
+            *self
+                .block_hash_requests
+                .borrow_mut()
+                .entry(number)
+                .or_default() += 1;
+            Ok(B256::with_last_byte(number as u8))
+        }
+    }
+
+    #[test]
+    fn fetches_each_account_once() {
+        let existing = Address::with_last_byte(1);
+        let missing = Address::with_last_byte(2);
+        let mut db = ForkDB::fork(MockFetcher {
+            existing,
+            ..Default::default()
+        });
+
+        for _ in 0..2 {
+            assert_eq!(db.basic(existing).unwrap().unwrap().balance, U256::from(10));
+            assert_eq!(db.basic(missing).unwrap(), None);
+            assert_eq!(db.storage(existing, U256::from(5)).unwrap(), U256::from(6));
+            assert_eq!(db.storage(missing, U256::from(5)).unwrap(), U256::ZERO);
+            assert_eq!(db.block_hash(7).unwrap(), B256::with_last_byte(7));
+        }
+
+        let fetcher = &db.db.fetcher;
+        assert_eq!(fetcher.account_requests.borrow()[&existing], 1);
+        assert_eq!(fetcher.account_requests.borrow()[&missing], 1);
+        assert_eq!(
+            fetcher.storage_requests.borrow()[&(existing, U256::from(5))],
+            1
+        );
+        // Missing accounts are cached as empty, their storage is never fetched.
+        assert!(!fetcher
+            .storage_requests
+            .borrow()
+            .contains_key(&(missing, U256::from(5))));
+        assert_eq!(fetcher.block_hash_requests.borrow()[&7], 1);
+    }
+}
@@ -26,6 +26,11 @@ pub struct CacheDB<ExtDB> {
     pub logs: Vec<Log>,
     /// All cached block hashes from the [DatabaseRef].
     pub block_hashes: HashMap<U256, B256>,
+    /// Maximum number of entries in `contracts`, `None` means unbounded.
+    ///
+    /// Once reached, code loaded from the underlying database is no longer cached.
+    /// Code inserted through [CacheDB::insert_contract] is always kept.
+    pub code_cache_limit: Option<usize>,
     /// The underlying database ([DatabaseRef]) that is used to load data.
     ///
     /// Note: This is read-only, data is never written to this database.
@@ -52,6 +57,7 @@ impl<ExtDb> CacheDB<CacheDB<ExtDb>> {
             contracts,
             logs,
             block_hashes,
+            code_cache_limit: _,
             db: mut inner,
         } = self;
 
@@ -79,10 +85,23 @@ impl<ExtDB> CacheDB<ExtDB> {
             contracts,
             logs: Vec::default(),
             block_hashes: HashMap::default(),
+            code_cache_limit: None,
             db,
         }
     }
 
+    /// Sets the maximum number of entries kept in the code cache.
+    pub fn with_code_cache_limit(mut self, limit: usize) -> Self {
+        self.code_cache_limit = Some(limit);
+        self
+    }
+
+    /// Returns `true` if the code cache reached its configured limit.
+    pub fn is_code_cache_full(&self) -> bool {
+        self.code_cache_limit
+            .is_some_and(|limit| self.contracts.len() >= limit)
+    }
+
     /// Inserts the account's code into the cache.
     ///
     /// Accounts objects and code are stored separately in the cache, this will take the code from the account and instead map it to the code hash.
@@ -110,6 +129,31 @@ impl<ExtDB> CacheDB<ExtDB> {
         self.accounts.entry(address).or_default().info = info;
     }
 
+    /// Shares the account's code with the code cache.
+    ///
+    /// If code with the same hash is already cached, the account references the cached
+    /// bytecode instead of its own copy, otherwise the code is cached if the limit allows it.
+    fn share_code(
+        contracts: &mut HashMap<B256, Bytecode>,
+        code_cache_limit: Option<usize>,
+        info: &mut AccountInfo,
+    ) {
+        let Some(code) = &mut info.code else {
+            return;
+        };
+        if code.is_empty() {
+            return;
+        }
+        match contracts.get(&info.code_hash) {
+            Some(cached) => *code = cached.clone(),
+            None => {
+                if !code_cache_limit.is_some_and(|limit| contracts.len() >= limit) {
+                    contracts.insert(info.code_hash, code.clone());
+                }
+            }
+        }
+    }
+
     /// Wraps the cache in a [CacheDB], creating a nested cache.
     pub fn nest(self) -> CacheDB<Self> {
         CacheDB::new(self)
@@ -126,9 +170,12 @@ impl<ExtDB: DatabaseRef> CacheDB<ExtDB> {
             Entry::Occupied(entry) => Ok(entry.into_mut()),
             Entry::Vacant(entry) => Ok(entry.insert(
                 db.basic_ref(address)?
-                    .map(|info| DbAccount {
-                        info,
-                        ..Default::default()
+                    .map(|mut info| {
+                        Self::share_code(&mut self.contracts, self.code_cache_limit, &mut info);
+                        DbAccount {
+                            info,
+                            ..Default::default()
+                        }
                     })
                     .unwrap_or_else(DbAccount::new_not_existing),
             )),
@@ -207,9 +254,12 @@ impl<ExtDB: DatabaseRef> Database for CacheDB<ExtDB> {
             Entry::Vacant(entry) => entry.insert(
                 self.db
                     .basic_ref(address)?
-                    .map(|info| DbAccount {
-                        info,
-                        ..Default::default()
+                    .map(|mut info| {
+                        Self::share_code(&mut self.contracts, self.code_cache_limit, &mut info);
+                        DbAccount {
+                            info,
+                            ..Default::default()
+                        }
                     })
                     .unwrap_or_else(DbAccount::new_not_existing),
             ),
@@ -218,13 +268,15 @@ impl<ExtDB: DatabaseRef> Database for CacheDB<ExtDB> {
     }
 
     fn code_by_hash(&mut self, code_hash: B256) -> Result<Bytecode, Self::Error> {
-        match self.contracts.entry(code_hash) {
-            Entry::Occupied(entry) => Ok(entry.get().clone()),
-            Entry::Vacant(entry) => {
-                // If you return code bytes when basic fn is called this function is not needed.
-                Ok(entry.insert(self.db.code_by_hash_ref(code_hash)?).clone())
-            }
+        if let Some(code) = self.contracts.get(&code_hash) {
+            return Ok(code.clone());
+        }
+        // If you return code bytes when basic fn is called this function is not needed.
+        let code = self.db.code_by_hash_ref(code_hash)?;
+        if !self.is_code_cache_full() {
+            self.contracts.insert(code_hash, code.clone());
         }
+        Ok(code)
     }
 
     /// Get the value in an account's storage slot.
@@ -444,9 +496,9 @@ impl Database for BenchmarkDB {
 #[cfg(test)]
 mod tests {
     use super::{CacheDB, EmptyDB};
-    use database_interface::Database;
-    use primitives::{Address, HashMap, U256};
-    use state::AccountInfo;
+    use database_interface::{Database, DatabaseCommit};
+    use primitives::{bytes, Address, HashMap, U256};
+    use state::{Account, AccountInfo, Bytecode};
 
     #[test]
     fn test_insert_account_storage() {
@@ -500,6 +552,30 @@ mod tests {
         assert_eq!(new_state.storage(account, key1), Ok(value1));
     }
 
+    #[test]
+    fn test_commit_selective() {
+        let kept = Address::with_last_byte(1);
+        let skipped = Address::with_last_byte(2);
+        let mut state = CacheDB::new(EmptyDB::default());
+
+        let changes = [kept, skipped]
+            .into_iter()
+            .map(|address| {
+                let mut account = Account::from(AccountInfo {
+                    nonce: 1,
+                    ..Default::default()
+                });
+                account.mark_touch();
+                (address, account)
+            })
+            .collect::<HashMap<_, _>>();
+        state.commit_selective(changes, |address| *address == kept);
+
+        assert_eq!(state.basic(kept).unwrap().unwrap().nonce, 1);
+        assert!(!state.accounts.contains_key(&skipped));
+        assert_eq!(state.basic(skipped).unwrap(), None);
+    }
+
     #[cfg(feature = "serde")]
     #[test]
     fn test_serialize_deserialize_cachedb() {
@@ -523,4 +599,46 @@ mod tests {
             nonce
         );
     }
+
+    #[test]
+    fn identical_code_is_cached_once() {
+        let code = bytes!("6001600055");
+        let mut inner = CacheDB::new(EmptyDB::default());
+        for address in [Address::with_last_byte(1), Address::with_last_byte(2)] {
+            // Each account gets its own copy of the code bytes.
+            let code = Bytecode::new_raw(code.to_vec().into());
+            inner.accounts.entry(address).or_default().info = AccountInfo {
+                code_hash: code.hash_slow(),
+                code: Some(code),
+                ..Default::default()
+            };
+        }
+
+        let mut db = CacheDB::new(inner);
+        let cached_before = db.contracts.len();
+        let first = db.basic(Address::with_last_byte(1)).unwrap().unwrap();
+        let second = db.basic(Address::with_last_byte(2)).unwrap().unwrap();
+
+        assert_eq!(db.contracts.len(), cached_before + 1);
+        assert_eq!(
+            first.code.unwrap().original_byte_slice().as_ptr(),
+            second.code.unwrap().original_byte_slice().as_ptr()
+        );
+    }
+
+    #[test]
+    fn code_cache_limit() {
+        let code = Bytecode::new_raw(bytes!("6001600055"));
+        let mut inner = CacheDB::new(EmptyDB::default());
+        inner.insert_account_info(
+            Address::with_last_byte(1),
+            AccountInfo::from_bytecode(code.clone()),
+        );
+
+        let mut db = CacheDB::new(inner);
+        db.code_cache_limit = Some(db.contracts.len());
+        db.basic(Address::with_last_byte(1)).unwrap();
+        assert_eq!(db.code_by_hash(code.hash_slow()).unwrap(), code);
+        assert!(!db.contracts.contains_key(&code.hash_slow()));
+    }
 }
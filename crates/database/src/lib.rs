@@ -2,14 +2,22 @@
 
 #[cfg(feature = "alloydb")]
 mod alloydb;
+#[cfg(feature = "forkdb")]
+mod fork_db;
 
+mod apply_checked;
 pub mod in_memory_db;
+mod preview_commit;
 pub mod states;
 
 #[cfg(feature = "alloydb")]
 pub use alloydb::{AlloyDB, BlockId};
+#[cfg(feature = "forkdb")]
+pub use fork_db::{ForkDB, ForkFetcher, ForkSource};
 
+pub use apply_checked::{apply_checked, StateConflict};
 pub use in_memory_db::*;
+pub use preview_commit::{CommitPreview, DbWrite};
 pub use states::{
     AccountRevert, AccountStatus, BundleAccount, BundleState, CacheState, DBBox,
     OriginalValuesKnown, PlainAccount, RevertToSlot, State, StateBuilder, StateDBBox,
@@ -0,0 +1,239 @@
+//! Write-set of a state diff, computed without touching a database.
+use bytecode::Bytecode;
+use primitives::{Address, B256, KECCAK_EMPTY, U256};
+use state::{AccountInfo, EvmState};
+use std::vec::Vec;
+
+/// Single write that committing a state diff performs on a database.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum DbWrite {
+    /// Account was selfdestructed, its info and storage are removed.
+    DeleteAccount(Address),
+    /// Bytecode is stored under its hash.
+    Code {
+        /// Hash of the bytecode.
+        code_hash: B256,
+        /// Bytecode.
+        code: Bytecode,
+    },
+    /// Account was created, its previous storage is wiped.
+    ClearStorage(Address),
+    /// Account info is written, without the bytecode.
+    Account {
+        /// Address of the account.
+        address: Address,
+        /// New account info.
+        info: AccountInfo,
+    },
+    /// Storage slot is written.
+    Storage {
+        /// Address of the account.
+        address: Address,
+        /// Storage slot.
+        slot: U256,
+        /// New value of the slot.
+        value: U256,
+    },
+}
+
+/// Previews the writes of [`DatabaseCommit::commit`](database_interface::DatabaseCommit::commit).
+pub trait CommitPreview {
+    /// Returns the writes that committing this state would perform, without performing them.
+    ///
+    /// Accounts are ordered by address and, within an account, writes follow the
+    /// [`DbWrite`] variant order with slots ordered by index. Untouched accounts and
+    /// unchanged storage slots are skipped.
+    fn preview_commit(&self) -> Vec<DbWrite>;
+}
+
+impl CommitPreview for EvmState {
+    fn preview_commit(&self) -> Vec<DbWrite> {
+        let mut accounts: Vec<_> = self
+            .iter()
+            .filter(|(_, account)| account.is_touched())
+            .collect();
+        accounts.sort_unstable_by_key(|(address, _)| **address);
+
+        let mut writes = Vec::new();
+        for (&address, account) in accounts {
+            if account.is_selfdestructed() {
+                writes.push(DbWrite::DeleteAccount(address));
+                continue;
+            }
+
+            let mut info = account.info.clone();
+            if let Some(code) = info.code.take().filter(|code| !code.is_empty()) {
+                if info.code_hash == KECCAK_EMPTY {
+                    info.code_hash = code.hash_slow();
+                }
+                writes.push(DbWrite::Code {
+                    code_hash: info.code_hash,
+                    code,
+                });
+            }
+            if info.code_hash.is_zero() {
+                info.code_hash = KECCAK_EMPTY;
+            }
+
+            if account.is_created() {
+                writes.push(DbWrite::ClearStorage(address));
+            }
+            writes.push(DbWrite::Account { address, info });
+
+            let mut slots: Vec<_> = account
+                .changed_storage_slots()
+                .map(|(slot, value)| (*slot, value.present_value()))
+                .collect();
+            slots.sort_unstable_by_key(|(slot, _)| *slot);
+            writes.extend(slots.into_iter().map(|(slot, value)| DbWrite::Storage {
+                address,
+                slot,
+                value,
+            }));
+        }
+        writes
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{CacheDB, EmptyDB};
+    use database_interface::DatabaseCommit;
+    use primitives::{Bytes, HashMap};
+    use state::{Account, EvmStorageSlot};
+
+    /// Records the writes a commit performs by diffing the cache before and after it.
+    #[derive(Default)]
+    struct RecordingDB {
+        inner: CacheDB<EmptyDB>,
+        writes: Vec<DbWrite>,
+    }
+
+    impl DatabaseCommit for RecordingDB {
+        fn commit(&mut self, changes: HashMap<Address, Account>) {
+            let before = self.inner.clone();
+            self.inner.commit(changes);
+
+            let mut addresses: Vec<_> = self.inner.accounts.keys().copied().collect();
+            addresses.sort_unstable();
+            for address in addresses {
+                let after = &self.inner.accounts[&address];
+                let prev = before.accounts.get(&address);
+                if after.info().is_none() {
+                    if prev.is_some_and(|prev| prev.info().is_some()) {
+                        self.writes.push(DbWrite::DeleteAccount(address));
+                    }
+                    continue;
+                }
+                let info = after.info.copy_without_code();
+                if !before.contracts.contains_key(&info.code_hash) {
+                    self.writes.push(DbWrite::Code {
+                        code_hash: info.code_hash,
+                        code: self.inner.contracts[&info.code_hash].clone(),
+                    });
+                }
+                if prev
+                    .and_then(|prev| prev.info())
+                    .map(AccountInfo::without_code)
+                    != Some(info.clone())
+                {
+                    self.writes.push(DbWrite::Account { address, info });
+                }
+                let mut slots: Vec<_> = after
+                    .storage
+                    .iter()
+                    .filter(|(slot, value)| {
+                        prev.and_then(|prev| prev.storage.get(*slot)) != Some(*value)
+                    })
+                    .map(|(slot, value)| (*slot, *value))
+                    .collect();
+                slots.sort_unstable_by_key(|(slot, _)| *slot);
+                self.writes
+                    .extend(slots.into_iter().map(|(slot, value)| DbWrite::Storage {
+                        address,
+                        slot,
+                        value,
+                    }));
+            }
+        }
+    }
+
+    fn touched(info: AccountInfo, storage: &[(u64, u64, u64)]) -> Account {
+        let mut account = Account::from(info);
+        account.mark_touch();
+        account.storage = storage
+            .iter()
+            .map(|&(slot, original, present)| {
+                (
+                    U256::from(slot),
+                    EvmStorageSlot::new_changed(U256::from(original), U256::from(present)),
+                )
+            })
+            .collect();
+        account
+    }
+
+    #[test]
+    fn preview_matches_recorded_writes() {
+        let updated = Address::with_last_byte(1);
+        let deployed = Address::with_last_byte(2);
+        let destroyed = Address::with_last_byte(3);
+        let untouched = Address::with_last_byte(4);
+
+        let mut db = RecordingDB::default();
+        db.inner
+            .insert_account_info(destroyed, AccountInfo::from_balance(U256::from(1)));
+        db.inner
+            .insert_account_storage(updated, U256::from(1), U256::from(5))
+            .unwrap();
+        db.inner
+            .insert_account_storage(updated, U256::from(2), U256::ZERO)
+            .unwrap();
+
+        let code = Bytecode::new_raw(Bytes::from_static(&[0x60, 0x00, 0x00]));
+        let mut state = EvmState::default();
+        state.insert(
+            updated,
+            touched(
+                AccountInfo::from_balance(U256::from(10)),
+                &[(1, 5, 6), (2, 0, 0), (3, 0, 7)],
+            ),
+        );
+        state.insert(
+            deployed,
+            touched(AccountInfo::from_bytecode(code.clone()), &[(0, 0, 1)]),
+        );
+        state.get_mut(&deployed).unwrap().mark_created();
+        state.insert(destroyed, {
+            let mut account = touched(AccountInfo::default(), &[]);
+            account.mark_selfdestruct();
+            account
+        });
+        state.insert(untouched, Account::new_not_existing());
+
+        let preview = state.preview_commit();
+        assert_eq!(
+            preview
+                .iter()
+                .filter(|write| matches!(write, DbWrite::ClearStorage(_)))
+                .collect::<Vec<_>>(),
+            [&DbWrite::ClearStorage(deployed)]
+        );
+        assert!(preview.iter().all(|write| !matches!(
+            write,
+            DbWrite::Storage { slot, .. } if *slot == U256::from(2)
+        )));
+        assert!(preview.contains(&DbWrite::Code {
+            code_hash: code.hash_slow(),
+            code,
+        }));
+
+        db.commit(state);
+        let preview: Vec<_> = preview
+            .into_iter()
+            .filter(|write| !matches!(write, DbWrite::ClearStorage(_)))
+            .collect();
+        assert_eq!(preview, db.writes);
+    }
+}
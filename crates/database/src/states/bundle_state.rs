@@ -532,6 +532,30 @@ impl BundleState {
         self.contracts.get(hash).cloned()
     }
 
+    /// Returns the present state of the accounts in ascending address order.
+    ///
+    /// Destroyed accounts are skipped. Account info is returned without code, and storage
+    /// is sorted by slot with zero values omitted, as expected by trie construction.
+    pub fn sorted_accounts(
+        &self,
+    ) -> impl Iterator<Item = (Address, AccountInfo, Vec<(U256, U256)>)> + '_ {
+        let mut addresses: Vec<_> = self.state.keys().copied().collect();
+        addresses.sort_unstable();
+
+        addresses.into_iter().filter_map(|address| {
+            let account = &self.state[&address];
+            let info = account.info.as_ref()?.copy_without_code();
+            let mut storage: Vec<_> = account
+                .storage
+                .iter()
+                .filter(|(_, slot)| !slot.present_value.is_zero())
+                .map(|(key, slot)| (*key, slot.present_value))
+                .collect();
+            storage.sort_unstable_by_key(|(key, _)| *key);
+            Some((address, info, storage))
+        })
+    }
+
     /// Consumes [`TransitionState`] by applying the changes and creating the
     /// reverts.
     ///
@@ -1193,6 +1217,49 @@ mod tests {
         assert_eq!(revert2.account, AccountInfoRevert::DoNothing);
     }
 
+    #[test]
+    fn sorted_accounts() {
+        let mut bundle = test_bundle1();
+        bundle.extend(test_bundle2());
+        // Destroyed account is skipped.
+        bundle.state.insert(
+            Address::new([0x50; 20]),
+            BundleAccount::new(
+                None,
+                None,
+                StorageWithOriginalValues::default(),
+                AccountStatus::Destroyed,
+            ),
+        );
+
+        let accounts: Vec<_> = bundle.sorted_accounts().collect();
+        assert_eq!(
+            accounts,
+            vec![
+                (
+                    account1(),
+                    AccountInfo {
+                        nonce: 3,
+                        balance: U256::from(20),
+                        code_hash: KECCAK_EMPTY,
+                        code: None,
+                    },
+                    vec![(slot1(), U256::from(15)), (slot2(), U256::from(15))],
+                ),
+                (
+                    account2(),
+                    AccountInfo {
+                        nonce: 1,
+                        balance: U256::from(10),
+                        code_hash: KECCAK_EMPTY,
+                        code: None,
+                    },
+                    vec![],
+                ),
+            ]
+        );
+    }
+
     #[test]
     fn take_reverts() {
         let bundle1 = test_bundle1();
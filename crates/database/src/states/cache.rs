@@ -125,7 +125,8 @@ impl CacheState {
         }
 
         let is_created = account.is_created();
-        let is_empty = account.is_empty();
+        // Retained empty accounts are changed like any other account.
+        let is_empty = account.is_empty() && !account.is_retained_empty();
 
         // Transform evm storage to storage with previous value.
         let changed_storage = account
@@ -0,0 +1,44 @@
+use crate::EvmCommit;
+use context_interface::{
+    result::{ExecutionResult, HaltReasonTrait},
+    ExecutionReceipt,
+};
+use primitives::Bloom;
+use std::vec::Vec;
+
+/// Receipts of the transactions of a block, see [`execute_block`].
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct BlockReceipts {
+    /// Receipt of every transaction, in execution order.
+    pub receipts: Vec<ExecutionReceipt>,
+    /// Gas used by all transactions of the block.
+    pub gas_used: u64,
+    /// Bloom filter over the logs of the block, the union of the receipt blooms.
+    pub logs_bloom: Bloom,
+}
+
+/// Executes `txs` in order on top of `block` and commits each of them.
+///
+/// Returns the receipts of the block, or the first error encountered. Transactions executed
+/// before the error stay committed.
+pub fn execute_block<EVM, HALT, ERROR>(
+    evm: &mut EVM,
+    block: EVM::Block,
+    txs: impl IntoIterator<Item = EVM::Transaction>,
+) -> Result<BlockReceipts, ERROR>
+where
+    EVM: EvmCommit<CommitOutput = Result<ExecutionResult<HALT>, ERROR>>,
+    HALT: HaltReasonTrait,
+{
+    evm.set_block(block);
+
+    let mut output = BlockReceipts::default();
+    for tx in txs {
+        let mut receipt = ExecutionReceipt::from(evm.exec_commit_with_tx(tx)?);
+        output.gas_used += receipt.gas_used;
+        receipt.cumulative_gas_used = output.gas_used;
+        output.logs_bloom.accrue_bloom(&receipt.logs_bloom);
+        output.receipts.push(receipt);
+    }
+    Ok(output)
+}
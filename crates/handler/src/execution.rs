@@ -46,6 +46,13 @@ where
         context: &mut Self::Context,
         gas_limit: u64,
     ) -> Result<FrameOrFrameResult<Self::Frame>, Self::Error> {
+        // Without gas metering the first frame gets unlimited gas.
+        let gas_limit = if context.cfg().is_gas_metering_disabled() {
+            u64::MAX
+        } else {
+            gas_limit
+        };
+
         // Make new frame action.
         let spec = context.cfg().spec().into();
         let tx = context.tx();
@@ -55,6 +62,7 @@ where
             TxKind::Call(target_address) => FrameInput::Call(Box::new(CallInputs {
                 input,
                 gas_limit,
+                stipend: 0,
                 target_address,
                 bytecode_address: target_address,
                 caller: tx.common_fields().caller(),
@@ -97,6 +105,12 @@ where
         let remaining = gas.remaining();
         let refunded = gas.refunded();
 
+        // Nothing is spent when gas is not metered.
+        if context.cfg().is_gas_metering_disabled() {
+            *gas = Gas::new(context.tx().common_fields().gas_limit());
+            return Ok(frame_result);
+        }
+
         // Spend the gas limit. Gas is reimbursed when the tx returns successfully.
         *gas = Gas::new_spent(context.tx().common_fields().gas_limit());
 
@@ -112,6 +126,14 @@ where
     }
 }
 
+impl<CTX, ERROR, FRAME> Clone for EthExecution<CTX, ERROR, FRAME> {
+    fn clone(&self) -> Self {
+        Self {
+            _phantom: core::marker::PhantomData,
+        }
+    }
+}
+
 impl<CTX, ERROR, FRAME> EthExecution<CTX, ERROR, FRAME> {
     pub fn new() -> Self {
         Self {
@@ -12,9 +12,9 @@ use interpreter::{
     interpreter::{EthInterpreter, InstructionProvider},
     interpreter_types::{LoopControl, ReturnData, RuntimeFlag},
     return_ok, return_revert, CallInputs, CallOutcome, CallValue, CreateInputs, CreateOutcome,
-    CreateScheme, EOFCreateInputs, EOFCreateKind, FrameInput, Gas, Host, InputsImpl,
-    InstructionResult, Interpreter, InterpreterAction, InterpreterResult, InterpreterTypes,
-    SharedMemory,
+    CreateScheme, EOFCreateInputs, EOFCreateKind, EmptyAccountCallResult, FrameInfo, FrameInput,
+    Gas, Host, InputsImpl, InstructionResult, Interpreter, InterpreterAction, InterpreterResult,
+    InterpreterTypes, SharedMemory,
 };
 use precompile::PrecompileErrors;
 use primitives::{keccak256, Address, Bytes, B256, U256};
@@ -68,6 +68,11 @@ where
             memory,
         }
     }
+
+    /// Returns the data of the frame.
+    pub fn data(&self) -> &FrameData {
+        &self.data
+    }
 }
 
 impl<CTX, ERROR, PRECOMPILE, INSTRUCTION>
@@ -77,6 +82,42 @@ where
     ERROR: EthFrameError<CTX>,
     PRECOMPILE: PrecompileProvider<Context = CTX, Error = ERROR>,
 {
+    /// Converts the result of a call intercepted by the host into a frame result.
+    fn intercepted_call_result(
+        context: &mut CTX,
+        checkpoint: JournalCheckpoint,
+        inputs: &CallInputs,
+        call: EmptyAccountCallResult,
+    ) -> FrameOrResultGen<Self, FrameResult> {
+        let mut gas = Gas::new(inputs.gas_limit);
+        if context.cfg().is_gas_metering_disabled() {
+            gas.set_unmetered();
+        }
+        let mut output = call.output;
+        let result = if !gas.record_cost(call.gas_used) {
+            gas.spend_all();
+            output = Bytes::new();
+            InstructionResult::OutOfGas
+        } else if call.success {
+            InstructionResult::Return
+        } else {
+            InstructionResult::Revert
+        };
+        if result.is_ok() {
+            context.journal().checkpoint_commit();
+        } else {
+            context.journal().checkpoint_revert(checkpoint);
+        }
+        FrameOrResultGen::Result(FrameResult::Call(CallOutcome::new(
+            InterpreterResult {
+                result,
+                gas,
+                output,
+            },
+            inputs.return_memory_offset.clone(),
+        )))
+    }
+
     /// Make call frame
     #[inline]
     pub fn make_call_frame(
@@ -90,14 +131,16 @@ where
         let gas = Gas::new(inputs.gas_limit);
 
         let return_result = |instruction_result: InstructionResult| {
-            Ok(FrameOrResultGen::Result(FrameResult::Call(CallOutcome {
-                result: InterpreterResult {
-                    result: instruction_result,
-                    gas,
-                    output: Bytes::new(),
-                },
-                memory_offset: inputs.return_memory_offset.clone(),
-            })))
+            Ok(FrameOrResultGen::Result(FrameResult::Call(
+                CallOutcome::new(
+                    InterpreterResult {
+                        result: instruction_result,
+                        gas,
+                        output: Bytes::new(),
+                    },
+                    inputs.return_memory_offset.clone(),
+                ),
+            )))
         };
 
         // Check depth
@@ -127,22 +170,40 @@ where
             }
         }
 
+        // Precompiles can't run out of gas when gas is not metered.
+        let precompile_gas_limit = if context.cfg().is_gas_metering_disabled() {
+            u64::MAX
+        } else {
+            inputs.gas_limit
+        };
         if let Some(result) = precompile.run(
             context,
             &inputs.bytecode_address,
             &inputs.input,
-            inputs.gas_limit,
+            precompile_gas_limit,
         )? {
             if result.result.is_ok() {
                 context.journal().checkpoint_commit();
             } else {
                 context.journal().checkpoint_revert(checkpoint);
             }
-            Ok(FrameOrResultGen::Result(FrameResult::Call(CallOutcome {
-                result,
-                memory_offset: inputs.return_memory_offset.clone(),
-            })))
+            Ok(FrameOrResultGen::Result(FrameResult::Call(
+                CallOutcome::new(result, inputs.return_memory_offset.clone()),
+            )))
         } else {
+            if inputs.bytecode_address == Address::ZERO {
+                if let Some(call) = context.call_zero_address(
+                    inputs.caller,
+                    inputs.call_value(),
+                    &inputs.input,
+                    inputs.gas_limit,
+                ) {
+                    return Ok(Self::intercepted_call_result(
+                        context, checkpoint, inputs, call,
+                    ));
+                }
+            }
+
             let account = context
                 .journal()
                 .load_account_code(inputs.bytecode_address)?;
@@ -159,8 +220,17 @@ where
             }
 
             if bytecode.is_empty() {
-                context.journal().checkpoint_commit();
-                return return_result(InstructionResult::Stop);
+                let Some(call) = context.call_empty_account(
+                    inputs.bytecode_address,
+                    &inputs.input,
+                    inputs.gas_limit,
+                ) else {
+                    context.journal().checkpoint_commit();
+                    return return_result(InstructionResult::Stop);
+                };
+                return Ok(Self::intercepted_call_result(
+                    context, checkpoint, inputs, call,
+                ));
             }
 
             if let Bytecode::Eip7702(eip7702_bytecode) = bytecode {
@@ -173,6 +243,16 @@ where
                     .unwrap_or_default();
             }
 
+            context.push_call_frame(
+                depth,
+                FrameInfo {
+                    address: inputs.target_address,
+                    caller: inputs.caller,
+                    scheme: Some(inputs.scheme),
+                    value: inputs.call_value(),
+                },
+            );
+
             // Create interpreter and executes call and push new CallStackFrame.
             let interpreter_input = InputsImpl {
                 target_address: inputs.target_address,
@@ -216,14 +296,14 @@ where
         let spec = context.cfg().spec().into();
         let return_error = |e| {
             Ok(FrameOrResultGen::Result(FrameResult::Create(
-                CreateOutcome {
-                    result: InterpreterResult {
+                CreateOutcome::new(
+                    InterpreterResult {
                         result: e,
                         gas: Gas::new(inputs.gas_limit),
                         output: Bytes::new(),
                     },
-                    address: None,
-                },
+                    None,
+                ),
             )))
         };
 
@@ -248,19 +328,22 @@ where
             return return_error(InstructionResult::OutOfFunds);
         }
 
-        // Increase nonce of caller and check if it overflows
+        // Increase nonce of caller and check if it overflows.
+        // EIP-2681: creates from a caller at the maximum nonce halt.
         let old_nonce;
         if let Some(nonce) = context.journal().inc_account_nonce(inputs.caller)? {
             old_nonce = nonce - 1;
         } else {
-            return return_error(InstructionResult::Return);
+            return return_error(InstructionResult::NonceOverflow);
         }
 
         // Create address
         // TODO : Incorporating code hash inside interpreter. It was a request by foundry.
         let mut _init_code_hash = B256::ZERO;
         let created_address = match inputs.scheme {
-            CreateScheme::Create => inputs.caller.create(old_nonce),
+            CreateScheme::Create => inputs
+                .caller
+                .create(context.cfg().create_nonce_override().unwrap_or(old_nonce)),
             CreateScheme::Create2 { salt } => {
                 _init_code_hash = keccak256(&inputs.init_code);
                 inputs.caller.create2(salt.to_be_bytes(), _init_code_hash)
@@ -283,6 +366,16 @@ where
 
         let bytecode = Bytecode::new_legacy(inputs.init_code.clone());
 
+        context.push_call_frame(
+            depth,
+            FrameInfo {
+                address: created_address,
+                caller: inputs.caller,
+                scheme: None,
+                value: inputs.value,
+            },
+        );
+
         let interpreter_input = InputsImpl {
             target_address: created_address,
             caller_address: inputs.caller,
@@ -322,14 +415,14 @@ where
         let spec = context.cfg().spec().into();
         let return_error = |e| {
             Ok(FrameOrResultGen::Result(FrameResult::EOFCreate(
-                CreateOutcome {
-                    result: InterpreterResult {
+                CreateOutcome::new(
+                    InterpreterResult {
                         result: e,
                         gas: Gas::new(inputs.gas_limit),
                         output: Bytes::new(),
                     },
-                    address: None,
-                },
+                    None,
+                ),
             )))
         };
 
@@ -400,6 +493,16 @@ where
             Err(e) => return return_error(e.into()),
         };
 
+        context.push_call_frame(
+            depth,
+            FrameInfo {
+                address: created_address,
+                caller: inputs.caller,
+                scheme: None,
+                value: inputs.value,
+            },
+        );
+
         let interpreter_input = InputsImpl {
             target_address: created_address,
             caller_address: inputs.caller,
@@ -434,7 +537,7 @@ where
         instructions: INSTRUCTION,
         context: &mut CTX,
     ) -> Result<FrameOrResultGen<Self, FrameResult>, ERROR> {
-        match frame_init {
+        let mut ret = match frame_init {
             FrameInput::Call(inputs) => {
                 Self::make_call_frame(context, depth, memory, &inputs, precompile, instructions)
             }
@@ -449,7 +552,105 @@ where
                 precompile,
                 instructions,
             ),
+        };
+
+        // Gas is not charged in any frame, so explicit call gas and stipends can't run out.
+        if context.cfg().is_gas_metering_disabled() {
+            if let Ok(FrameOrResultGen::Frame(frame)) = &mut ret {
+                frame.interpreter.control.gas.set_unmetered();
+            }
         }
+        ret
+    }
+}
+
+impl<CTX, ERROR, PRECOMPILE, INSTRUCTION>
+    EthFrame<CTX, ERROR, EthInterpreter<()>, PRECOMPILE, INSTRUCTION>
+where
+    CTX: EthFrameContext<ERROR>,
+    ERROR: EthFrameError<CTX>,
+    PRECOMPILE: PrecompileProvider<Context = CTX, Error = ERROR>,
+    INSTRUCTION: InstructionProvider<WIRE = EthInterpreter<()>, Host = CTX>,
+{
+    /// Handles the action returned by the interpreter of this frame.
+    ///
+    /// Returns the new frame to run, or commits or reverts the frame changes and returns its
+    /// result.
+    pub fn process_next_action(
+        &mut self,
+        context: &mut CTX,
+        next_action: InterpreterAction,
+    ) -> Result<FrameOrResultGen<FrameInput, FrameResult>, ERROR> {
+        let spec = context.cfg().spec().into();
+
+        let mut interpreter_result = match next_action {
+            InterpreterAction::NewFrame(new_frame) => {
+                return Ok(FrameOrResultGen::Frame(new_frame))
+            }
+            InterpreterAction::Return { result } => result,
+            InterpreterAction::None => unreachable!("InterpreterAction::None is not expected"),
+        };
+        context.pop_call_frame();
+
+        // Handle return from frame
+        let result = match &self.data {
+            FrameData::Call(frame) => {
+                // return_call
+                // Revert changes or not.
+                if interpreter_result.result.is_ok() || context.cfg().is_state_kept_on_revert() {
+                    context.journal().checkpoint_commit();
+                } else {
+                    context.journal().checkpoint_revert(self.checkpoint);
+                }
+                FrameOrResultGen::Result(FrameResult::Call(CallOutcome::new(
+                    interpreter_result,
+                    frame.return_memory_range.clone(),
+                )))
+            }
+            FrameData::Create(frame) => {
+                let max_code_size = context.cfg().max_code_size();
+                if !interpreter_result.result.is_ok() && context.cfg().is_state_kept_on_revert() {
+                    context.journal().checkpoint_commit();
+                } else {
+                    return_create(
+                        context.journal(),
+                        self.checkpoint,
+                        &mut interpreter_result,
+                        frame.created_address,
+                        max_code_size,
+                        spec,
+                    );
+                }
+
+                FrameOrResultGen::Result(FrameResult::Create(CreateOutcome::new(
+                    interpreter_result,
+                    Some(frame.created_address),
+                )))
+            }
+            FrameData::EOFCreate(frame) => {
+                let max_code_size = context.cfg().max_code_size();
+                if interpreter_result.result != InstructionResult::ReturnContract
+                    && context.cfg().is_state_kept_on_revert()
+                {
+                    context.journal().checkpoint_commit();
+                } else {
+                    return_eofcreate(
+                        context.journal(),
+                        self.checkpoint,
+                        &mut interpreter_result,
+                        frame.created_address,
+                        max_code_size,
+                    );
+                }
+
+                FrameOrResultGen::Result(FrameResult::EOFCreate(CreateOutcome::new(
+                    interpreter_result,
+                    Some(frame.created_address),
+                )))
+            }
+        };
+
+        Ok(result)
     }
 }
 
@@ -510,68 +711,9 @@ where
         &mut self,
         context: &mut Self::Context,
     ) -> Result<FrameOrResultGen<Self::FrameInit, Self::FrameResult>, Self::Error> {
-        let spec = context.cfg().spec().into();
-
         // Run interpreter
         let next_action = self.interpreter.run(self.instructions.table(), context);
-
-        let mut interpreter_result = match next_action {
-            InterpreterAction::NewFrame(new_frame) => {
-                return Ok(FrameOrResultGen::Frame(new_frame))
-            }
-            InterpreterAction::Return { result } => result,
-            InterpreterAction::None => unreachable!("InterpreterAction::None is not expected"),
-        };
-
-        // Handle return from frame
-        let result = match &self.data {
-            FrameData::Call(frame) => {
-                // return_call
-                // Revert changes or not.
-                if interpreter_result.result.is_ok() {
-                    context.journal().checkpoint_commit();
-                } else {
-                    context.journal().checkpoint_revert(self.checkpoint);
-                }
-                FrameOrResultGen::Result(FrameResult::Call(CallOutcome::new(
-                    interpreter_result,
-                    frame.return_memory_range.clone(),
-                )))
-            }
-            FrameData::Create(frame) => {
-                let max_code_size = context.cfg().max_code_size();
-                return_create(
-                    context.journal(),
-                    self.checkpoint,
-                    &mut interpreter_result,
-                    frame.created_address,
-                    max_code_size,
-                    spec,
-                );
-
-                FrameOrResultGen::Result(FrameResult::Create(CreateOutcome::new(
-                    interpreter_result,
-                    Some(frame.created_address),
-                )))
-            }
-            FrameData::EOFCreate(frame) => {
-                let max_code_size = context.cfg().max_code_size();
-                return_eofcreate(
-                    context.journal(),
-                    self.checkpoint,
-                    &mut interpreter_result,
-                    frame.created_address,
-                    max_code_size,
-                );
-
-                FrameOrResultGen::Result(FrameResult::EOFCreate(CreateOutcome::new(
-                    interpreter_result,
-                    Some(frame.created_address),
-                )))
-            }
-        };
-
-        Ok(result)
+        self.process_next_action(context, next_action)
     }
 
     fn return_result(
@@ -7,6 +7,8 @@ extern crate alloc as std;
 
 // Mainnet related handlers.
 
+mod block;
+mod exec;
 mod execution;
 mod frame;
 mod frame_data;
@@ -17,12 +19,18 @@ mod validation;
 
 // Public exports
 
+pub use block::{execute_block, BlockReceipts};
+pub use exec::{EvmCommit, EvmExec};
 pub use execution::{EthExecution, EthExecutionContext, EthExecutionError};
 pub use frame::{return_create, return_eofcreate, EthFrame, EthFrameContext, EthFrameError};
 pub use frame_data::{FrameData, FrameResult};
-pub use post_execution::{EthPostExecution, EthPostExecutionContext, EthPostExecutionError};
+pub use post_execution::{
+    reimburse_caller_balance, EthPostExecution, EthPostExecutionContext, EthPostExecutionError,
+    ReimburseCallerHook, RevertGasHook,
+};
 pub use pre_execution::{
-    apply_eip7702_auth_list, EthPreExecution, EthPreExecutionContext, EthPreExecutionError,
+    apply_eip7702_auth_list, deduct_caller_balance, DeductCallerHook, EthPreExecution,
+    EthPreExecutionContext, EthPreExecutionError,
 };
 use precompile::PrecompileErrors;
 pub use precompile_provider::EthPrecompileProvider;
@@ -30,9 +38,9 @@ use primitives::Log;
 use state::EvmState;
 use std::vec::Vec;
 pub use validation::{
-    validate_eip4844_tx, validate_initial_tx_gas, validate_priority_fee_tx,
-    validate_tx_against_account, validate_tx_env, EthValidation, EthValidationContext,
-    EthValidationError,
+    calculate_intrinsic_gas, validate_eip4844_tx, validate_initial_tx_gas,
+    validate_priority_fee_tx, validate_tx_against_account, validate_tx_env, EthValidation,
+    EthValidationContext, EthValidationError,
 };
 
 // Imports
@@ -65,6 +73,25 @@ pub struct EthHandler<
     _phantom: core::marker::PhantomData<fn() -> (CTX, ERROR)>,
 }
 
+impl<CTX, ERROR, VAL, PREEXEC, EXEC, POSTEXEC> Clone
+    for EthHandler<CTX, ERROR, VAL, PREEXEC, EXEC, POSTEXEC>
+where
+    VAL: Clone,
+    PREEXEC: Clone,
+    EXEC: Clone,
+    POSTEXEC: Clone,
+{
+    fn clone(&self) -> Self {
+        Self {
+            validation: self.validation.clone(),
+            pre_execution: self.pre_execution.clone(),
+            execution: self.execution.clone(),
+            post_execution: self.post_execution.clone(),
+            _phantom: core::marker::PhantomData,
+        }
+    }
+}
+
 impl<CTX, ERROR> Default for EthHandler<CTX, ERROR> {
     fn default() -> Self {
         Self {
@@ -77,6 +104,23 @@ impl<CTX, ERROR> Default for EthHandler<CTX, ERROR> {
     }
 }
 
+impl<CTX, ERROR> EthHandler<CTX, ERROR> {
+    /// Charges the gas cost of transactions with `deduct` and reimburses the unused gas with
+    /// `reimburse`, so that the gas can be paid by an account other than the caller.
+    ///
+    /// The caller balance is then only checked against the transferred value.
+    pub fn with_gas_payment_hooks(
+        mut self,
+        deduct: DeductCallerHook<CTX, ERROR>,
+        reimburse: ReimburseCallerHook<CTX, ERROR>,
+    ) -> Self {
+        self.validation.skip_gas_cost_check = true;
+        self.pre_execution.deduct_caller_hook = Some(deduct);
+        self.post_execution.reimburse_caller_hook = Some(reimburse);
+        self
+    }
+}
+
 impl<CTX, ERROR, VAL, PREEXEC, EXEC, POSTEXEC>
     EthHandler<CTX, ERROR, VAL, PREEXEC, EXEC, POSTEXEC>
 {
@@ -104,7 +148,7 @@ where
         + JournalGetter
         + CfgGetter
         + ErrorGetter<Error = ERROR>
-        + JournalGetter<Journal: Journal<FinalOutput = (EvmState, Vec<Log>)>>
+        + JournalGetter<Journal: Journal<FinalOutput = (EvmState, Vec<Log>, Vec<usize>)>>
         + Host,
     ERROR: From<InvalidTransaction>
         + From<InvalidHeader>
@@ -5,27 +5,73 @@ use context_interface::{
     TransactionGetter,
 };
 use handler_interface::PostExecutionHandler;
-use interpreter::SuccessOrHalt;
-use primitives::{Log, U256};
+use interpreter::{Gas, SuccessOrHalt};
+use primitives::{Address, Log, U256};
 use specification::hardfork::SpecId;
 use state::EvmState;
 use std::{boxed::Box, vec::Vec};
 
 use super::frame_data::FrameResult;
 
+/// Returns the gas charged for a reverted transaction.
+///
+/// It is given the transaction gas after the revert, where the unused gas is not spent.
+/// The result is capped to the transaction gas limit.
+pub type RevertGasHook<CTX> = fn(&CTX, &Gas) -> u64;
+
+/// Credits the value of the unused gas `amount` to the `payer` of a transaction.
+///
+/// [`EthPostExecution`] passes the caller of the transaction as payer.
+pub type ReimburseCallerHook<CTX, ERROR> = fn(&mut CTX, Address, U256) -> Result<(), ERROR>;
+
 #[derive(Default)]
 pub struct EthPostExecution<CTX, ERROR, HALTREASON> {
+    /// Decides the gas charged for reverted transactions, the rest being reimbursed.
+    ///
+    /// By default, it is set to `None` and the unused gas is reimbursed as on Ethereum.
+    pub revert_gas_hook: Option<RevertGasHook<CTX>>,
+    /// Replaces the reimbursement of the unused gas to the caller balance.
+    ///
+    /// Pairs with [`EthPreExecution::deduct_caller_hook`](crate::EthPreExecution::deduct_caller_hook)
+    /// to refund the account that paid for the gas.
+    ///
+    /// By default, it is set to `None` and [`reimburse_caller_balance`] is used.
+    pub reimburse_caller_hook: Option<ReimburseCallerHook<CTX, ERROR>>,
     pub _phantom: core::marker::PhantomData<(CTX, ERROR, HALTREASON)>,
 }
 
+impl<CTX, ERROR, HALTREASON> Clone for EthPostExecution<CTX, ERROR, HALTREASON> {
+    fn clone(&self) -> Self {
+        Self {
+            revert_gas_hook: self.revert_gas_hook,
+            reimburse_caller_hook: self.reimburse_caller_hook,
+            _phantom: core::marker::PhantomData,
+        }
+    }
+}
+
 impl<CTX, ERROR, HALTREASON> EthPostExecution<CTX, ERROR, HALTREASON> {
     /// Create new instance of post execution handler.
     pub fn new() -> Self {
         Self {
+            revert_gas_hook: None,
+            reimburse_caller_hook: None,
             _phantom: core::marker::PhantomData,
         }
     }
 
+    /// Sets the hook deciding the gas charged for reverted transactions.
+    pub fn with_revert_gas_hook(mut self, hook: RevertGasHook<CTX>) -> Self {
+        self.revert_gas_hook = Some(hook);
+        self
+    }
+
+    /// Sets the hook reimbursing the unused gas.
+    pub fn with_reimburse_caller_hook(mut self, hook: ReimburseCallerHook<CTX, ERROR>) -> Self {
+        self.reimburse_caller_hook = Some(hook);
+        self
+    }
+
     /// Create new boxed instance of post execution handler.
     ///
     /// Boxed instance is useful to erase FORK type.
@@ -34,6 +80,82 @@ impl<CTX, ERROR, HALTREASON> EthPostExecution<CTX, ERROR, HALTREASON> {
     }
 }
 
+impl<CTX, ERROR, HALTREASON> EthPostExecution<CTX, ERROR, HALTREASON>
+where
+    CTX: EthPostExecutionContext<ERROR>,
+    ERROR: EthPostExecutionError<CTX>,
+{
+    /// Credits the caller with the value of the unused and refunded gas.
+    ///
+    /// Returns the value credited.
+    pub fn reimburse_caller_amount(
+        &self,
+        context: &mut CTX,
+        exec_result: &FrameResult,
+    ) -> Result<U256, ERROR> {
+        let basefee = context.block().basefee() as u128;
+        let caller = context.tx().common_fields().caller();
+        let effective_gas_price = context.tx().effective_gas_price(basefee);
+        let gas = exec_result.gas();
+
+        // Return balance of not spend gas.
+        let reimbursed = U256::from(
+            effective_gas_price.saturating_mul((gas.remaining() + gas.refunded() as u64) as u128),
+        );
+        let reimburse = self
+            .reimburse_caller_hook
+            .unwrap_or(reimburse_caller_balance::<CTX, ERROR>);
+        reimburse(context, caller, reimbursed)?;
+
+        Ok(reimbursed)
+    }
+
+    /// Transfers the fee of the used gas to the block beneficiary.
+    ///
+    /// Returns the value credited.
+    pub fn reward_beneficiary_amount(
+        &self,
+        context: &mut CTX,
+        exec_result: &FrameResult,
+    ) -> Result<U256, ERROR> {
+        let block = context.block();
+        let tx = context.tx();
+        let beneficiary = block.beneficiary();
+        let basefee = block.basefee() as u128;
+        let effective_gas_price = tx.effective_gas_price(basefee);
+        let gas = exec_result.gas();
+
+        // Transfer fee to coinbase/beneficiary.
+        // EIP-1559 discard basefee for coinbase transfer. Basefee amount of gas is discarded.
+        let coinbase_gas_price = if context.cfg().spec().into().is_enabled_in(SpecId::LONDON) {
+            effective_gas_price.saturating_sub(basefee)
+        } else {
+            effective_gas_price
+        };
+        let reward = U256::from(coinbase_gas_price * (gas.spent() - gas.refunded() as u64) as u128);
+
+        let coinbase_account = context.journal().load_account(beneficiary)?;
+
+        coinbase_account.data.mark_touch();
+        coinbase_account.data.info.balance =
+            coinbase_account.data.info.balance.saturating_add(reward);
+
+        Ok(reward)
+    }
+}
+
+/// Adds `amount` to the balance of `payer`, saturating at the maximum.
+#[inline]
+pub fn reimburse_caller_balance<CTX: JournalGetter, ERROR: From<JournalDBError<CTX>>>(
+    context: &mut CTX,
+    payer: Address,
+    amount: U256,
+) -> Result<(), ERROR> {
+    let account = context.journal().load_account(payer)?.data;
+    account.info.balance = account.info.balance.saturating_add(amount);
+    Ok(())
+}
+
 impl<CTX, ERROR, HALTREASON> PostExecutionHandler for EthPostExecution<CTX, ERROR, HALTREASON>
 where
     CTX: EthPostExecutionContext<ERROR>,
@@ -51,13 +173,23 @@ where
         exec_result: &mut Self::ExecResult,
         eip7702_refund: i64,
     ) {
+        if let Some(hook) = self.revert_gas_hook {
+            if exec_result.interpreter_result().result.is_revert() {
+                let gas = exec_result.gas_mut();
+                let limit = gas.limit();
+                let charged = hook(context, gas).min(limit);
+                *gas = Gas::new_spent(limit);
+                gas.erase_cost(limit - charged);
+            }
+        }
+
         let gas = exec_result.gas_mut();
         gas.record_refund(eip7702_refund);
 
         // Calculate gas refund for transaction.
-        // If spec is set to london, it will decrease the maximum refund amount to 5th part of
-        // gas spend. (Before london it was 2th part of gas spend)
-        gas.set_final_refund(context.cfg().spec().into().is_enabled_in(SpecId::LONDON));
+        // By default, from london the maximum refund amount is 5th part of gas spend.
+        // (Before london it was 2th part of gas spend)
+        gas.set_final_refund_with_quotient(context.cfg().max_refund_quotient());
     }
 
     fn reimburse_caller(
@@ -65,23 +197,8 @@ where
         context: &mut Self::Context,
         exec_result: &mut Self::ExecResult,
     ) -> Result<(), Self::Error> {
-        let basefee = context.block().basefee() as u128;
-        let caller = context.tx().common_fields().caller();
-        let effective_gas_price = context.tx().effective_gas_price(basefee);
-        let gas = exec_result.gas();
-
-        // Return balance of not spend gas.
-        let caller_account = context.journal().load_account(caller)?;
-
-        let reimbursed =
-            effective_gas_price.saturating_mul((gas.remaining() + gas.refunded() as u64) as u128);
-        caller_account.data.info.balance = caller_account
-            .data
-            .info
-            .balance
-            .saturating_add(U256::from(reimbursed));
-
-        Ok(())
+        self.reimburse_caller_amount(context, exec_result)
+            .map(|_| ())
     }
 
     fn reward_beneficiary(
@@ -89,34 +206,8 @@ where
         context: &mut Self::Context,
         exec_result: &mut Self::ExecResult,
     ) -> Result<(), Self::Error> {
-        let block = context.block();
-        let tx = context.tx();
-        let beneficiary = block.beneficiary();
-        let basefee = block.basefee() as u128;
-        let effective_gas_price = tx.effective_gas_price(basefee);
-        let gas = exec_result.gas();
-
-        // Transfer fee to coinbase/beneficiary.
-        // EIP-1559 discard basefee for coinbase transfer. Basefee amount of gas is discarded.
-        let coinbase_gas_price = if context.cfg().spec().into().is_enabled_in(SpecId::LONDON) {
-            effective_gas_price.saturating_sub(basefee)
-        } else {
-            effective_gas_price
-        };
-
-        let coinbase_account = context.journal().load_account(beneficiary)?;
-
-        coinbase_account.data.mark_touch();
-        coinbase_account.data.info.balance =
-            coinbase_account
-                .data
-                .info
-                .balance
-                .saturating_add(U256::from(
-                    coinbase_gas_price * (gas.spent() - gas.refunded() as u64) as u128,
-                ));
-
-        Ok(())
+        self.reward_beneficiary_amount(context, exec_result)
+            .map(|_| ())
     }
 
     fn output(
@@ -133,7 +224,16 @@ where
         let instruction_result = result.into_interpreter_result();
 
         // Reset journal and return present state.
-        let (state, logs) = context.journal().finalize()?;
+        let (mut state, logs, log_depths) = context.journal().finalize()?;
+
+        // Keep touched empty accounts from being removed by EIP-161.
+        if context.cfg().is_empty_account_retained() {
+            for account in state.values_mut() {
+                if account.is_touched() && !account.is_selfdestructed() && account.is_empty() {
+                    account.mark_retained_empty();
+                }
+            }
+        }
 
         let result = match SuccessOrHalt::<HALTREASON>::from(instruction_result.result) {
             SuccessOrHalt::Success(reason) => ExecutionResult::Success {
@@ -141,6 +241,7 @@ where
                 gas_used: final_gas_used,
                 gas_refunded,
                 logs,
+                log_depths,
                 output,
             },
             SuccessOrHalt::Revert => ExecutionResult::Revert {
@@ -178,7 +279,7 @@ pub trait EthPostExecutionContext<ERROR>:
     TransactionGetter
     + ErrorGetter<Error = ERROR>
     + BlockGetter
-    + JournalGetter<Journal: Journal<FinalOutput = (EvmState, Vec<Log>)>>
+    + JournalGetter<Journal: Journal<FinalOutput = (EvmState, Vec<Log>, Vec<usize>)>>
     + CfgGetter
 {
 }
@@ -188,7 +289,7 @@ impl<
         CTX: TransactionGetter
             + ErrorGetter<Error = ERROR>
             + BlockGetter
-            + JournalGetter<Journal: Journal<FinalOutput = (EvmState, Vec<Log>)>>
+            + JournalGetter<Journal: Journal<FinalOutput = (EvmState, Vec<Log>, Vec<usize>)>>
             + CfgGetter,
     > EthPostExecutionContext<ERROR> for CTX
 {
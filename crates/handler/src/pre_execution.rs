@@ -16,14 +16,37 @@ use primitives::{Address, BLOCKHASH_STORAGE_ADDRESS, U256};
 use specification::{eip7702, hardfork::SpecId};
 use std::{boxed::Box, vec::Vec};
 
+/// Charges the upfront gas cost `amount` to the `payer` of a transaction.
+///
+/// [`EthPreExecution`] passes the caller of the transaction as payer.
+pub type DeductCallerHook<CTX, ERROR> = fn(&mut CTX, Address, U256) -> Result<(), ERROR>;
+
 #[derive(Default)]
 pub struct EthPreExecution<CTX, ERROR> {
+    /// Replaces the deduction of the gas cost from the caller balance.
+    ///
+    /// Bumping the caller nonce is not affected. Unused gas is reimbursed by
+    /// [`EthPostExecution::reimburse_caller_hook`](crate::EthPostExecution::reimburse_caller_hook),
+    /// see [`EthHandler::with_gas_payment_hooks`](crate::EthHandler::with_gas_payment_hooks).
+    ///
+    /// By default, it is set to `None` and [`deduct_caller_balance`] is used.
+    pub deduct_caller_hook: Option<DeductCallerHook<CTX, ERROR>>,
     pub _phantom: core::marker::PhantomData<(CTX, ERROR)>,
 }
 
+impl<CTX, ERROR> Clone for EthPreExecution<CTX, ERROR> {
+    fn clone(&self) -> Self {
+        Self {
+            deduct_caller_hook: self.deduct_caller_hook,
+            _phantom: core::marker::PhantomData,
+        }
+    }
+}
+
 impl<CTX, ERROR> EthPreExecution<CTX, ERROR> {
     pub fn new() -> Self {
         Self {
+            deduct_caller_hook: None,
             _phantom: core::marker::PhantomData,
         }
     }
@@ -31,6 +54,12 @@ impl<CTX, ERROR> EthPreExecution<CTX, ERROR> {
     pub fn new_boxed() -> Box<Self> {
         Box::new(Self::new())
     }
+
+    /// Sets the hook charging the upfront gas cost.
+    pub fn with_deduct_caller_hook(mut self, hook: DeductCallerHook<CTX, ERROR>) -> Self {
+        self.deduct_caller_hook = Some(hook);
+        self
+    }
 }
 
 impl<CTX, ERROR> PreExecutionHandler for EthPreExecution<CTX, ERROR>
@@ -100,13 +129,13 @@ where
         let is_call = context.tx().kind().is_call();
         let caller = context.tx().common_fields().caller();
 
+        let deduct = self
+            .deduct_caller_hook
+            .unwrap_or(deduct_caller_balance::<CTX, ERROR>);
+        deduct(context, caller, U256::from(gas_cost))?;
+
         // Load caller's account.
         let caller_account = context.journal().load_account(caller)?.data;
-        // Set new caller account balance.
-        caller_account.info.balance = caller_account
-            .info
-            .balance
-            .saturating_sub(U256::from(gas_cost));
 
         // Bump the nonce for calls. Nonce for CREATE will be bumped in `handle_create`.
         if is_call {
@@ -120,6 +149,19 @@ where
     }
 }
 
+/// Subtracts `amount` from the balance of `payer`, saturating at zero.
+#[inline]
+pub fn deduct_caller_balance<CTX: JournalGetter, ERROR: From<JournalDBError<CTX>>>(
+    context: &mut CTX,
+    payer: Address,
+    amount: U256,
+) -> Result<(), ERROR> {
+    let account = context.journal().load_account(payer)?.data;
+    account.info.balance = account.info.balance.saturating_sub(amount);
+    account.mark_touch();
+    Ok(())
+}
+
 /// Apply EIP-7702 auth list and return number gas refund on already created accounts.
 #[inline]
 pub fn apply_eip7702_auth_list<
@@ -1,9 +1,10 @@
 use context_interface::{Cfg, CfgGetter};
 use handler_interface::PrecompileProvider;
 use interpreter::{Gas, InstructionResult, InterpreterResult};
-use precompile::PrecompileErrors;
-use precompile::{PrecompileSpecId, Precompiles};
+use precompile::{bn128, identity, PrecompileSpecId, Precompiles};
+use precompile::{PrecompileError, PrecompileErrors};
 use primitives::{Address, Bytes};
+use specification::hardfork::SpecId;
 
 pub struct EthPrecompileProvider<CTX, ERROR> {
     pub precompiles: &'static Precompiles,
@@ -19,6 +20,18 @@ impl<CTX, ERROR> Clone for EthPrecompileProvider<CTX, ERROR> {
     }
 }
 
+impl<CTX, ERROR> EthPrecompileProvider<CTX, ERROR> {
+    /// Returns the addresses of the precompiles active in `spec`.
+    ///
+    /// These are the addresses of a provider created for `spec`. If [`Self::precompiles`] is
+    /// replaced, use [`PrecompileProvider::warm_addresses`] of the provider instead.
+    pub fn addresses(spec: SpecId) -> impl Iterator<Item = Address> {
+        Precompiles::new(PrecompileSpecId::from_spec_id(spec))
+            .addresses()
+            .cloned()
+    }
+}
+
 impl<CTX, ERROR> PrecompileProvider for EthPrecompileProvider<CTX, ERROR>
 where
     CTX: CfgGetter,
@@ -37,7 +50,7 @@ where
 
     fn run(
         &mut self,
-        _context: &mut Self::Context,
+        context: &mut Self::Context,
         address: &Address,
         bytes: &Bytes,
         gas_limit: u64,
@@ -52,7 +65,39 @@ where
             output: Bytes::new(),
         };
 
-        match (*precompile)(bytes, gas_limit) {
+        let cfg = context.cfg();
+        let max_input_size = cfg.precompile_max_input_size(address);
+        let pair_gas = (cfg.bn254_pair_base_gas(), cfg.bn254_pair_per_point_gas());
+        let output = if max_input_size.is_some_and(|max| bytes.len() > max) {
+            Err(PrecompileError::InputTooLarge.into())
+        } else if *address == bn128::pair::ADDRESS && pair_gas != (None, None) {
+            let spec: SpecId = cfg.spec().into();
+            let (base, per_point) = if spec.is_enabled_in(SpecId::ISTANBUL) {
+                (
+                    bn128::pair::ISTANBUL_PAIR_BASE,
+                    bn128::pair::ISTANBUL_PAIR_PER_POINT,
+                )
+            } else {
+                (
+                    bn128::pair::BYZANTIUM_PAIR_BASE,
+                    bn128::pair::BYZANTIUM_PAIR_PER_POINT,
+                )
+            };
+            bn128::run_pair(
+                bytes,
+                pair_gas.1.unwrap_or(per_point),
+                pair_gas.0.unwrap_or(base),
+                gas_limit,
+            )
+        } else {
+            match cfg.identity_per_word_gas() {
+                Some(per_word) if *address == identity::FUN.0 => {
+                    identity::identity_run_with_per_word_cost(bytes, gas_limit, per_word)
+                }
+                _ => (*precompile)(bytes, gas_limit),
+            }
+        };
+        match output {
             Ok(output) => {
                 let underflow = result.gas.record_cost(output.gas_used);
                 assert!(underflow, "Gas underflow is not possible");
@@ -79,3 +124,18 @@ where
         self.precompiles.contains(address)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn addresses_follow_spec() {
+        let point_evaluation = precompile::u64_to_address(0x0a);
+        let addresses = |spec| EthPrecompileProvider::<(), ()>::addresses(spec).collect::<Vec<_>>();
+
+        assert!(addresses(SpecId::CANCUN).contains(&point_evaluation));
+        assert!(!addresses(SpecId::FRONTIER).contains(&point_evaluation));
+        assert_eq!(addresses(SpecId::FRONTIER).len(), 4);
+    }
+}
@@ -2,12 +2,12 @@ use context_interface::{
     journaled_state::Journal,
     result::{InvalidHeader, InvalidTransaction},
     transaction::{
-        eip7702::Authorization, Eip1559CommonTxFields, Eip2930Tx, Eip4844Tx, Eip7702Tx, LegacyTx,
-        Transaction, TransactionType,
+        eip1559, eip7702::Authorization, Eip1559CommonTxFields, Eip2930Tx, Eip4844Tx, Eip7702Tx,
+        LegacyTx, Transaction, TransactionType,
     },
     Block, BlockGetter, Cfg, CfgGetter, JournalDBError, JournalGetter, TransactionGetter,
 };
-use core::cmp::{self, Ordering};
+use core::cmp::Ordering;
 use handler_interface::ValidationHandler;
 use interpreter::gas;
 use primitives::{B256, U256};
@@ -16,12 +16,29 @@ use state::Account;
 use std::boxed::Box;
 
 pub struct EthValidation<CTX, ERROR> {
+    /// Skips checking that the caller balance covers the gas cost of the transaction.
+    ///
+    /// Used when the gas is paid by another account through a
+    /// [`DeductCallerHook`](crate::DeductCallerHook). The value transfer is still checked.
+    ///
+    /// By default, it is set to `false`.
+    pub skip_gas_cost_check: bool,
     pub _phantom: core::marker::PhantomData<fn() -> (CTX, ERROR)>,
 }
 
+impl<CTX, ERROR> Clone for EthValidation<CTX, ERROR> {
+    fn clone(&self) -> Self {
+        Self {
+            skip_gas_cost_check: self.skip_gas_cost_check,
+            _phantom: core::marker::PhantomData,
+        }
+    }
+}
+
 impl<CTX, ERROR> Default for EthValidation<CTX, ERROR> {
     fn default() -> Self {
         Self {
+            skip_gas_cost_check: false,
             _phantom: core::marker::PhantomData,
         }
     }
@@ -30,6 +47,7 @@ impl<CTX, ERROR> Default for EthValidation<CTX, ERROR> {
 impl<CTX, ERROR> EthValidation<CTX, ERROR> {
     pub fn new() -> Self {
         Self {
+            skip_gas_cost_check: false,
             _phantom: core::marker::PhantomData,
         }
     }
@@ -54,10 +72,18 @@ where
             return Err(InvalidHeader::PrevrandaoNotSet.into());
         }
         // `excess_blob_gas` is required for Cancun
-        if spec.is_enabled_in(SpecId::CANCUN)
-            && context.block().blob_excess_gas_and_price().is_none()
-        {
-            return Err(InvalidHeader::ExcessBlobGasNotSet.into());
+        if spec.is_enabled_in(SpecId::CANCUN) {
+            let Some(blob) = context.block().blob_excess_gas_and_price() else {
+                return Err(InvalidHeader::ExcessBlobGasNotSet.into());
+            };
+            // The blob gas price must follow the same schedule as the blob count limit.
+            let blob_gasprice = context
+                .cfg()
+                .blob_params()
+                .calc_blob_gasprice(blob.excess_blob_gas);
+            if blob.blob_gasprice != blob_gasprice {
+                return Err(InvalidHeader::BlobGasPriceMismatch.into());
+            }
         }
         validate_tx_env::<&Self::Context, InvalidTransaction>(context, spec).map_err(Into::into)
     }
@@ -69,10 +95,14 @@ where
         let account = &mut context.journal().load_account_code(tx_caller)?;
         let account = account.data.clone();
 
-        validate_tx_against_account::<CTX, ERROR>(&account, context)
+        check_tx_against_account::<CTX, ERROR>(&account, context, !self.skip_gas_cost_check)
     }
 
     fn validate_initial_tx_gas(&self, context: &Self::Context) -> Result<u64, Self::Error> {
+        // Intrinsic gas is not charged when gas is not metered.
+        if context.cfg().is_gas_metering_disabled() {
+            return Ok(0);
+        }
         let spec = context.cfg().spec().into();
         validate_initial_tx_gas::<&Self::Context, InvalidTransaction>(context, spec)
             .map_err(Into::into)
@@ -92,8 +122,7 @@ pub fn validate_priority_fee_tx(
 
     // Check minimal cost against basefee
     if let Some(base_fee) = base_fee {
-        let effective_gas_price = cmp::min(max_fee, base_fee.saturating_add(max_priority_fee));
-        if effective_gas_price < base_fee {
+        if eip1559::effective_gas_price(max_fee, max_priority_fee, base_fee) < base_fee {
             return Err(InvalidTransaction::GasPriceLessThanBasefee);
         }
     }
@@ -106,6 +135,7 @@ pub fn validate_eip4844_tx(
     blobs: &[B256],
     max_blob_fee: u128,
     block_blob_gas_price: u128,
+    max_blobs: u64,
 ) -> Result<(), InvalidTransaction> {
     // Ensure that the user was willing to at least pay the current blob gasprice
     if block_blob_gas_price > max_blob_fee {
@@ -125,11 +155,11 @@ pub fn validate_eip4844_tx(
     }
 
     // Ensure the total blob gas spent is at most equal to the limit
-    // assert blob_gas_used <= MAX_BLOB_GAS_PER_BLOCK
-    if blobs.len() > eip4844::MAX_BLOB_NUMBER_PER_BLOCK as usize {
+    // assert blob_gas_used <= max_blob_gas_per_block
+    if blobs.len() > max_blobs as usize {
         return Err(InvalidTransaction::TooManyBlobs {
             have: blobs.len(),
-            max: eip4844::MAX_BLOB_NUMBER_PER_BLOCK as usize,
+            max: max_blobs as usize,
         });
     }
     Ok(())
@@ -224,6 +254,7 @@ where
                 tx.blob_versioned_hashes(),
                 tx.max_fee_per_blob_gas(),
                 context.block().blob_gasprice().unwrap_or_default(),
+                context.cfg().blob_params().max_blob_count(),
             )?;
         }
         TransactionType::Eip7702 => {
@@ -268,6 +299,14 @@ where
         return Err(InvalidTransaction::CallerGasLimitMoreThanBlock.into());
     }
 
+    // Check if the input is larger than the configured limit
+    if let Some(max) = context.cfg().max_calldata_size() {
+        let have = common_field.input().len();
+        if have > max {
+            return Err(InvalidTransaction::CalldataSizeLimit { max, have }.into());
+        }
+    }
+
     // EIP-3860: Limit and meter initcode
     if spec_id.is_enabled_in(SpecId::SHANGHAI) && context.tx().kind().is_create() {
         let max_initcode_size = context.cfg().max_code_size().saturating_mul(2);
@@ -285,6 +324,19 @@ pub fn validate_tx_against_account<CTX: TransactionGetter + CfgGetter, ERROR>(
     account: &Account,
     context: &CTX,
 ) -> Result<(), ERROR>
+where
+    ERROR: From<InvalidTransaction>,
+{
+    check_tx_against_account(account, context, true)
+}
+
+/// Validates the account against the transaction, including the gas cost in the balance
+/// check only if `check_gas_cost` is set.
+fn check_tx_against_account<CTX: TransactionGetter + CfgGetter, ERROR>(
+    account: &Account,
+    context: &CTX,
+    check_gas_cost: bool,
+) -> Result<(), ERROR>
 where
     ERROR: From<InvalidTransaction>,
 {
@@ -303,6 +355,14 @@ where
 
     // Check that the transaction's nonce is correct
     if !context.cfg().is_nonce_check_disabled() {
+        // EIP-2681: Limit account nonce to 2^64-1
+        // The nonce of the sender is increased by the transaction, so it can not be at the
+        // limit. Like the other nonce checks, this is skipped when nonce checks are disabled,
+        // in which case the nonce increase saturates.
+        if account.info.nonce == u64::MAX {
+            return Err(InvalidTransaction::NonceOverflowInTransaction.into());
+        }
+
         let tx = context.tx().common_fields().nonce();
         let state = account.info.nonce;
         match tx.cmp(&state) {
@@ -316,13 +376,18 @@ where
         }
     }
 
-    // gas_limit * max_fee + value
-    let mut balance_check = U256::from(context.tx().common_fields().gas_limit())
-        .checked_mul(U256::from(context.tx().max_fee()))
-        .and_then(|gas_cost| gas_cost.checked_add(context.tx().common_fields().value()))
-        .ok_or(InvalidTransaction::OverflowPaymentInTransaction)?;
+    let value = context.tx().common_fields().value();
+    let mut balance_check = if check_gas_cost {
+        // gas_limit * max_fee + value
+        U256::from(context.tx().common_fields().gas_limit())
+            .checked_mul(U256::from(context.tx().max_fee()))
+            .and_then(|gas_cost| gas_cost.checked_add(value))
+            .ok_or(InvalidTransaction::OverflowPaymentInTransaction)?
+    } else {
+        value
+    };
 
-    if tx_type == TransactionType::Eip4844 {
+    if check_gas_cost && tx_type == TransactionType::Eip4844 {
         let tx = context.tx().eip4844();
         let data_fee = tx.calc_max_data_fee();
         balance_check = balance_check
@@ -343,37 +408,40 @@ where
     Ok(())
 }
 
-/// Validate initial transaction gas.
-pub fn validate_initial_tx_gas<TxGetter: TransactionGetter, Error>(
-    env: TxGetter,
-    spec_id: SpecId,
-) -> Result<u64, Error>
-where
-    Error: From<InvalidTransaction>,
-{
-    let tx_type = env.tx().tx_type().into();
+/// Calculates the intrinsic gas of the transaction.
+///
+/// This includes the input data, the EIP-2930 access list entries, the init code of creates
+/// and the EIP-7702 authorizations on top of the base stipend.
+pub fn calculate_intrinsic_gas(tx: &impl Transaction, spec_id: SpecId) -> u64 {
+    let tx_type = tx.tx_type().into();
 
     let authorization_list_num = if tx_type == TransactionType::Eip7702 {
-        env.tx().eip7702().authorization_list_len() as u64
+        tx.eip7702().authorization_list_len() as u64
     } else {
         0
     };
 
-    let common_fields = env.tx().common_fields();
-    let is_create = env.tx().kind().is_create();
-    let input = common_fields.input();
-    let access_list = env.tx().access_list();
-
-    let initial_gas_spend = gas::validate_initial_tx_gas(
+    gas::validate_initial_tx_gas(
         spec_id,
-        input,
-        is_create,
-        access_list,
+        tx.common_fields().input(),
+        tx.kind().is_create(),
+        tx.access_list(),
         authorization_list_num,
-    );
+    )
+}
+
+/// Validate initial transaction gas.
+pub fn validate_initial_tx_gas<TxGetter: TransactionGetter, Error>(
+    env: TxGetter,
+    spec_id: SpecId,
+) -> Result<u64, Error>
+where
+    Error: From<InvalidTransaction>,
+{
+    let initial_gas_spend = calculate_intrinsic_gas(env.tx(), spec_id);
 
     // Additional check to see if limit is big enough to cover initial gas.
-    if initial_gas_spend > common_fields.gas_limit() {
+    if initial_gas_spend > env.tx().common_fields().gas_limit() {
         return Err(InvalidTransaction::CallGasCostMoreThanGasLimit.into());
     }
     Ok(initial_gas_spend)
@@ -0,0 +1,158 @@
+//! BreakpointInspector. Halts execution when a breakpoint is reached.
+use crate::Inspector;
+use revm::{
+    interpreter::{
+        interpreter_types::{InputsTrait, Jumps, LoopControl},
+        InstructionResult, Interpreter, InterpreterTypes,
+    },
+    primitives::{Address, HashSet},
+};
+
+/// Breakpoint of the [`BreakpointInspector`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Breakpoint {
+    /// Breaks before executing the opcode, in any frame.
+    Opcode(u8),
+    /// Breaks before executing the instruction at `pc` of the code running at `address`.
+    Pc {
+        /// Address of the executing account.
+        address: Address,
+        /// Program counter of the instruction.
+        pc: usize,
+    },
+}
+
+/// Breakpoint reached by the [`BreakpointInspector`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct BreakpointHit {
+    /// The breakpoint that matched.
+    pub breakpoint: Breakpoint,
+    /// Address of the account executing the instruction.
+    pub address: Address,
+    /// Program counter of the instruction.
+    pub pc: usize,
+    /// Opcode of the instruction.
+    pub opcode: u8,
+}
+
+/// [Inspector] that halts execution when a [`Breakpoint`] is reached.
+///
+/// The matching instruction is not executed: its frame halts with
+/// [`InstructionResult::Breakpoint`], and every frame above it halts on its next instruction.
+/// The transaction ends with [`HaltReason::Breakpoint`] and its state changes are reverted.
+/// The breakpoint that hit is reported by [`BreakpointInspector::hit`].
+///
+/// [`HaltReason::Breakpoint`]: revm::context_interface::result::HaltReason::Breakpoint
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct BreakpointInspector {
+    breakpoints: HashSet<Breakpoint>,
+    hit: Option<BreakpointHit>,
+}
+
+impl BreakpointInspector {
+    /// Creates a new inspector without breakpoints.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds a breakpoint.
+    pub fn with_breakpoint(mut self, breakpoint: Breakpoint) -> Self {
+        self.breakpoints.insert(breakpoint);
+        self
+    }
+
+    /// Adds a breakpoint. Returns `false` if it was already set.
+    pub fn add_breakpoint(&mut self, breakpoint: Breakpoint) -> bool {
+        self.breakpoints.insert(breakpoint)
+    }
+
+    /// Removes a breakpoint. Returns `false` if it was not set.
+    pub fn remove_breakpoint(&mut self, breakpoint: &Breakpoint) -> bool {
+        self.breakpoints.remove(breakpoint)
+    }
+
+    /// Returns the breakpoint that halted execution, if any.
+    pub fn hit(&self) -> Option<&BreakpointHit> {
+        self.hit.as_ref()
+    }
+
+    /// Clears the hit breakpoint so that another execution can be inspected.
+    pub fn resume(&mut self) {
+        self.hit = None;
+    }
+
+    fn find(&self, address: Address, pc: usize, opcode: u8) -> Option<Breakpoint> {
+        [Breakpoint::Pc { address, pc }, Breakpoint::Opcode(opcode)]
+            .into_iter()
+            .find(|breakpoint| self.breakpoints.contains(breakpoint))
+    }
+}
+
+impl<CTX, INTR: InterpreterTypes> Inspector<CTX, INTR> for BreakpointInspector {
+    fn step(&mut self, interp: &mut Interpreter<INTR>, _context: &mut CTX) {
+        if self.hit.is_none() {
+            let address = interp.input.target_address();
+            let pc = interp.bytecode.pc();
+            let opcode = interp.bytecode.opcode();
+            let Some(breakpoint) = self.find(address, pc, opcode) else {
+                return;
+            };
+            self.hit = Some(BreakpointHit {
+                breakpoint,
+                address,
+                pc,
+                opcode,
+            });
+        }
+        interp
+            .control
+            .set_instruction_result(InstructionResult::Breakpoint);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::run_with_inspector;
+    use revm::{
+        bytecode::opcode,
+        context_interface::result::{ExecutionResult, HaltReason},
+        primitives::U256,
+    };
+
+    #[test]
+    fn halts_before_sstore() {
+        let contract = Address::with_last_byte(0xaa);
+        let code = vec![
+            opcode::PUSH1,
+            0x2a,
+            opcode::PUSH1,
+            0x00,
+            opcode::SSTORE,
+            opcode::STOP,
+        ];
+
+        let inspector =
+            BreakpointInspector::new().with_breakpoint(Breakpoint::Opcode(opcode::SSTORE));
+        let (output, inspector) = run_with_inspector([(contract, code)], inspector);
+        assert!(matches!(
+            output.result,
+            ExecutionResult::Halt {
+                reason: HaltReason::Breakpoint,
+                ..
+            }
+        ));
+        // The slot was never written, nor even read.
+        assert!(!output.state[&contract].storage.contains_key(&U256::ZERO));
+
+        assert_eq!(
+            inspector.hit(),
+            Some(&BreakpointHit {
+                breakpoint: Breakpoint::Opcode(opcode::SSTORE),
+                address: contract,
+                pc: 4,
+                opcode: opcode::SSTORE,
+            })
+        );
+    }
+}
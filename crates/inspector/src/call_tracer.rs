@@ -0,0 +1,378 @@
+//! CallTracerInspector. Records the call tree in the format of geth's `callTracer`.
+use crate::{Inspector, TransactionSummary};
+use revm::{
+    context_interface::{CallScheme, CreateScheme},
+    interpreter::{
+        CallInputs, CallOutcome, CreateInputs, CreateOutcome, EOFCreateInputs, EOFCreateKind,
+        InstructionResult, InterpreterResult, InterpreterTypes,
+    },
+    primitives::{Address, Bytes, U256},
+};
+use serde::{Deserialize, Serialize, Serializer};
+use std::{format, string::String, vec::Vec};
+
+/// Options of the [`CallTracerInspector`], named as geth's `tracerConfig`.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash, Deserialize, Serialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct CallTracerConfig {
+    /// Only record the top level call of the transaction.
+    pub only_top_call: bool,
+}
+
+/// Kind of a [`CallFrame`], serialized as the opcode name.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Serialize)]
+#[serde(rename_all = "UPPERCASE")]
+pub enum CallKind {
+    /// `CALL` or `EXTCALL`.
+    Call,
+    /// `CALLCODE`.
+    CallCode,
+    /// `DELEGATECALL` or `EXTDELEGATECALL`.
+    DelegateCall,
+    /// `STATICCALL` or `EXTSTATICCALL`.
+    StaticCall,
+    /// `CREATE`.
+    Create,
+    /// `CREATE2`.
+    Create2,
+    /// `EOFCREATE` or an EOF create transaction.
+    EofCreate,
+}
+
+impl From<CallScheme> for CallKind {
+    fn from(scheme: CallScheme) -> Self {
+        match scheme {
+            CallScheme::Call | CallScheme::ExtCall => Self::Call,
+            CallScheme::CallCode => Self::CallCode,
+            CallScheme::DelegateCall | CallScheme::ExtDelegateCall => Self::DelegateCall,
+            CallScheme::StaticCall | CallScheme::ExtStaticCall => Self::StaticCall,
+        }
+    }
+}
+
+/// Call of the trace, serialized with the field names of geth's `callTracer`.
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CallFrame {
+    /// Kind of the call.
+    #[serde(rename = "type")]
+    pub kind: CallKind,
+    /// Caller of the call.
+    pub from: Address,
+    /// Callee of the call, the created address for creates.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub to: Option<Address>,
+    /// Value transferred, `None` for calls that do not transfer value.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub value: Option<U256>,
+    /// Gas given to the call.
+    #[serde(serialize_with = "serialize_quantity")]
+    pub gas: u64,
+    /// Gas used by the call.
+    #[serde(serialize_with = "serialize_quantity")]
+    pub gas_used: u64,
+    /// Input of the call, the init code for creates.
+    pub input: Bytes,
+    /// Output of the call, omitted if empty or if the call halted.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub output: Option<Bytes>,
+    /// Error of a failed call.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+    /// Calls made by this call.
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub calls: Vec<CallFrame>,
+}
+
+impl CallFrame {
+    /// Sets the gas used, output and error of the call from its result.
+    fn finish(&mut self, result: &InterpreterResult) {
+        self.gas_used = result.gas.spent();
+        if !result.result.is_ok() {
+            self.error = Some(error_message(result.result));
+        }
+        // Halted calls do not return output.
+        if (result.is_ok() || result.is_revert()) && !result.output.is_empty() {
+            self.output = Some(result.output.clone());
+        }
+    }
+}
+
+/// Serializes a number as a hex quantity, as geth does.
+fn serialize_quantity<S: Serializer>(value: &u64, serializer: S) -> Result<S::Ok, S::Error> {
+    serializer.serialize_str(&format!("{value:#x}"))
+}
+
+/// Returns the geth error message of a failed call.
+fn error_message(result: InstructionResult) -> String {
+    match result {
+        InstructionResult::Revert => "execution reverted".into(),
+        InstructionResult::OutOfGas
+        | InstructionResult::MemoryOOG
+        | InstructionResult::MemoryLimitOOG
+        | InstructionResult::PrecompileOOG
+        | InstructionResult::InvalidOperandOOG => "out of gas".into(),
+        InstructionResult::InvalidJump => "invalid jump destination".into(),
+        InstructionResult::StateChangeDuringStaticCall
+        | InstructionResult::CallNotAllowedInsideStatic => "write protection".into(),
+        InstructionResult::CallTooDeep => "max call depth exceeded".into(),
+        InstructionResult::OutOfFunds => "insufficient balance for transfer".into(),
+        InstructionResult::CreateCollision => "contract address collision".into(),
+        InstructionResult::CreateContractSizeLimit => "max code size exceeded".into(),
+        InstructionResult::CreateContractStartingWithEF => {
+            "invalid code: must not begin with 0xef".into()
+        }
+        InstructionResult::StackUnderflow => "stack underflow".into(),
+        InstructionResult::StackOverflow => "stack limit reached 1024".into(),
+        InstructionResult::OpcodeNotFound | InstructionResult::InvalidFEOpcode => {
+            "invalid opcode".into()
+        }
+        result => format!("{result:?}"),
+    }
+}
+
+/// [Inspector] that records the calls of a transaction as geth's `callTracer` does.
+///
+/// The top level call reports the gas limit and gas used of the transaction. Serializing
+/// [`CallTracerInspector::frame`] produces the `callTracer` JSON output.
+#[derive(Clone, Debug, Default)]
+pub struct CallTracerInspector {
+    config: CallTracerConfig,
+    /// Calls that have not finished yet, the innermost last.
+    stack: Vec<CallFrame>,
+    /// Depth of the running frame, including the frames that are not recorded.
+    depth: usize,
+    /// Finished top level call.
+    root: Option<CallFrame>,
+}
+
+impl CallTracerInspector {
+    /// Creates a new call tracer with the given options.
+    pub fn new(config: CallTracerConfig) -> Self {
+        Self {
+            config,
+            ..Default::default()
+        }
+    }
+
+    /// Returns the top level call of the last transaction, `None` while it is running.
+    pub fn frame(&self) -> Option<&CallFrame> {
+        self.root.as_ref()
+    }
+
+    /// Consumes the tracer and returns the top level call of the last transaction.
+    pub fn into_frame(self) -> Option<CallFrame> {
+        self.root
+    }
+
+    fn start(&mut self, frame: CallFrame) {
+        if self.depth == 0 || !self.config.only_top_call {
+            self.stack.push(frame);
+        }
+        self.depth += 1;
+    }
+
+    fn end(&mut self, result: &InterpreterResult, to: Option<Address>) {
+        self.depth -= 1;
+        if self.depth != 0 && self.config.only_top_call {
+            return;
+        }
+        let Some(mut frame) = self.stack.pop() else {
+            return;
+        };
+        frame.finish(result);
+        if to.is_some() {
+            frame.to = to;
+        }
+        match self.stack.last_mut() {
+            Some(parent) => parent.calls.push(frame),
+            None => self.root = Some(frame),
+        }
+    }
+}
+
+impl<CTX, INTR: InterpreterTypes> Inspector<CTX, INTR> for CallTracerInspector {
+    fn transaction_start(&mut self, _context: &mut CTX, _intrinsic_gas: u64, _gas_limit: u64) {
+        self.stack.clear();
+        self.depth = 0;
+        self.root = None;
+    }
+
+    fn transaction_end(&mut self, summary: &TransactionSummary) {
+        if let Some(root) = &mut self.root {
+            root.gas = summary.gas_limit;
+            root.gas_used = summary.gas_used;
+        }
+    }
+
+    fn call(&mut self, _context: &mut CTX, inputs: &mut CallInputs) -> Option<CallOutcome> {
+        let kind = CallKind::from(inputs.scheme);
+        // Geth reports the address of the executed code as the callee of a delegate call.
+        let to = match kind {
+            CallKind::DelegateCall | CallKind::CallCode => inputs.bytecode_address,
+            _ => inputs.target_address,
+        };
+        self.start(CallFrame {
+            kind,
+            from: inputs.caller,
+            to: Some(to),
+            value: inputs.transfer_value(),
+            gas: inputs.gas_limit,
+            gas_used: 0,
+            input: inputs.input.clone(),
+            output: None,
+            error: None,
+            calls: Vec::new(),
+        });
+        None
+    }
+
+    fn call_end(&mut self, _context: &mut CTX, _inputs: &CallInputs, outcome: &mut CallOutcome) {
+        self.end(&outcome.result, None);
+    }
+
+    fn create(&mut self, _context: &mut CTX, inputs: &mut CreateInputs) -> Option<CreateOutcome> {
+        self.start(CallFrame {
+            kind: match inputs.scheme {
+                CreateScheme::Create => CallKind::Create,
+                CreateScheme::Create2 { .. } => CallKind::Create2,
+            },
+            from: inputs.caller,
+            to: None,
+            value: Some(inputs.value),
+            gas: inputs.gas_limit,
+            gas_used: 0,
+            input: inputs.init_code.clone(),
+            output: None,
+            error: None,
+            calls: Vec::new(),
+        });
+        None
+    }
+
+    fn create_end(
+        &mut self,
+        _context: &mut CTX,
+        _inputs: &CreateInputs,
+        outcome: &mut CreateOutcome,
+    ) {
+        self.end(&outcome.result, outcome.address);
+    }
+
+    fn eofcreate(
+        &mut self,
+        _context: &mut CTX,
+        inputs: &mut EOFCreateInputs,
+    ) -> Option<CreateOutcome> {
+        let (to, input) = match &inputs.kind {
+            EOFCreateKind::Tx { initdata } => (None, initdata.clone()),
+            EOFCreateKind::Opcode {
+                initcode,
+                created_address,
+                ..
+            } => (Some(*created_address), initcode.raw().clone()),
+        };
+        self.start(CallFrame {
+            kind: CallKind::EofCreate,
+            from: inputs.caller,
+            to,
+            value: Some(inputs.value),
+            gas: inputs.gas_limit,
+            gas_used: 0,
+            input,
+            output: None,
+            error: None,
+            calls: Vec::new(),
+        });
+        None
+    }
+
+    fn eofcreate_end(
+        &mut self,
+        _context: &mut CTX,
+        _inputs: &EOFCreateInputs,
+        outcome: &mut CreateOutcome,
+    ) {
+        self.end(&outcome.result, outcome.address);
+    }
+}
+
+#[cfg(all(test, feature = "serde-json"))]
+mod tests {
+    use super::*;
+    use crate::test_utils::run_with_inspector;
+    use revm::bytecode::opcode;
+
+    fn trace(config: CallTracerConfig) -> CallFrame {
+        let entry = Address::with_last_byte(0x11);
+        let target = Address::with_last_byte(0xaa);
+
+        // Calls the target with 10000 gas and returns the 32 bytes it returned.
+        let entry_code = vec![
+            opcode::PUSH1,
+            0x20,
+            opcode::PUSH1,
+            0x00,
+            opcode::PUSH1,
+            0x00,
+            opcode::PUSH1,
+            0x00,
+            opcode::PUSH1,
+            0x00,
+            opcode::PUSH1,
+            0xaa,
+            opcode::PUSH2,
+            0x27,
+            0x10,
+            opcode::CALL,
+            opcode::POP,
+            opcode::PUSH1,
+            0x20,
+            opcode::PUSH1,
+            0x00,
+            opcode::RETURN,
+        ];
+        // Reverts with the word `0x2a`.
+        let target_code = vec![
+            opcode::PUSH1,
+            0x2a,
+            opcode::PUSH1,
+            0x00,
+            opcode::MSTORE,
+            opcode::PUSH1,
+            0x20,
+            opcode::PUSH1,
+            0x00,
+            opcode::REVERT,
+        ];
+
+        let (output, inspector) = run_with_inspector(
+            [(entry, entry_code), (target, target_code)],
+            CallTracerInspector::new(config),
+        );
+        assert!(output.result.is_success());
+        inspector.frame().unwrap().clone()
+    }
+
+    #[test]
+    fn nested_call_matches_fixture() {
+        let frame = trace(CallTracerConfig::default());
+        let expected: serde_json::Value =
+            serde_json::from_str(include_str!("../testdata/call_tracer_nested.json")).unwrap();
+        assert_eq!(serde_json::to_value(&frame).unwrap(), expected);
+    }
+
+    #[test]
+    fn only_top_call() {
+        let config: CallTracerConfig = serde_json::from_str(r#"{"onlyTopCall":true}"#).unwrap();
+        let frame = trace(config);
+        assert!(frame.calls.is_empty());
+        assert_eq!(
+            frame,
+            CallFrame {
+                calls: Vec::new(),
+                ..trace(CallTracerConfig::default())
+            }
+        );
+    }
+}
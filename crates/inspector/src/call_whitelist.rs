@@ -0,0 +1,124 @@
+//! CallWhitelistInspector. Fails calls to addresses that are not whitelisted.
+use crate::Inspector;
+use revm::{
+    context_interface::{Cfg, CfgGetter},
+    interpreter::{
+        CallInputs, CallOutcome, Gas, InstructionResult, InterpreterResult, InterpreterTypes,
+    },
+    precompile::{PrecompileSpecId, Precompiles},
+    primitives::{Address, Bytes, HashSet},
+};
+use std::vec::Vec;
+
+/// [Inspector] that fails every call whose code address is not whitelisted.
+///
+/// A rejected call is not executed and returns as a revert with empty output, so the `CALL`
+/// family of opcodes pushes `0`, and its gas is returned to the caller. The code address is
+/// checked, so `DELEGATECALL` and `CALLCODE` are allowed if the executed code is whitelisted.
+/// The transaction call is checked as well.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct CallWhitelistInspector {
+    /// Addresses that can be called.
+    whitelist: HashSet<Address>,
+    /// Whether precompiles of the current spec can be called.
+    allow_precompiles: bool,
+    /// Rejected code addresses in call order.
+    rejected: Vec<Address>,
+}
+
+impl CallWhitelistInspector {
+    /// Creates a new inspector allowing calls to the given addresses.
+    pub fn new(whitelist: impl IntoIterator<Item = Address>) -> Self {
+        Self {
+            whitelist: whitelist.into_iter().collect(),
+            ..Default::default()
+        }
+    }
+
+    /// Sets whether precompiles can be called even if they are not whitelisted.
+    pub fn with_precompiles_allowed(mut self, allow: bool) -> Self {
+        self.allow_precompiles = allow;
+        self
+    }
+
+    /// Adds `address` to the whitelist.
+    pub fn allow(&mut self, address: Address) {
+        self.whitelist.insert(address);
+    }
+
+    /// Returns `true` if calls to `address` are whitelisted.
+    pub fn is_allowed(&self, address: &Address) -> bool {
+        self.whitelist.contains(address)
+    }
+
+    /// Returns the rejected code addresses in call order.
+    pub fn rejected(&self) -> &[Address] {
+        &self.rejected
+    }
+}
+
+impl<CTX: CfgGetter, INTR: InterpreterTypes> Inspector<CTX, INTR> for CallWhitelistInspector {
+    fn call(&mut self, context: &mut CTX, inputs: &mut CallInputs) -> Option<CallOutcome> {
+        let address = inputs.bytecode_address;
+        if self.is_allowed(&address) {
+            return None;
+        }
+        if self.allow_precompiles {
+            let spec = PrecompileSpecId::from_spec_id(context.cfg().spec().into());
+            if Precompiles::new(spec).contains(&address) {
+                return None;
+            }
+        }
+
+        self.rejected.push(address);
+        Some(CallOutcome::new(
+            InterpreterResult {
+                result: InstructionResult::Revert,
+                output: Bytes::new(),
+                gas: Gas::new(inputs.gas_limit),
+            },
+            inputs.return_memory_offset.clone(),
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::{call, run_with_inspector};
+    use revm::{bytecode::opcode, primitives::U256};
+
+    fn call_and_store(target: u8, slot: u8) -> Vec<u8> {
+        [&call(target)[..], &[opcode::PUSH1, slot, opcode::SSTORE]].concat()
+    }
+
+    #[test]
+    fn forbidden_call_fails() {
+        let entry = Address::with_last_byte(0x11);
+        let allowed = Address::with_last_byte(0xaa);
+        let forbidden = Address::with_last_byte(0xbb);
+
+        let mut entry_code = call_and_store(0xbb, 0);
+        entry_code.extend(call_and_store(0xaa, 1));
+        // Identity precompile.
+        entry_code.extend(call_and_store(0x04, 2));
+        entry_code.push(opcode::STOP);
+
+        let inspector =
+            CallWhitelistInspector::new([entry, allowed]).with_precompiles_allowed(true);
+        let (output, inspector) = run_with_inspector(
+            [
+                (entry, entry_code),
+                (allowed, vec![opcode::STOP]),
+                (forbidden, vec![opcode::STOP]),
+            ],
+            inspector,
+        );
+        assert!(output.result.is_success());
+        let storage = &output.state[&entry].storage;
+        assert_eq!(storage[&U256::ZERO].present_value, U256::ZERO);
+        assert_eq!(storage[&U256::from(1)].present_value, U256::from(1));
+        assert_eq!(storage[&U256::from(2)].present_value, U256::from(1));
+        assert_eq!(inspector.rejected(), [forbidden]);
+    }
+}
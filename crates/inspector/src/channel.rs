@@ -0,0 +1,276 @@
+//! ChannelTracerInspector. Streams trace events to a channel as execution proceeds.
+use crate::Inspector;
+use revm::{
+    interpreter::{
+        interpreter_types::{InputsTrait, Jumps, LoopControl},
+        CallInputs, CallOutcome, CreateInputs, CreateOutcome, EOFCreateInputs, InstructionResult,
+        Interpreter, InterpreterTypes,
+    },
+    primitives::{Address, Log, U256},
+};
+use std::sync::mpsc::Sender;
+
+/// Event sent by [`ChannelTracerInspector`].
+///
+/// `depth` is zero for the frame of the transaction.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum TraceEvent {
+    /// An instruction is about to be executed.
+    Step {
+        depth: usize,
+        address: Address,
+        pc: usize,
+        opcode: u8,
+    },
+    /// A call frame starts.
+    CallStart {
+        depth: usize,
+        caller: Address,
+        target: Address,
+    },
+    /// A call frame ended.
+    CallEnd {
+        depth: usize,
+        result: InstructionResult,
+    },
+    /// A create frame starts.
+    CreateStart { depth: usize, caller: Address },
+    /// A create frame ended.
+    CreateEnd {
+        depth: usize,
+        result: InstructionResult,
+        address: Option<Address>,
+    },
+    /// A log was emitted.
+    Log(Log),
+    /// A contract self destructed.
+    SelfDestruct {
+        contract: Address,
+        target: Address,
+        value: U256,
+    },
+}
+
+/// [Inspector] that sends a [`TraceEvent`] per callback over a channel.
+///
+/// Once the receiver is dropped, no more events are sent and every frame halts with
+/// [`InstructionResult::Revert`] on its next instruction.
+#[derive(Clone, Debug)]
+pub struct ChannelTracerInspector {
+    sender: Sender<TraceEvent>,
+    /// Number of active frames.
+    depth: usize,
+    /// Set once the receiver is dropped.
+    closed: bool,
+}
+
+impl ChannelTracerInspector {
+    /// Creates a new inspector sending events to `sender`.
+    pub fn new(sender: Sender<TraceEvent>) -> Self {
+        Self {
+            sender,
+            depth: 0,
+            closed: false,
+        }
+    }
+
+    /// Returns `true` if the receiver was dropped.
+    pub fn is_closed(&self) -> bool {
+        self.closed
+    }
+
+    fn send(&mut self, event: TraceEvent) {
+        if !self.closed && self.sender.send(event).is_err() {
+            self.closed = true;
+        }
+    }
+
+    fn frame_start(&mut self) -> usize {
+        self.depth += 1;
+        self.depth - 1
+    }
+
+    fn frame_end(&mut self) -> usize {
+        self.depth = self.depth.saturating_sub(1);
+        self.depth
+    }
+}
+
+impl<CTX, INTR: InterpreterTypes> Inspector<CTX, INTR> for ChannelTracerInspector {
+    fn step(&mut self, interp: &mut Interpreter<INTR>, _context: &mut CTX) {
+        self.send(TraceEvent::Step {
+            depth: self.depth.saturating_sub(1),
+            address: interp.input.target_address(),
+            pc: interp.bytecode.pc(),
+            opcode: interp.bytecode.opcode(),
+        });
+        if self.closed {
+            interp
+                .control
+                .set_instruction_result(InstructionResult::Revert);
+        }
+    }
+
+    fn log(&mut self, _interp: &mut Interpreter<INTR>, _context: &mut CTX, log: &Log) {
+        self.send(TraceEvent::Log(log.clone()));
+    }
+
+    fn call(&mut self, _context: &mut CTX, inputs: &mut CallInputs) -> Option<CallOutcome> {
+        let depth = self.frame_start();
+        self.send(TraceEvent::CallStart {
+            depth,
+            caller: inputs.caller,
+            target: inputs.target_address,
+        });
+        None
+    }
+
+    fn call_end(&mut self, _context: &mut CTX, _inputs: &CallInputs, outcome: &mut CallOutcome) {
+        let depth = self.frame_end();
+        self.send(TraceEvent::CallEnd {
+            depth,
+            result: outcome.result.result,
+        });
+    }
+
+    fn create(&mut self, _context: &mut CTX, inputs: &mut CreateInputs) -> Option<CreateOutcome> {
+        let depth = self.frame_start();
+        self.send(TraceEvent::CreateStart {
+            depth,
+            caller: inputs.caller,
+        });
+        None
+    }
+
+    fn create_end(
+        &mut self,
+        _context: &mut CTX,
+        _inputs: &CreateInputs,
+        outcome: &mut CreateOutcome,
+    ) {
+        let depth = self.frame_end();
+        self.send(TraceEvent::CreateEnd {
+            depth,
+            result: outcome.result.result,
+            address: outcome.address,
+        });
+    }
+
+    fn eofcreate(
+        &mut self,
+        _context: &mut CTX,
+        inputs: &mut EOFCreateInputs,
+    ) -> Option<CreateOutcome> {
+        let depth = self.frame_start();
+        self.send(TraceEvent::CreateStart {
+            depth,
+            caller: inputs.caller,
+        });
+        None
+    }
+
+    fn eofcreate_end(
+        &mut self,
+        _context: &mut CTX,
+        _inputs: &EOFCreateInputs,
+        outcome: &mut CreateOutcome,
+    ) {
+        let depth = self.frame_end();
+        self.send(TraceEvent::CreateEnd {
+            depth,
+            result: outcome.result.result,
+            address: outcome.address,
+        });
+    }
+
+    fn selfdestruct(&mut self, contract: Address, target: Address, value: U256) {
+        self.send(TraceEvent::SelfDestruct {
+            contract,
+            target,
+            value,
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::{call, run_with_inspector, CALLER};
+    use revm::{bytecode::opcode, context_interface::result::ExecutionResult, primitives::Bytes};
+    use std::sync::mpsc::channel;
+
+    const ENTRY: Address = Address::with_last_byte(0x11);
+    const CALLEE: Address = Address::with_last_byte(0xaa);
+
+    fn transact(inspector: ChannelTracerInspector) -> ExecutionResult {
+        // Calls `callee` and stops.
+        let entry_code = [&call(0xaa)[..], &[opcode::STOP]].concat();
+        // Emits a LOG0 and stops.
+        let callee_code = vec![
+            opcode::PUSH1,
+            0x00,
+            opcode::PUSH1,
+            0x00,
+            opcode::LOG0,
+            opcode::STOP,
+        ];
+        run_with_inspector([(ENTRY, entry_code), (CALLEE, callee_code)], inspector)
+            .0
+            .result
+    }
+
+    #[test]
+    fn nested_call_event_order() {
+        let (sender, receiver) = channel();
+        let result = transact(ChannelTracerInspector::new(sender));
+        assert!(result.is_success());
+
+        let events: Vec<_> = receiver.try_iter().collect();
+        let step = |depth, address, pc, opcode| TraceEvent::Step {
+            depth,
+            address,
+            pc,
+            opcode,
+        };
+        let mut expected = vec![TraceEvent::CallStart {
+            depth: 0,
+            caller: CALLER,
+            target: ENTRY,
+        }];
+        for pc in (0..12).step_by(2) {
+            expected.push(step(0, ENTRY, pc, opcode::PUSH1));
+        }
+        expected.extend([
+            step(0, ENTRY, 12, opcode::GAS),
+            step(0, ENTRY, 13, opcode::CALL),
+            TraceEvent::CallStart {
+                depth: 1,
+                caller: ENTRY,
+                target: CALLEE,
+            },
+            step(1, CALLEE, 0, opcode::PUSH1),
+            step(1, CALLEE, 2, opcode::PUSH1),
+            step(1, CALLEE, 4, opcode::LOG0),
+            TraceEvent::Log(Log::new_unchecked(CALLEE, vec![], Bytes::new())),
+            step(1, CALLEE, 5, opcode::STOP),
+            TraceEvent::CallEnd {
+                depth: 1,
+                result: InstructionResult::Stop,
+            },
+            step(0, ENTRY, 14, opcode::STOP),
+            TraceEvent::CallEnd {
+                depth: 0,
+                result: InstructionResult::Stop,
+            },
+        ]);
+        assert_eq!(events, expected);
+    }
+
+    #[test]
+    fn closed_channel_halts() {
+        let (sender, receiver) = channel();
+        drop(receiver);
+        let result = transact(ChannelTracerInspector::new(sender));
+        assert!(matches!(result, ExecutionResult::Revert { .. }));
+    }
+}
@@ -0,0 +1,155 @@
+//! FailureCauseInspector. Records the chain of nested failures behind a halt or revert.
+use crate::Inspector;
+use revm::interpreter::{
+    CallInputs, CallOutcome, CreateInputs, CreateOutcome, EOFCreateInputs, InstructionResult,
+    InterpreterTypes,
+};
+use std::vec::Vec;
+
+/// [Inspector] that assembles the causal chain of a failed transaction.
+///
+/// The execution result only carries the reason the top frame failed. This inspector
+/// links it to the failure of the last failed subcall or create of every failed frame,
+/// so that e.g. a caller reverting because its callee ran out of gas is reported as
+/// `[Revert, OutOfGas]`.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct FailureCauseInspector {
+    /// Last failure chain of a child, per active frame.
+    frames: Vec<Option<Vec<InstructionResult>>>,
+    /// Failure chain of the last finished top frame.
+    causes: Vec<InstructionResult>,
+}
+
+impl FailureCauseInspector {
+    /// Creates a new inspector.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the failure chain of the last transaction, outermost reason first.
+    ///
+    /// Empty if the transaction succeeded.
+    pub fn causes(&self) -> &[InstructionResult] {
+        &self.causes
+    }
+
+    fn frame_start(&mut self) {
+        if self.frames.is_empty() {
+            self.causes.clear();
+        }
+        self.frames.push(None);
+    }
+
+    fn frame_end(&mut self, result: InstructionResult) {
+        let child = self.frames.pop().flatten();
+        if result.is_ok() {
+            return;
+        }
+
+        let mut chain = Vec::with_capacity(1 + child.as_ref().map_or(0, Vec::len));
+        chain.push(result);
+        chain.extend(child.into_iter().flatten());
+        match self.frames.last_mut() {
+            Some(parent) => *parent = Some(chain),
+            None => self.causes = chain,
+        }
+    }
+}
+
+impl<CTX, INTR: InterpreterTypes> Inspector<CTX, INTR> for FailureCauseInspector {
+    fn call(&mut self, _context: &mut CTX, _inputs: &mut CallInputs) -> Option<CallOutcome> {
+        self.frame_start();
+        None
+    }
+
+    fn call_end(&mut self, _context: &mut CTX, _inputs: &CallInputs, outcome: &mut CallOutcome) {
+        self.frame_end(outcome.result.result);
+    }
+
+    fn create(&mut self, _context: &mut CTX, _inputs: &mut CreateInputs) -> Option<CreateOutcome> {
+        self.frame_start();
+        None
+    }
+
+    fn create_end(
+        &mut self,
+        _context: &mut CTX,
+        _inputs: &CreateInputs,
+        outcome: &mut CreateOutcome,
+    ) {
+        self.frame_end(outcome.result.result);
+    }
+
+    fn eofcreate(
+        &mut self,
+        _context: &mut CTX,
+        _inputs: &mut EOFCreateInputs,
+    ) -> Option<CreateOutcome> {
+        self.frame_start();
+        None
+    }
+
+    fn eofcreate_end(
+        &mut self,
+        _context: &mut CTX,
+        _inputs: &EOFCreateInputs,
+        outcome: &mut CreateOutcome,
+    ) {
+        self.frame_end(outcome.result.result);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::run_with_inspector;
+    use revm::{bytecode::opcode, context_interface::result::ExecutionResult, primitives::Address};
+
+    #[test]
+    fn nested_out_of_gas_causes_revert() {
+        let entry = Address::with_last_byte(0x11);
+        let callee = Address::with_last_byte(0xaa);
+
+        // Calls `callee` with 4096 gas and reverts if the call failed.
+        let entry_code = vec![
+            opcode::PUSH1,
+            0x00,
+            opcode::PUSH1,
+            0x00,
+            opcode::PUSH1,
+            0x00,
+            opcode::PUSH1,
+            0x00,
+            opcode::PUSH1,
+            0x00,
+            opcode::PUSH1,
+            0xaa,
+            opcode::PUSH2,
+            0x10,
+            0x00,
+            opcode::CALL,
+            opcode::PUSH1,
+            0x18,
+            opcode::JUMPI,
+            opcode::PUSH1,
+            0x00,
+            opcode::PUSH1,
+            0x00,
+            opcode::REVERT,
+            opcode::JUMPDEST,
+            opcode::STOP,
+        ];
+        // Infinite loop.
+        let callee_code = vec![opcode::JUMPDEST, opcode::PUSH1, 0x00, opcode::JUMP];
+
+        let (output, inspector) = run_with_inspector(
+            [(entry, entry_code), (callee, callee_code)],
+            FailureCauseInspector::new(),
+        );
+        assert!(matches!(output.result, ExecutionResult::Revert { .. }));
+        assert_eq!(
+            inspector.causes(),
+            [InstructionResult::Revert, InstructionResult::OutOfGas]
+        );
+    }
+}
@@ -0,0 +1,323 @@
+//! FilteredInspector. Forwards callbacks to an inner inspector only for matching addresses.
+use crate::{CallDispatch, Inspector, TransactionSummary};
+use revm::{
+    interpreter::{
+        interpreter_types::InputsTrait, CallInputs, CallOutcome, CreateInputs, CreateOutcome,
+        EOFCreateInputs, FrameInput, InstructionResult, Interpreter, InterpreterResult,
+        InterpreterTypes,
+    },
+    primitives::{Address, Log, U256},
+    state::EvmState,
+};
+use std::vec::Vec;
+
+/// [Inspector] that forwards callbacks to the inner inspector only if the address they relate
+/// to matches a predicate. Other callbacks are no-ops.
+///
+/// Interpreter callbacks are matched against the executing contract, call callbacks against
+/// the call target and create callbacks against the creating caller. Logs are matched by the
+/// emitting address. Transaction level callbacks are always forwarded.
+#[derive(Clone, Debug)]
+pub struct FilteredInspector<I, F> {
+    /// Inspector receiving the matching callbacks.
+    inner: I,
+    /// Predicate selecting the addresses to inspect.
+    filter: F,
+    /// Whether the instruction being executed is in a matching contract.
+    active: bool,
+}
+
+impl<I, F: FnMut(Address) -> bool> FilteredInspector<I, F> {
+    /// Creates a new inspector forwarding callbacks for addresses matching `filter` to `inner`.
+    pub fn new(inner: I, filter: F) -> Self {
+        Self {
+            inner,
+            filter,
+            active: false,
+        }
+    }
+
+    /// Returns a reference to the inner inspector.
+    pub fn inner(&self) -> &I {
+        &self.inner
+    }
+
+    /// Returns a mutable reference to the inner inspector.
+    pub fn inner_mut(&mut self) -> &mut I {
+        &mut self.inner
+    }
+
+    /// Consumes the filter and returns the inner inspector.
+    pub fn into_inner(self) -> I {
+        self.inner
+    }
+
+    /// Returns `true` if callbacks for `address` are forwarded.
+    pub fn matches(&mut self, address: Address) -> bool {
+        (self.filter)(address)
+    }
+
+    /// Returns `true` if callbacks for the given frame are forwarded.
+    fn matches_frame(&mut self, inputs: &FrameInput) -> bool {
+        let address = match inputs {
+            FrameInput::Call(inputs) => inputs.target_address,
+            FrameInput::Create(inputs) => inputs.caller,
+            FrameInput::EOFCreate(inputs) => inputs.caller,
+        };
+        self.matches(address)
+    }
+}
+
+impl<CTX, INTR, I, F> Inspector<CTX, INTR> for FilteredInspector<I, F>
+where
+    INTR: InterpreterTypes,
+    I: Inspector<CTX, INTR>,
+    F: FnMut(Address) -> bool,
+{
+    fn initialize_interp(&mut self, interp: &mut Interpreter<INTR>, context: &mut CTX) {
+        if self.matches(interp.input.target_address()) {
+            self.inner.initialize_interp(interp, context);
+        }
+    }
+
+    fn step(&mut self, interp: &mut Interpreter<INTR>, context: &mut CTX) {
+        self.active = self.matches(interp.input.target_address());
+        if self.active {
+            self.inner.step(interp, context);
+        }
+    }
+
+    fn step_end(&mut self, interp: &mut Interpreter<INTR>, context: &mut CTX) {
+        if self.active {
+            self.inner.step_end(interp, context);
+        }
+    }
+
+    fn halt(
+        &mut self,
+        interp: &mut Interpreter<INTR>,
+        context: &mut CTX,
+        result: InstructionResult,
+    ) {
+        if self.active {
+            self.inner.halt(interp, context, result);
+        }
+    }
+
+    fn static_violation(
+        &mut self,
+        interp: &mut Interpreter<INTR>,
+        context: &mut CTX,
+        opcode: u8,
+        target: Address,
+    ) {
+        if self.active {
+            self.inner.static_violation(interp, context, opcode, target);
+        }
+    }
+
+    fn memory_expansion(&mut self, old_size: usize, new_size: usize, gas_cost: u64) {
+        if self.active {
+            self.inner.memory_expansion(old_size, new_size, gas_cost);
+        }
+    }
+
+    fn log(&mut self, interp: &mut Interpreter<INTR>, context: &mut CTX, log: &Log) {
+        if self.matches(log.address) {
+            self.inner.log(interp, context, log);
+        }
+    }
+
+    fn access_cost(
+        &mut self,
+        interp: &mut Interpreter<INTR>,
+        context: &mut CTX,
+        opcode: u8,
+        is_cold: bool,
+    ) {
+        if self.active {
+            self.inner.access_cost(interp, context, opcode, is_cold);
+        }
+    }
+
+    fn jump(
+        &mut self,
+        interp: &mut Interpreter<INTR>,
+        context: &mut CTX,
+        from_pc: usize,
+        to_pc: usize,
+        conditional: bool,
+        taken: bool,
+    ) {
+        if self.active {
+            self.inner
+                .jump(interp, context, from_pc, to_pc, conditional, taken);
+        }
+    }
+
+    fn invalid_jump(&mut self, from_pc: usize, to_pc: usize) {
+        if self.active {
+            self.inner.invalid_jump(from_pc, to_pc);
+        }
+    }
+
+    fn transaction_start(&mut self, context: &mut CTX, intrinsic_gas: u64, gas_limit: u64) {
+        self.inner
+            .transaction_start(context, intrinsic_gas, gas_limit);
+    }
+
+    fn transaction_end(&mut self, summary: &TransactionSummary) {
+        self.inner.transaction_end(summary);
+    }
+
+    fn frame_start(&mut self, context: &mut CTX, inputs: &FrameInput, is_root: bool) {
+        if self.matches_frame(inputs) {
+            self.inner.frame_start(context, inputs, is_root);
+        }
+    }
+
+    fn frame_input_final(&mut self, context: &mut CTX, inputs: &FrameInput) {
+        if self.matches_frame(inputs) {
+            self.inner.frame_input_final(context, inputs);
+        }
+    }
+
+    fn call(&mut self, context: &mut CTX, inputs: &mut CallInputs) -> Option<CallOutcome> {
+        if !self.matches(inputs.target_address) {
+            return None;
+        }
+        self.inner.call(context, inputs)
+    }
+
+    fn call_dispatch(&mut self, context: &mut CTX, inputs: &CallInputs, dispatch: CallDispatch) {
+        if self.matches(inputs.target_address) {
+            self.inner.call_dispatch(context, inputs, dispatch);
+        }
+    }
+
+    fn call_end(&mut self, context: &mut CTX, inputs: &CallInputs, outcome: &mut CallOutcome) {
+        if self.matches(inputs.target_address) {
+            self.inner.call_end(context, inputs, outcome);
+        }
+    }
+
+    fn create(&mut self, context: &mut CTX, inputs: &mut CreateInputs) -> Option<CreateOutcome> {
+        if !self.matches(inputs.caller) {
+            return None;
+        }
+        self.inner.create(context, inputs)
+    }
+
+    fn create_end(
+        &mut self,
+        context: &mut CTX,
+        inputs: &CreateInputs,
+        outcome: &mut CreateOutcome,
+    ) {
+        if self.matches(inputs.caller) {
+            self.inner.create_end(context, inputs, outcome);
+        }
+    }
+
+    fn eofcreate(
+        &mut self,
+        context: &mut CTX,
+        inputs: &mut EOFCreateInputs,
+    ) -> Option<CreateOutcome> {
+        if !self.matches(inputs.caller) {
+            return None;
+        }
+        self.inner.eofcreate(context, inputs)
+    }
+
+    fn eofcreate_end(
+        &mut self,
+        context: &mut CTX,
+        inputs: &EOFCreateInputs,
+        outcome: &mut CreateOutcome,
+    ) {
+        if self.matches(inputs.caller) {
+            self.inner.eofcreate_end(context, inputs, outcome);
+        }
+    }
+
+    fn frame_logs(&mut self, logs: &[Log]) {
+        let logs: Vec<Log> = logs
+            .iter()
+            .filter(|log| (self.filter)(log.address))
+            .cloned()
+            .collect();
+        if !logs.is_empty() {
+            self.inner.frame_logs(&logs);
+        }
+    }
+
+    fn selfdestruct(&mut self, contract: Address, target: Address, value: U256) {
+        if self.matches(contract) {
+            self.inner.selfdestruct(contract, target, value);
+        }
+    }
+
+    fn selfdestruct_beneficiary(&mut self, target: Address, created: bool, balance: U256) {
+        if self.active {
+            self.inner
+                .selfdestruct_beneficiary(target, created, balance);
+        }
+    }
+
+    fn create_revert_snapshot_enabled(&self) -> bool {
+        self.inner.create_revert_snapshot_enabled()
+    }
+
+    fn create_revert_snapshot(
+        &mut self,
+        address: Address,
+        result: &InterpreterResult,
+        changes: &EvmState,
+    ) {
+        if self.matches(address) {
+            self.inner.create_revert_snapshot(address, result, changes);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::{call, run_with_inspector};
+    use revm::bytecode::opcode;
+
+    #[derive(Default)]
+    struct StepCounter {
+        steps: usize,
+    }
+
+    impl<CTX, INTR: InterpreterTypes> Inspector<CTX, INTR> for StepCounter {
+        fn step(&mut self, _interp: &mut Interpreter<INTR>, _context: &mut CTX) {
+            self.steps += 1;
+        }
+    }
+
+    #[test]
+    fn counts_steps_of_target_only() {
+        let entry = Address::with_last_byte(0x11);
+        let target = Address::with_last_byte(0xaa);
+
+        let entry_code = [&call(0xaa)[..], &[opcode::STOP]].concat();
+        let target_code = vec![
+            opcode::PUSH1,
+            0x01,
+            opcode::PUSH1,
+            0x00,
+            opcode::SSTORE,
+            opcode::STOP,
+        ];
+
+        let inspector =
+            FilteredInspector::new(StepCounter::default(), move |address| address == target);
+        let (output, inspector) =
+            run_with_inspector([(entry, entry_code), (target, target_code)], inspector);
+        assert!(output.result.is_success());
+        assert_eq!(inspector.inner().steps, 4);
+    }
+}
@@ -0,0 +1,152 @@
+//! PerAddressGasBudgetInspector. Caps the gas each address can consume in a transaction.
+use crate::Inspector;
+use revm::{
+    interpreter::{
+        interpreter_types::{InputsTrait, LoopControl},
+        CallInputs, CallOutcome, CreateInputs, CreateOutcome, EOFCreateInputs, InstructionResult,
+        Interpreter, InterpreterTypes,
+    },
+    primitives::{Address, HashMap},
+};
+
+/// [Inspector] that halts execution of an address once it consumed more than a gas budget.
+///
+/// Gas is accumulated per executing address across all of its frames. Gas forwarded to
+/// subcalls and creates is charged to the callee, not the caller. When an address exceeds
+/// the budget, its current frame halts with [`InstructionResult::OutOfGas`] on the next
+/// instruction, while other frames continue.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct PerAddressGasBudgetInspector {
+    /// Maximum gas an address can consume.
+    budget: u64,
+    /// Gas consumed per address.
+    gas_used: HashMap<Address, u64>,
+    /// Gas remaining before the current instruction.
+    gas_remaining: u64,
+    /// Address that executed the instruction creating the pending frame.
+    pending_frame: Option<Address>,
+}
+
+impl PerAddressGasBudgetInspector {
+    /// Creates a new inspector with the given per-address gas budget.
+    pub fn new(budget: u64) -> Self {
+        Self {
+            budget,
+            ..Default::default()
+        }
+    }
+
+    /// Returns the per-address gas budget.
+    pub fn budget(&self) -> u64 {
+        self.budget
+    }
+
+    /// Returns the gas consumed by `address`.
+    pub fn gas_used(&self, address: &Address) -> u64 {
+        self.gas_used.get(address).copied().unwrap_or_default()
+    }
+
+    /// Returns `true` if `address` consumed more than the budget.
+    pub fn is_exceeded(&self, address: &Address) -> bool {
+        self.gas_used(address) > self.budget
+    }
+
+    /// Moves the gas forwarded to a new frame from its creator to the new frame.
+    fn uncharge_forwarded(&mut self, forwarded: u64) {
+        if let Some(address) = self.pending_frame.take() {
+            let used = self.gas_used.entry(address).or_default();
+            *used = used.saturating_sub(forwarded);
+        }
+    }
+}
+
+impl<CTX, INTR: InterpreterTypes> Inspector<CTX, INTR> for PerAddressGasBudgetInspector {
+    fn step(&mut self, interp: &mut Interpreter<INTR>, _context: &mut CTX) {
+        if self.is_exceeded(&interp.input.target_address()) {
+            interp
+                .control
+                .set_instruction_result(InstructionResult::OutOfGas);
+            return;
+        }
+        self.gas_remaining = interp.control.gas().remaining();
+    }
+
+    fn step_end(&mut self, interp: &mut Interpreter<INTR>, _context: &mut CTX) {
+        let address = interp.input.target_address();
+        let cost = self
+            .gas_remaining
+            .saturating_sub(interp.control.gas().remaining());
+        *self.gas_used.entry(address).or_default() += cost;
+
+        if interp.control.instruction_result() == InstructionResult::CallOrCreate {
+            self.pending_frame = Some(address);
+        }
+    }
+
+    fn call(&mut self, _context: &mut CTX, inputs: &mut CallInputs) -> Option<CallOutcome> {
+        // Stipend is not paid by the caller.
+        self.uncharge_forwarded(inputs.gas_limit.saturating_sub(inputs.stipend));
+        None
+    }
+
+    fn create(&mut self, _context: &mut CTX, inputs: &mut CreateInputs) -> Option<CreateOutcome> {
+        self.uncharge_forwarded(inputs.gas_limit);
+        None
+    }
+
+    fn eofcreate(
+        &mut self,
+        _context: &mut CTX,
+        inputs: &mut EOFCreateInputs,
+    ) -> Option<CreateOutcome> {
+        self.uncharge_forwarded(inputs.gas_limit);
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::{call, run_with_inspector};
+    use revm::{bytecode::opcode, primitives::U256};
+
+    #[test]
+    fn hot_contract_exceeds_budget() {
+        let entry = Address::with_last_byte(0x11);
+        let hot = Address::with_last_byte(0xaa);
+        let cold = Address::with_last_byte(0xcc);
+
+        let entry_code = [
+            &call(0xaa)[..],
+            &[opcode::POP],
+            &call(0xcc),
+            &[opcode::POP, opcode::STOP],
+        ]
+        .concat();
+        // Infinite loop.
+        let hot_code = vec![opcode::JUMPDEST, opcode::PUSH1, 0x00, opcode::JUMP];
+        let cold_code = vec![
+            opcode::PUSH1,
+            0x01,
+            opcode::PUSH1,
+            0x00,
+            opcode::SSTORE,
+            opcode::STOP,
+        ];
+
+        let (output, inspector) = run_with_inspector(
+            [(entry, entry_code), (hot, hot_code), (cold, cold_code)],
+            PerAddressGasBudgetInspector::new(50_000),
+        );
+        assert!(output.result.is_success());
+        assert_eq!(
+            output.state[&cold].storage[&U256::ZERO].present_value,
+            U256::from(1)
+        );
+
+        assert!(inspector.is_exceeded(&hot));
+        assert!(!inspector.is_exceeded(&entry));
+        assert!(!inspector.is_exceeded(&cold));
+        assert!(inspector.gas_used(&cold) > 20_000);
+    }
+}
@@ -1,34 +1,51 @@
 use auto_impl::auto_impl;
-use core::mem::MaybeUninit;
+use core::{
+    cell::{Cell, RefCell},
+    mem::MaybeUninit,
+};
 use revm::{
     bytecode::opcode::OpCode,
-    context::JournaledState,
+    context::{tx::TxEnv, JournaledState},
     context_interface::{
         block::BlockSetter,
-        journaled_state::{AccountLoad, Eip7702CodeLoad},
+        journaled_state::{AccountLoad, Eip7702CodeLoad, JournalCheckpoint},
+        result::{ExecutionResult, HaltReason, HaltReasonTrait, ResultAndState},
         transaction::TransactionSetter,
-        BlockGetter, CfgGetter, DatabaseGetter, ErrorGetter, Journal, JournalDBError,
-        JournalGetter, TransactionGetter,
+        Block, BlockGetter, Cfg, CfgGetter, DatabaseGetter, ErrorGetter, Journal, JournalDBError,
+        JournalGetter, Transaction, TransactionGetter,
     },
     database_interface::{Database, EmptyDB},
     handler::{
-        EthExecution, EthFrame, EthHandler, EthPostExecution, EthPreExecution,
-        EthPrecompileProvider, EthValidation, FrameResult,
+        EthExecution, EthFrame, EthHandler, EthPostExecution, EthPostExecutionContext,
+        EthPostExecutionError, EthPreExecution, EthPrecompileProvider, EthValidation, FrameData,
+        FrameResult,
+    },
+    handler_interface::{
+        util::FrameOrFrameResult, ExecutionHandler, Frame, FrameOrResultGen, PostExecutionHandler,
+        PrecompileProvider,
     },
-    handler_interface::{Frame, FrameOrResultGen, PrecompileProvider},
     interpreter::{
-        instructions::host::{log, selfdestruct},
-        interpreter::{EthInterpreter, InstructionProvider},
-        interpreter_types::{Jumps, LoopControl},
+        gas,
+        instructions::{
+            contract, control,
+            host::{self, log, selfdestruct},
+            utility::IntoAddress,
+        },
+        interpreter::{num_words, EthInterpreter, InstructionProvider},
+        interpreter_types::{
+            InputsTrait, Jumps, LoopControl, MemoryTrait, RuntimeFlag, StackTrait,
+        },
         table::{self, CustomInstruction},
-        CallInputs, CallOutcome, CreateInputs, CreateOutcome, EOFCreateInputs, FrameInput, Host,
-        Instruction, InstructionResult, Interpreter, InterpreterTypes, SStoreResult,
-        SelfDestructResult, StateLoad,
+        CallInputs, CallOutcome, CreateInputs, CreateOutcome, EOFCreateInputs,
+        EmptyAccountCallResult, FrameInfo, FrameInput, Gas, Host, Instruction, InstructionResult,
+        Interpreter, InterpreterAction, InterpreterResult, InterpreterTypes, SStoreResult,
+        SelfDestructResult, SharedMemory, StateLoad,
     },
     precompile::PrecompileErrors,
     primitives::{Address, Bytes, Log, B256, U256},
-    state::EvmState,
-    Context, Error, Evm, JournalEntry,
+    specification::hardfork::SpecId,
+    state::{Account, AccountInfo, EvmState},
+    Context, Error, EthContext, Evm, JournalEntry,
 };
 use std::{rc::Rc, vec::Vec};
 
@@ -69,6 +86,52 @@ pub trait Inspector<CTX, INTR: InterpreterTypes> {
         let _ = context;
     }
 
+    /// Called when an instruction halts the current frame with an error.
+    ///
+    /// `result` distinguishes the cause, e.g. [`InstructionResult::StackUnderflow`] from
+    /// [`InstructionResult::StackOverflow`]. This is called after [`Inspector::step_end`],
+    /// before the frame exits.
+    #[inline]
+    fn halt(
+        &mut self,
+        interp: &mut Interpreter<INTR>,
+        context: &mut CTX,
+        result: InstructionResult,
+    ) {
+        let _ = interp;
+        let _ = context;
+        let _ = result;
+    }
+
+    /// Called when an instruction attempted a state change in static context and was vetoed.
+    ///
+    /// `target` is the callee for value-bearing calls and the executing contract otherwise.
+    /// This is called after [`Inspector::step_end`], before [`Inspector::halt`].
+    #[inline]
+    fn static_violation(
+        &mut self,
+        interp: &mut Interpreter<INTR>,
+        context: &mut CTX,
+        opcode: u8,
+        target: Address,
+    ) {
+        let _ = interp;
+        let _ = context;
+        let _ = opcode;
+        let _ = target;
+    }
+
+    /// Called when an instruction expanded the memory of the current frame.
+    ///
+    /// Sizes are in bytes and `gas_cost` is the memory expansion gas charged for the growth.
+    /// This is called before [`Inspector::step_end`].
+    #[inline]
+    fn memory_expansion(&mut self, old_size: usize, new_size: usize, gas_cost: u64) {
+        let _ = old_size;
+        let _ = new_size;
+        let _ = gas_cost;
+    }
+
     /// Called when a log is emitted.
     #[inline]
     fn log(&mut self, interp: &mut Interpreter<INTR>, context: &mut CTX, log: &Log) {
@@ -77,9 +140,105 @@ pub trait Inspector<CTX, INTR: InterpreterTypes> {
         let _ = log;
     }
 
+    /// Called after an instruction that accesses an account or a storage slot has been executed.
+    ///
+    /// `is_cold` is `true` if the account or slot was not yet warm and the cold access cost was
+    /// charged.
+    #[inline]
+    fn access_cost(
+        &mut self,
+        interp: &mut Interpreter<INTR>,
+        context: &mut CTX,
+        opcode: u8,
+        is_cold: bool,
+    ) {
+        let _ = interp;
+        let _ = context;
+        let _ = opcode;
+        let _ = is_cold;
+    }
+
+    /// Called after a `JUMP` or `JUMPI` has been executed, from `from_pc` to the `to_pc` target.
+    ///
+    /// `conditional` is `true` for `JUMPI`, and `taken` is `false` if its condition was zero.
+    /// Jumps to an invalid destination halt instead, see [`Inspector::invalid_jump`].
+    #[inline]
+    fn jump(
+        &mut self,
+        interp: &mut Interpreter<INTR>,
+        context: &mut CTX,
+        from_pc: usize,
+        to_pc: usize,
+        conditional: bool,
+        taken: bool,
+    ) {
+        let _ = interp;
+        let _ = context;
+        let _ = from_pc;
+        let _ = to_pc;
+        let _ = conditional;
+        let _ = taken;
+    }
+
+    /// Called when a `JUMP` or `JUMPI` at `from_pc` targets `to_pc`, which is not a `JUMPDEST`.
+    ///
+    /// The frame halts with [`InstructionResult::InvalidJump`], this is called before
+    /// [`Inspector::halt`]. Targets that do not fit in `usize` are reported as `usize::MAX`.
+    #[inline]
+    fn invalid_jump(&mut self, from_pc: usize, to_pc: usize) {
+        let _ = from_pc;
+        let _ = to_pc;
+    }
+
+    /// Called once per transaction after validation and the intrinsic gas deduction, before the
+    /// first frame starts.
+    ///
+    /// `gas_limit` is the gas limit of the transaction and `intrinsic_gas` the part of it that
+    /// was charged upfront. With gas metering disabled, `intrinsic_gas` is reported as zero.
+    #[inline]
+    fn transaction_start(&mut self, context: &mut CTX, intrinsic_gas: u64, gas_limit: u64) {
+        let _ = context;
+        let _ = intrinsic_gas;
+        let _ = gas_limit;
+    }
+
+    /// Called once per transaction after the caller was reimbursed and the beneficiary
+    /// rewarded, with the fee accounting of the transaction.
+    ///
+    /// It is only called by handlers using [`InspectorEthPostExecution`].
+    #[inline]
+    fn transaction_end(&mut self, summary: &TransactionSummary) {
+        let _ = summary;
+    }
+
+    /// Called whenever a frame is about to start, before [`Inspector::call`],
+    /// [`Inspector::create`] or [`Inspector::eofcreate`].
+    ///
+    /// `is_root` is `true` only for the first frame of the transaction.
+    #[inline]
+    fn frame_start(&mut self, context: &mut CTX, inputs: &FrameInput, is_root: bool) {
+        let _ = context;
+        let _ = inputs;
+        let _ = is_root;
+    }
+
+    /// Called with the final inputs of a frame, after [`Inspector::call`],
+    /// [`Inspector::create`] or [`Inspector::eofcreate`] and before the frame is initialized.
+    ///
+    /// Inputs modified by those hooks are reported as the frame receives them. It is not
+    /// called if the frame result was overridden.
+    #[inline]
+    fn frame_input_final(&mut self, context: &mut CTX, inputs: &FrameInput) {
+        let _ = context;
+        let _ = inputs;
+    }
+
     /// Called whenever a call to a contract is about to start.
     ///
     /// InstructionResulting anything other than [revm::interpreter::InstructionResult::Continue] overrides the result of the call.
+    ///
+    /// The originating transaction is available through `context.tx()`, see also
+    /// [`Host::tx_origin`] and [`Host::effective_gas_price`].
     #[inline]
     fn call(&mut self, context: &mut CTX, inputs: &mut CallInputs) -> Option<CallOutcome> {
         let _ = context;
@@ -87,6 +246,16 @@ pub trait Inspector<CTX, INTR: InterpreterTypes> {
         None
     }
 
+    /// Called when the dispatch of a call target has been resolved, before the call is executed.
+    ///
+    /// This is called after [`Inspector::call`] if it did not override the result of the call.
+    #[inline]
+    fn call_dispatch(&mut self, context: &mut CTX, inputs: &CallInputs, dispatch: CallDispatch) {
+        let _ = context;
+        let _ = inputs;
+        let _ = dispatch;
+    }
+
     /// Called when a call to a contract has concluded.
     ///
     /// The returned [CallOutcome] is used as the result of the call.
@@ -104,6 +273,8 @@ pub trait Inspector<CTX, INTR: InterpreterTypes> {
     /// If this returns `Some` then the [CreateOutcome] is used to override the result of the creation.
     ///
     /// If this returns `None` then the creation proceeds as normal.
+    ///
+    /// As with [`Inspector::call`], the originating transaction is available through `context.tx()`.
     #[inline]
     fn create(&mut self, context: &mut CTX, inputs: &mut CreateInputs) -> Option<CreateOutcome> {
         let _ = context;
@@ -152,6 +323,15 @@ pub trait Inspector<CTX, INTR: InterpreterTypes> {
         let _ = outcome;
     }
 
+    /// Called when a frame ends, right before [`Inspector::call_end`], [`Inspector::create_end`]
+    /// or [`Inspector::eofcreate_end`], with the logs emitted by the frame and its subframes.
+    ///
+    /// Logs of reverted frames are rolled back, so `logs` is empty for them.
+    #[inline]
+    fn frame_logs(&mut self, logs: &[Log]) {
+        let _ = logs;
+    }
+
     /// Called when a contract has been self-destructed with funds transferred to target.
     #[inline]
     fn selfdestruct(&mut self, contract: Address, target: Address, value: U256) {
@@ -159,6 +339,113 @@ pub trait Inspector<CTX, INTR: InterpreterTypes> {
         let _ = target;
         let _ = value;
     }
+
+    /// Called after [`Inspector::selfdestruct`] with the resulting state of the beneficiary.
+    ///
+    /// `created` is `true` if the target was empty before and the transferred funds
+    /// brought it into existence. `balance` is the balance of the target after the transfer.
+    #[inline]
+    fn selfdestruct_beneficiary(&mut self, target: Address, created: bool, balance: U256) {
+        let _ = target;
+        let _ = created;
+        let _ = balance;
+    }
+
+    /// Returns `true` if [`Inspector::create_revert_snapshot`] should be called.
+    ///
+    /// Building the snapshot walks the journal of the failed frame, so it is disabled by default.
+    #[inline]
+    fn create_revert_snapshot_enabled(&self) -> bool {
+        false
+    }
+
+    /// Called when the execution of a create frame failed, before its changes are reverted.
+    ///
+    /// `changes` contains the accounts changed by the frame and its subframes, with only the
+    /// storage slots they changed. All of it is discarded once the hook returns.
+    #[inline]
+    fn create_revert_snapshot(
+        &mut self,
+        address: Address,
+        result: &InterpreterResult,
+        changes: &EvmState,
+    ) {
+        let _ = address;
+        let _ = result;
+        let _ = changes;
+    }
+}
+
+/// Resolved dispatch of a call target.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum CallDispatch {
+    /// Target is a precompile.
+    Precompile,
+    /// Target is an account with code.
+    Contract,
+    /// Target is an existing account without code.
+    Eoa,
+    /// Target account is empty or does not exist.
+    Empty,
+}
+
+impl CallDispatch {
+    /// Classifies an account that is not a precompile.
+    fn of(info: &AccountInfo) -> Self {
+        if !info.is_empty_code_hash() {
+            Self::Contract
+        } else if !info.is_empty() {
+            Self::Eoa
+        } else {
+            Self::Empty
+        }
+    }
+}
+
+/// Builds the outcome of a call skipped by [`Inspector::call`].
+///
+/// [`Inspector::call`] sees the resolved call, with its target, code address and forwarded
+/// gas in `inputs`. Returning this outcome from it resolves the call immediately: the callee
+/// is not executed, and no step or frame callback is made for it. The call succeeds with
+/// `output` as return data, or reverts if `success` is `false`, and `gas_used` of the
+/// forwarded gas is charged, capped by the gas limit of the call.
+pub fn skip_call(inputs: &CallInputs, success: bool, gas_used: u64, output: Bytes) -> CallOutcome {
+    let mut gas = Gas::new(inputs.gas_limit);
+    gas.record_cost(gas_used.min(inputs.gas_limit));
+    let result = if success {
+        InstructionResult::Return
+    } else {
+        InstructionResult::Revert
+    };
+    CallOutcome::new(
+        InterpreterResult {
+            result,
+            output,
+            gas,
+        },
+        inputs.return_memory_offset.clone(),
+    )
+}
+
+/// Fee accounting of a transaction, reported by [`Inspector::transaction_end`].
+///
+/// Blob gas is paid separately and is not included.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct TransactionSummary {
+    /// Gas limit of the transaction, purchased upfront.
+    pub gas_limit: u64,
+    /// Gas used by the transaction after the refund.
+    pub gas_used: u64,
+    /// Gas refunded to the caller, capped by the refund quotient.
+    pub gas_refunded: u64,
+    /// Price paid per unit of gas.
+    pub effective_gas_price: u128,
+    /// Value paid by the caller for the gas limit.
+    pub gas_cost: U256,
+    /// Value returned to the caller for the unused and refunded gas.
+    pub caller_reimbursement: U256,
+    /// Value credited to the block beneficiary.
+    pub coinbase_reward: U256,
 }
 
 /// Provides access to an `Inspector` instance.
@@ -173,10 +460,44 @@ pub trait InspectorCtx {
     fn step(&mut self, interp: &mut Interpreter<Self::IT>);
     fn step_end(&mut self, interp: &mut Interpreter<Self::IT>);
     fn initialize_interp(&mut self, interp: &mut Interpreter<Self::IT>);
+    fn inspector_transaction_start(&mut self, intrinsic_gas: u64, gas_limit: u64);
+    fn inspector_transaction_end(&mut self, summary: &TransactionSummary);
     fn frame_start(&mut self, frame_input: &mut FrameInput) -> Option<FrameResult>;
     fn frame_end(&mut self, frame_output: &mut FrameResult);
+    fn inspector_call_dispatch(&mut self, inputs: &CallInputs, dispatch: CallDispatch);
     fn inspector_selfdestruct(&mut self, contract: Address, target: Address, value: U256);
+    fn inspector_selfdestruct_beneficiary(&mut self, target: Address, created: bool, balance: U256);
+    fn inspector_create_revert_snapshot_enabled(&mut self) -> bool;
+    fn inspector_create_revert_snapshot(
+        &mut self,
+        address: Address,
+        result: &InterpreterResult,
+        changes: &EvmState,
+    );
     fn inspector_log(&mut self, interp: &mut Interpreter<Self::IT>, log: &Log);
+    fn inspector_memory_expansion(&mut self, old_size: usize, new_size: usize, gas_cost: u64);
+    fn inspector_halt(&mut self, interp: &mut Interpreter<Self::IT>, result: InstructionResult);
+    fn inspector_static_violation(
+        &mut self,
+        interp: &mut Interpreter<Self::IT>,
+        opcode: u8,
+        target: Address,
+    );
+    fn inspector_access_cost(
+        &mut self,
+        interp: &mut Interpreter<Self::IT>,
+        opcode: u8,
+        is_cold: bool,
+    );
+    fn inspector_jump(
+        &mut self,
+        interp: &mut Interpreter<Self::IT>,
+        from_pc: usize,
+        to_pc: usize,
+        conditional: bool,
+        taken: bool,
+    );
+    fn inspector_invalid_jump(&mut self, from_pc: usize, to_pc: usize);
 }
 
 impl<CTX, INTR: InterpreterTypes, INSP: Inspector<CTX, INTR>> GetInspector<CTX, INTR> for INSP {
@@ -195,6 +516,8 @@ where
     pub inspector: INSP,
     pub inner: CTX,
     pub frame_input_stack: Vec<FrameInput>,
+    /// Number of logs when each frame of `frame_input_stack` started.
+    pub frame_log_start_stack: Vec<usize>,
 }
 
 impl<INSP, DB, CTX> InspectorContext<INSP, DB, CTX>
@@ -213,6 +536,7 @@ where
             inner,
             inspector,
             frame_input_stack: Vec::new(),
+            frame_log_start_stack: Vec::new(),
         }
     }
 }
@@ -225,14 +549,42 @@ where
         self.inner.block_hash(requested_number)
     }
 
+    fn prevrandao(&mut self) -> Option<B256> {
+        self.inner.prevrandao()
+    }
+
     fn load_account_delegated(&mut self, address: Address) -> Option<AccountLoad> {
         self.inner.load_account_delegated(address)
     }
 
+    fn call_empty_account(
+        &mut self,
+        address: Address,
+        input: &Bytes,
+        gas_limit: u64,
+    ) -> Option<EmptyAccountCallResult> {
+        self.inner.call_empty_account(address, input, gas_limit)
+    }
+
+    fn call_zero_address(
+        &mut self,
+        caller: Address,
+        value: U256,
+        input: &Bytes,
+        gas_limit: u64,
+    ) -> Option<EmptyAccountCallResult> {
+        self.inner
+            .call_zero_address(caller, value, input, gas_limit)
+    }
+
     fn balance(&mut self, address: Address) -> Option<StateLoad<U256>> {
         self.inner.balance(address)
     }
 
+    fn self_balance(&mut self, address: Address) -> Option<U256> {
+        self.inner.self_balance(address)
+    }
+
     fn code(&mut self, address: Address) -> Option<Eip7702CodeLoad<Bytes>> {
         self.inner.code(address)
     }
@@ -266,6 +618,10 @@ where
         self.inner.log(log);
     }
 
+    fn log_count(&self) -> usize {
+        self.inner.log_count()
+    }
+
     fn selfdestruct(
         &mut self,
         address: Address,
@@ -278,7 +634,7 @@ where
 impl<INSP, DB, CTX> InspectorCtx for InspectorContext<INSP, DB, CTX>
 where
     INSP: GetInspector<CTX, EthInterpreter>,
-    CTX: DatabaseGetter<Database = DB>,
+    CTX: DatabaseGetter<Database = DB> + JournalExtGetter,
 {
     type IT = EthInterpreter<()>;
 
@@ -297,15 +653,84 @@ where
             .get_inspector()
             .initialize_interp(interp, &mut self.inner);
     }
+
+    fn inspector_transaction_start(&mut self, intrinsic_gas: u64, gas_limit: u64) {
+        self.inspector
+            .get_inspector()
+            .transaction_start(&mut self.inner, intrinsic_gas, gas_limit);
+    }
+
+    fn inspector_transaction_end(&mut self, summary: &TransactionSummary) {
+        self.inspector.get_inspector().transaction_end(summary);
+    }
+
     fn inspector_log(&mut self, interp: &mut Interpreter<Self::IT>, log: &Log) {
         self.inspector
             .get_inspector()
             .log(interp, &mut self.inner, log);
     }
 
+    fn inspector_memory_expansion(&mut self, old_size: usize, new_size: usize, gas_cost: u64) {
+        self.inspector
+            .get_inspector()
+            .memory_expansion(old_size, new_size, gas_cost);
+    }
+
+    fn inspector_halt(&mut self, interp: &mut Interpreter<Self::IT>, result: InstructionResult) {
+        self.inspector
+            .get_inspector()
+            .halt(interp, &mut self.inner, result);
+    }
+
+    fn inspector_static_violation(
+        &mut self,
+        interp: &mut Interpreter<Self::IT>,
+        opcode: u8,
+        target: Address,
+    ) {
+        self.inspector
+            .get_inspector()
+            .static_violation(interp, &mut self.inner, opcode, target);
+    }
+
+    fn inspector_access_cost(
+        &mut self,
+        interp: &mut Interpreter<Self::IT>,
+        opcode: u8,
+        is_cold: bool,
+    ) {
+        self.inspector
+            .get_inspector()
+            .access_cost(interp, &mut self.inner, opcode, is_cold);
+    }
+
+    fn inspector_jump(
+        &mut self,
+        interp: &mut Interpreter<Self::IT>,
+        from_pc: usize,
+        to_pc: usize,
+        conditional: bool,
+        taken: bool,
+    ) {
+        self.inspector.get_inspector().jump(
+            interp,
+            &mut self.inner,
+            from_pc,
+            to_pc,
+            conditional,
+            taken,
+        );
+    }
+
+    fn inspector_invalid_jump(&mut self, from_pc: usize, to_pc: usize) {
+        self.inspector.get_inspector().invalid_jump(from_pc, to_pc);
+    }
+
     fn frame_start(&mut self, frame_input: &mut FrameInput) -> Option<FrameResult> {
         let insp = self.inspector.get_inspector();
         let context = &mut self.inner;
+        let is_root = self.frame_input_stack.is_empty();
+        insp.frame_start(context, frame_input, is_root);
         match frame_input {
             FrameInput::Call(i) => {
                 if let Some(output) = insp.call(context, i) {
@@ -323,12 +748,19 @@ where
                 }
             }
         }
+        insp.frame_input_final(context, frame_input);
         self.frame_input_stack.push(frame_input.clone());
+        self.frame_log_start_stack
+            .push(self.inner.journal_ext().logs().len());
         None
     }
 
     fn frame_end(&mut self, frame_output: &mut FrameResult) {
         let insp = self.inspector.get_inspector();
+        let log_start = self.frame_log_start_stack.pop().expect("Frame pushed");
+        let logs = self.inner.journal_ext().logs();
+        insp.frame_logs(logs.get(log_start..).unwrap_or_default());
+
         let context = &mut self.inner;
         let frame_input = self.frame_input_stack.pop().expect("Frame pushed");
         match frame_output {
@@ -353,11 +785,45 @@ where
         }
     }
 
+    fn inspector_call_dispatch(&mut self, inputs: &CallInputs, dispatch: CallDispatch) {
+        self.inspector
+            .get_inspector()
+            .call_dispatch(&mut self.inner, inputs, dispatch);
+    }
+
     fn inspector_selfdestruct(&mut self, contract: Address, target: Address, value: U256) {
         self.inspector
             .get_inspector()
             .selfdestruct(contract, target, value)
     }
+
+    fn inspector_selfdestruct_beneficiary(
+        &mut self,
+        target: Address,
+        created: bool,
+        balance: U256,
+    ) {
+        self.inspector
+            .get_inspector()
+            .selfdestruct_beneficiary(target, created, balance)
+    }
+
+    fn inspector_create_revert_snapshot_enabled(&mut self) -> bool {
+        self.inspector
+            .get_inspector()
+            .create_revert_snapshot_enabled()
+    }
+
+    fn inspector_create_revert_snapshot(
+        &mut self,
+        address: Address,
+        result: &InterpreterResult,
+        changes: &EvmState,
+    ) {
+        self.inspector
+            .get_inspector()
+            .create_revert_snapshot(address, result, changes)
+    }
 }
 
 impl<INSP, DB, CTX> CfgGetter for InspectorContext<INSP, DB, CTX>
@@ -498,14 +964,51 @@ where
             return;
         }
 
+        let opcode = interpreter.bytecode.opcode();
         // Reset PC to previous value.
         interpreter.bytecode.relative_jump(1);
 
+        // Peek the callee of a call, which is popped before the value is checked.
+        let call_target = if !interpreter.runtime_flag.is_static() {
+            None
+        } else if opcode == OpCode::CALL.get() {
+            interpreter.stack.popn::<2>().map(|[gas, to]| {
+                let _ = interpreter.stack.push(to);
+                let _ = interpreter.stack.push(gas);
+                to.into_address()
+            })
+        } else if opcode == OpCode::EXTCALL.get() {
+            interpreter.stack.top().map(|to| to.into_address())
+        } else {
+            None
+        };
+
         // Execute instruction.
+        let memory_size = interpreter.memory.size();
         (self.instruction)(interpreter, host);
 
+        let new_memory_size = interpreter.memory.size();
+        if new_memory_size > memory_size {
+            let gas_cost = gas::memory_gas(num_words(new_memory_size))
+                - gas::memory_gas(num_words(memory_size));
+            host.inspector_memory_expansion(memory_size, new_memory_size, gas_cost);
+        }
+
         // Call step_end.
         host.step_end(interpreter);
+
+        let result = interpreter.control.instruction_result();
+        if matches!(
+            result,
+            InstructionResult::StateChangeDuringStaticCall
+                | InstructionResult::CallNotAllowedInsideStatic
+        ) {
+            let target = call_target.unwrap_or_else(|| interpreter.input.target_address());
+            host.inspector_static_violation(interpreter, opcode, target);
+        }
+        if result.is_error() {
+            host.inspector_halt(interpreter, result);
+        }
     }
 
     fn from_base(instruction: Instruction<Self::Wire, Self::Host>) -> Self {
@@ -533,6 +1036,9 @@ pub trait JournalExt {
 
     fn last_journal(&self) -> &[JournalEntry];
 
+    /// Returns the journal entries recorded since `checkpoint`.
+    fn journal_since(&self, checkpoint: JournalCheckpoint) -> &[Vec<JournalEntry>];
+
     fn evm_state(&self) -> &EvmState;
 
     fn evm_state_mut(&mut self) -> &mut EvmState;
@@ -547,6 +1053,10 @@ impl<DB: Database> JournalExt for JournaledState<DB> {
         self.journal.last().expect("Journal is never empty")
     }
 
+    fn journal_since(&self, checkpoint: JournalCheckpoint) -> &[Vec<JournalEntry>] {
+        &self.journal[checkpoint.journal_i..]
+    }
+
     fn evm_state(&self) -> &EvmState {
         &self.state
     }
@@ -604,61 +1114,273 @@ where
             }
         }
 
-        /* LOG and Selfdestruct instructions */
-        table[OpCode::LOG0.as_usize()] = InspectorInstruction {
+        // Inspector access cost wrapper
+        fn inspector_access_cost<CTX: Host + JournalExtGetter + InspectorCtx>(
+            interpreter: &mut Interpreter<<CTX as InspectorCtx>::IT>,
+            context: &mut CTX,
+            opcode: OpCode,
+            prev: Instruction<<CTX as InspectorCtx>::IT, CTX>,
+        ) {
+            let journal_len = context.journal_ext().last_journal().len();
+            prev(interpreter, context);
+
+            if matches!(
+                interpreter.control.instruction_result(),
+                InstructionResult::Continue | InstructionResult::CallOrCreate
+            ) {
+                // Forced cold accesses are charged as cold without warming anything.
+                let is_cold = context.cfg().is_cold_access_forced()
+                    || context.journal_ext().last_journal()[journal_len..]
+                        .iter()
+                        .any(|entry| {
+                            matches!(
+                                entry,
+                                JournalEntry::AccountWarmed { .. }
+                                    | JournalEntry::StorageWarmed { .. }
+                            )
+                        });
+                context.inspector_access_cost(interpreter, opcode.get(), is_cold);
+            }
+        }
+
+        /* Account and storage access instructions */
+        table[OpCode::BALANCE.as_usize()] = InspectorInstruction {
             instruction: |interp, context| {
-                inspector_log(interp, context, log::<0, HOST>);
+                inspector_access_cost(
+                    interp,
+                    context,
+                    OpCode::BALANCE,
+                    host::balance::<WIRE, HOST>,
+                );
             },
         };
-        table[OpCode::LOG1.as_usize()] = InspectorInstruction {
+        table[OpCode::EXTCODESIZE.as_usize()] = InspectorInstruction {
             instruction: |interp, context| {
-                inspector_log(interp, context, log::<1, HOST>);
+                inspector_access_cost(
+                    interp,
+                    context,
+                    OpCode::EXTCODESIZE,
+                    host::extcodesize::<WIRE, HOST>,
+                );
             },
         };
-        table[OpCode::LOG2.as_usize()] = InspectorInstruction {
+        table[OpCode::EXTCODEHASH.as_usize()] = InspectorInstruction {
             instruction: |interp, context| {
-                inspector_log(interp, context, log::<2, HOST>);
+                inspector_access_cost(
+                    interp,
+                    context,
+                    OpCode::EXTCODEHASH,
+                    host::extcodehash::<WIRE, HOST>,
+                );
             },
         };
-        table[OpCode::LOG3.as_usize()] = InspectorInstruction {
+        table[OpCode::EXTCODECOPY.as_usize()] = InspectorInstruction {
             instruction: |interp, context| {
-                inspector_log(interp, context, log::<3, HOST>);
+                inspector_access_cost(
+                    interp,
+                    context,
+                    OpCode::EXTCODECOPY,
+                    host::extcodecopy::<WIRE, HOST>,
+                );
             },
         };
-        table[OpCode::LOG4.as_usize()] = InspectorInstruction {
+        table[OpCode::SLOAD.as_usize()] = InspectorInstruction {
             instruction: |interp, context| {
-                inspector_log(interp, context, log::<4, HOST>);
+                inspector_access_cost(interp, context, OpCode::SLOAD, host::sload::<WIRE, HOST>);
             },
         };
-
-        table[OpCode::SELFDESTRUCT.as_usize()] = InspectorInstruction {
+        table[OpCode::SSTORE.as_usize()] = InspectorInstruction {
             instruction: |interp, context| {
-                selfdestruct::<Self::WIRE, HOST>(interp, context);
-                if interp.control.instruction_result() == InstructionResult::SelfDestruct {
-                    match context.journal_ext().last_journal().last() {
-                        Some(JournalEntry::AccountDestroyed {
-                            address,
-                            target,
-                            had_balance,
-                            ..
-                        }) => {
-                            context.inspector_selfdestruct(*address, *target, *had_balance);
-                        }
-                        Some(JournalEntry::BalanceTransfer {
-                            from, to, balance, ..
-                        }) => {
-                            context.inspector_selfdestruct(*from, *to, *balance);
-                        }
-                        _ => {}
-                    }
-                }
+                inspector_access_cost(interp, context, OpCode::SSTORE, host::sstore::<WIRE, HOST>);
+            },
+        };
+        table[OpCode::CALL.as_usize()] = InspectorInstruction {
+            instruction: |interp, context| {
+                inspector_access_cost(interp, context, OpCode::CALL, contract::call::<WIRE, HOST>);
+            },
+        };
+        table[OpCode::CALLCODE.as_usize()] = InspectorInstruction {
+            instruction: |interp, context| {
+                inspector_access_cost(
+                    interp,
+                    context,
+                    OpCode::CALLCODE,
+                    contract::call_code::<WIRE, HOST>,
+                );
+            },
+        };
+        table[OpCode::DELEGATECALL.as_usize()] = InspectorInstruction {
+            instruction: |interp, context| {
+                inspector_access_cost(
+                    interp,
+                    context,
+                    OpCode::DELEGATECALL,
+                    contract::delegate_call::<WIRE, HOST>,
+                );
+            },
+        };
+        table[OpCode::STATICCALL.as_usize()] = InspectorInstruction {
+            instruction: |interp, context| {
+                inspector_access_cost(
+                    interp,
+                    context,
+                    OpCode::STATICCALL,
+                    contract::static_call::<WIRE, HOST>,
+                );
+            },
+        };
+        table[OpCode::EXTCALL.as_usize()] = InspectorInstruction {
+            instruction: |interp, context| {
+                inspector_access_cost(
+                    interp,
+                    context,
+                    OpCode::EXTCALL,
+                    contract::extcall::<WIRE, HOST>,
+                );
+            },
+        };
+        table[OpCode::EXTDELEGATECALL.as_usize()] = InspectorInstruction {
+            instruction: |interp, context| {
+                inspector_access_cost(
+                    interp,
+                    context,
+                    OpCode::EXTDELEGATECALL,
+                    contract::extdelegatecall::<WIRE, HOST>,
+                );
+            },
+        };
+        table[OpCode::EXTSTATICCALL.as_usize()] = InspectorInstruction {
+            instruction: |interp, context| {
+                inspector_access_cost(
+                    interp,
+                    context,
+                    OpCode::EXTSTATICCALL,
+                    contract::extstaticcall::<WIRE, HOST>,
+                );
             },
         };
 
-        Self {
-            instruction_table: Rc::new(table),
+        // Inspector jump wrapper
+        fn inspector_jump<CTX: Host + InspectorCtx>(
+            interpreter: &mut Interpreter<<CTX as InspectorCtx>::IT>,
+            context: &mut CTX,
+            conditional: bool,
+            prev: Instruction<<CTX as InspectorCtx>::IT, CTX>,
+        ) {
+            // The program counter is already past the opcode.
+            let from_pc = interpreter.bytecode.pc() - 1;
+            // Peek the target and condition, the instruction pops them.
+            let operands = if conditional {
+                let operands = interpreter.stack.popn::<2>();
+                if let Some([target, cond]) = operands {
+                    let _ = interpreter.stack.push(cond);
+                    let _ = interpreter.stack.push(target);
+                }
+                operands
+            } else {
+                interpreter
+                    .stack
+                    .top()
+                    .map(|target| [*target, U256::from(1)])
+            };
+            prev(interpreter, context);
+
+            let Some([target, cond]) = operands else {
+                return;
+            };
+            let to_pc = usize::try_from(target).unwrap_or(usize::MAX);
+            match interpreter.control.instruction_result() {
+                InstructionResult::Continue => {
+                    context.inspector_jump(
+                        interpreter,
+                        from_pc,
+                        to_pc,
+                        conditional,
+                        !cond.is_zero(),
+                    );
+                }
+                InstructionResult::InvalidJump => context.inspector_invalid_jump(from_pc, to_pc),
+                _ => {}
+            }
         }
-    }
+
+        /* Jump instructions */
+        table[OpCode::JUMP.as_usize()] = InspectorInstruction {
+            instruction: |interp, context| {
+                inspector_jump(interp, context, false, control::jump::<WIRE, HOST>);
+            },
+        };
+        table[OpCode::JUMPI.as_usize()] = InspectorInstruction {
+            instruction: |interp, context| {
+                inspector_jump(interp, context, true, control::jumpi::<WIRE, HOST>);
+            },
+        };
+
+        /* LOG and Selfdestruct instructions */
+        table[OpCode::LOG0.as_usize()] = InspectorInstruction {
+            instruction: |interp, context| {
+                inspector_log(interp, context, log::<0, HOST>);
+            },
+        };
+        table[OpCode::LOG1.as_usize()] = InspectorInstruction {
+            instruction: |interp, context| {
+                inspector_log(interp, context, log::<1, HOST>);
+            },
+        };
+        table[OpCode::LOG2.as_usize()] = InspectorInstruction {
+            instruction: |interp, context| {
+                inspector_log(interp, context, log::<2, HOST>);
+            },
+        };
+        table[OpCode::LOG3.as_usize()] = InspectorInstruction {
+            instruction: |interp, context| {
+                inspector_log(interp, context, log::<3, HOST>);
+            },
+        };
+        table[OpCode::LOG4.as_usize()] = InspectorInstruction {
+            instruction: |interp, context| {
+                inspector_log(interp, context, log::<4, HOST>);
+            },
+        };
+
+        table[OpCode::SELFDESTRUCT.as_usize()] = InspectorInstruction {
+            instruction: |interp, context| {
+                selfdestruct::<Self::WIRE, HOST>(interp, context);
+                if interp.control.instruction_result() != InstructionResult::SelfDestruct {
+                    return;
+                }
+                let (contract, target, value) = match context.journal_ext().last_journal().last() {
+                    Some(JournalEntry::AccountDestroyed {
+                        address,
+                        target,
+                        had_balance,
+                        ..
+                    }) => (*address, *target, *had_balance),
+                    Some(JournalEntry::BalanceTransfer {
+                        from, to, balance, ..
+                    }) => (*from, *to, *balance),
+                    _ => return,
+                };
+                context.inspector_selfdestruct(contract, target, value);
+
+                // Target is loaded by the instruction. Only the balance of the target is
+                // changed, so it was empty before if its balance equals the transferred value.
+                let Some(account) = context.journal_ext().evm_state().get(&target) else {
+                    return;
+                };
+                let balance = account.info.balance;
+                let created = contract != target
+                    && !value.is_zero()
+                    && balance == value
+                    && account.info.has_no_code_and_nonce();
+                context.inspector_selfdestruct_beneficiary(target, created, balance);
+            },
+        };
+
+        Self {
+            instruction_table: Rc::new(table),
+        }
+    }
 
     fn table(&mut self) -> &[impl CustomInstruction<Wire = Self::WIRE, Host = Self::Host>; 256] {
         self.instruction_table.as_ref()
@@ -679,6 +1401,44 @@ where
     >,
 }
 
+impl<CTX, ERROR, PRECOMPILE> InspectorEthFrame<CTX, ERROR, PRECOMPILE>
+where
+    CTX: JournalGetter + JournalExtGetter + Host + InspectorCtx<IT = EthInterpreter>,
+    ERROR: From<JournalDBError<CTX>>,
+    PRECOMPILE: PrecompileProvider<Context = CTX, Error = ERROR>,
+{
+    /// Resolves the dispatch of the call target and reports it to the inspector.
+    ///
+    /// The target is read from the journaled state if present, or from the database otherwise,
+    /// without loading it in the journal. Warming it is left to the call frame.
+    fn inspect_call_dispatch(
+        context: &mut CTX,
+        precompiles: &PRECOMPILE,
+        frame_input: &FrameInput,
+    ) -> Result<(), ERROR> {
+        let FrameInput::Call(inputs) = frame_input else {
+            return Ok(());
+        };
+
+        let address = inputs.bytecode_address;
+        let dispatch = if precompiles.contains(&address) {
+            CallDispatch::Precompile
+        } else {
+            match context.journal_ext().evm_state().get(&address) {
+                Some(account) => CallDispatch::of(&account.info),
+                None => context
+                    .journal()
+                    .db()
+                    .basic(address)?
+                    .map_or(CallDispatch::Empty, |info| CallDispatch::of(&info)),
+            }
+        };
+
+        context.inspector_call_dispatch(inputs, dispatch);
+        Ok(())
+    }
+}
+
 impl<CTX, ERROR, PRECOMPILE> Frame for InspectorEthFrame<CTX, ERROR, PRECOMPILE>
 where
     CTX: TransactionGetter
@@ -704,8 +1464,22 @@ where
         if let Some(output) = context.frame_start(&mut frame_input) {
             return Ok(FrameOrResultGen::Result(output));
         }
-        let mut ret = EthFrame::init_first(context, frame_input)
-            .map(|frame| frame.map_frame(|eth_frame| Self { eth_frame }));
+
+        // Same as `EthFrame::init_first`, with the dispatch reported before the frame is made.
+        let memory = Rc::new(RefCell::new(SharedMemory::new()));
+        let precompiles = PRECOMPILE::new(context);
+        let instructions = InspectorInstructionProvider::new(context);
+
+        // Load precompiles addresses as warm.
+        for address in precompiles.warm_addresses() {
+            context.journal().warm_account(address);
+        }
+
+        Self::inspect_call_dispatch(context, &precompiles, &frame_input)?;
+        memory.borrow_mut().new_context();
+        let mut ret =
+            EthFrame::init_with_context(0, frame_input, memory, precompiles, instructions, context)
+                .map(|frame| frame.map_frame(|eth_frame| Self { eth_frame }));
 
         match &mut ret {
             Ok(FrameOrResultGen::Result(res)) => {
@@ -735,6 +1509,7 @@ where
         if let Some(output) = context.frame_start(&mut frame_input) {
             return Ok(FrameOrResultGen::Result(output));
         }
+        Self::inspect_call_dispatch(context, &self.eth_frame.precompiles, &frame_input)?;
         let mut ret = self
             .eth_frame
             .init(context, frame_input)
@@ -750,7 +1525,19 @@ where
         &mut self,
         context: &mut CTX,
     ) -> Result<FrameOrResultGen<Self::FrameInit, Self::FrameResult>, Self::Error> {
-        self.eth_frame.run(context)
+        let frame = &mut self.eth_frame;
+        let next_action = frame.interpreter.run(frame.instructions.table(), context);
+
+        // Changes of a failed create are reverted when processing the action.
+        if let (FrameData::Create(create), InterpreterAction::Return { result }) =
+            (frame.data(), &next_action)
+        {
+            if !result.result.is_ok() && context.inspector_create_revert_snapshot_enabled() {
+                let changes = frame_changes(context.journal_ext(), frame.checkpoint);
+                context.inspector_create_revert_snapshot(create.created_address, result, &changes);
+            }
+        }
+        frame.process_next_action(context, next_action)
     }
 
     fn return_result(
@@ -763,6 +1550,51 @@ where
     }
 }
 
+/// Collects the accounts and storage slots changed since `checkpoint`.
+fn frame_changes(journal: &impl JournalExt, checkpoint: JournalCheckpoint) -> EvmState {
+    let state = journal.evm_state();
+    let mut changes = EvmState::default();
+    let mut record = |address: &Address, key: Option<&U256>| {
+        let Some(account) = state.get(address) else {
+            return;
+        };
+        let change = changes.entry(*address).or_insert_with(|| Account {
+            info: account.info.clone(),
+            storage: Default::default(),
+            status: account.status,
+        });
+        if let Some(key) = key {
+            if let Some(slot) = account.storage.get(key) {
+                change.storage.insert(*key, slot.clone());
+            }
+        }
+    };
+
+    for entry in journal.journal_since(checkpoint).iter().flatten() {
+        match entry {
+            JournalEntry::StorageChanged { address, key, .. } => record(address, Some(key)),
+            JournalEntry::AccountDestroyed {
+                address, target, ..
+            } => {
+                record(address, None);
+                record(target, None);
+            }
+            JournalEntry::BalanceTransfer { from, to, .. } => {
+                record(from, None);
+                record(to, None);
+            }
+            JournalEntry::AccountTouched { address }
+            | JournalEntry::NonceChange { address }
+            | JournalEntry::AccountCreated { address }
+            | JournalEntry::CodeChange { address } => record(address, None),
+            JournalEntry::AccountWarmed { .. }
+            | JournalEntry::StorageWarmed { .. }
+            | JournalEntry::TransientStorageChange { .. } => {}
+        }
+    }
+    changes
+}
+
 pub type InspCtxType<INSP, DB, CTX> = InspectorContext<INSP, DB, CTX>;
 
 pub type InspectorMainEvm<INSP, CTX, DB = EmptyDB> = Evm<
@@ -774,6 +1606,7 @@ pub type InspectorMainEvm<INSP, CTX, DB = EmptyDB> = Evm<
         EthValidation<InspCtxType<INSP, DB, CTX>, Error<DB>>,
         EthPreExecution<InspCtxType<INSP, DB, CTX>, Error<DB>>,
         InspectorEthExecution<InspCtxType<INSP, DB, CTX>, Error<DB>>,
+        InspectorEthPostExecution<InspCtxType<INSP, DB, CTX>, Error<DB>>,
     >,
 >;
 
@@ -783,14 +1616,83 @@ pub fn inspector_handler<CTX: Host, ERROR, PRECOMPILE>() -> InspectorHandler<CTX
     EthHandler::new(
         EthValidation::new(),
         EthPreExecution::new(),
-        EthExecution::<_, _, InspectorEthFrame<_, _, PRECOMPILE>>::new(),
-        EthPostExecution::new(),
+        InspectorExecution::new(EthExecution::new()),
+        InspectorEthPostExecution::new(),
     )
 }
 
+/// Executes `tx` on top of `context` with `inspector` attached and returns its result.
+///
+/// Shorthand for building an [`InspectorMainEvm`] with [`inspector_handler`] for one-off
+/// tracing. State changes are not committed to the database. Pass the inspector by mutable
+/// reference to read it back after execution.
+pub fn transact_with_inspector<INSP, DB>(
+    context: EthContext<DB>,
+    inspector: INSP,
+    tx: TxEnv,
+) -> Result<ExecutionResult<HaltReason>, Error<DB>>
+where
+    DB: Database,
+    INSP: GetInspector<EthContext<DB>, EthInterpreter>,
+{
+    let mut context = InspectorContext::new(context, inspector);
+    context.set_tx(tx);
+    let mut evm = InspectorMainEvm::new(context, inspector_handler());
+    evm.transact().map(|output| output.result)
+}
+
+/// Execution handler that reports the start of the transaction to the inspector.
+///
+/// [`Inspector::transaction_start`] is called before the first frame is made, with the initial
+/// gas returned by [`validate_initial_tx_gas`][ValidationHandler::validate_initial_tx_gas].
+///
+/// [ValidationHandler::validate_initial_tx_gas]: revm::handler_interface::ValidationHandler::validate_initial_tx_gas
+#[derive(Clone, Debug, Default)]
+pub struct InspectorExecution<EXEC> {
+    pub inner: EXEC,
+}
+
+impl<EXEC> InspectorExecution<EXEC> {
+    /// Wraps the execution handler.
+    pub fn new(inner: EXEC) -> Self {
+        Self { inner }
+    }
+}
+
+impl<EXEC> ExecutionHandler for InspectorExecution<EXEC>
+where
+    EXEC: ExecutionHandler,
+    EXEC::Context: TransactionGetter + InspectorCtx,
+{
+    type Context = EXEC::Context;
+    type Error = EXEC::Error;
+    type Frame = EXEC::Frame;
+    type ExecResult = EXEC::ExecResult;
+
+    fn init_first_frame(
+        &mut self,
+        context: &mut Self::Context,
+        gas_limit: u64,
+    ) -> Result<FrameOrFrameResult<Self::Frame>, Self::Error> {
+        // `gas_limit` is the transaction gas limit reduced by the initial gas, before the
+        // first frame is given unlimited gas when gas is not metered.
+        let tx_gas_limit = context.tx().common_fields().gas_limit();
+        context.inspector_transaction_start(tx_gas_limit.saturating_sub(gas_limit), tx_gas_limit);
+        self.inner.init_first_frame(context, gas_limit)
+    }
+
+    fn last_frame_result(
+        &self,
+        context: &mut Self::Context,
+        frame_result: <Self::Frame as Frame>::FrameResult,
+    ) -> Result<Self::ExecResult, Self::Error> {
+        self.inner.last_frame_result(context, frame_result)
+    }
+}
+
 /// Composed type for Inspector Execution handler.
 pub type InspectorEthExecution<CTX, ERROR, PRECOMPILE = EthPrecompileProvider<CTX, ERROR>> =
-    EthExecution<CTX, ERROR, InspectorEthFrame<CTX, ERROR, PRECOMPILE>>;
+    InspectorExecution<EthExecution<CTX, ERROR, InspectorEthFrame<CTX, ERROR, PRECOMPILE>>>;
 
 /// Composed type for Inspector Handler.
 pub type InspectorHandler<CTX, ERROR, PRECOMPILE> = EthHandler<
@@ -799,4 +1701,1567 @@ pub type InspectorHandler<CTX, ERROR, PRECOMPILE> = EthHandler<
     EthValidation<CTX, ERROR>,
     EthPreExecution<CTX, ERROR>,
     InspectorEthExecution<CTX, ERROR, PRECOMPILE>,
+    InspectorEthPostExecution<CTX, ERROR>,
 >;
+
+/// [`EthPostExecution`] that reports the fee accounting of the transaction to the inspector.
+///
+/// [`Inspector::transaction_end`] is called after the caller was reimbursed and the
+/// beneficiary rewarded, before the output is built.
+#[derive(Default)]
+pub struct InspectorEthPostExecution<CTX, ERROR, HALTREASON = HaltReason> {
+    pub inner: EthPostExecution<CTX, ERROR, HALTREASON>,
+    /// Value credited to the caller by [`PostExecutionHandler::reimburse_caller`].
+    caller_reimbursement: Cell<U256>,
+    /// Value credited to the beneficiary by [`PostExecutionHandler::reward_beneficiary`].
+    coinbase_reward: Cell<U256>,
+}
+
+impl<CTX, ERROR, HALTREASON> InspectorEthPostExecution<CTX, ERROR, HALTREASON> {
+    /// Create new instance of inspector post execution handler.
+    pub fn new() -> Self {
+        Self {
+            inner: EthPostExecution::new(),
+            caller_reimbursement: Cell::new(U256::ZERO),
+            coinbase_reward: Cell::new(U256::ZERO),
+        }
+    }
+}
+
+impl<CTX, ERROR, HALTREASON> PostExecutionHandler
+    for InspectorEthPostExecution<CTX, ERROR, HALTREASON>
+where
+    CTX: EthPostExecutionContext<ERROR> + InspectorCtx,
+    ERROR: EthPostExecutionError<CTX>,
+    HALTREASON: HaltReasonTrait,
+{
+    type Context = CTX;
+    type Error = ERROR;
+    type ExecResult = FrameResult;
+    type Output = ResultAndState<HALTREASON>;
+
+    fn refund(
+        &self,
+        context: &mut Self::Context,
+        exec_result: &mut Self::ExecResult,
+        eip7702_refund: i64,
+    ) {
+        self.inner.refund(context, exec_result, eip7702_refund)
+    }
+
+    fn reimburse_caller(
+        &self,
+        context: &mut Self::Context,
+        exec_result: &mut Self::ExecResult,
+    ) -> Result<(), Self::Error> {
+        let reimbursed = self.inner.reimburse_caller_amount(context, exec_result)?;
+        self.caller_reimbursement.set(reimbursed);
+        Ok(())
+    }
+
+    fn reward_beneficiary(
+        &self,
+        context: &mut Self::Context,
+        exec_result: &mut Self::ExecResult,
+    ) -> Result<(), Self::Error> {
+        let reward = self.inner.reward_beneficiary_amount(context, exec_result)?;
+        self.coinbase_reward.set(reward);
+        Ok(())
+    }
+
+    fn output(
+        &self,
+        context: &mut Self::Context,
+        result: Self::ExecResult,
+    ) -> Result<Self::Output, Self::Error> {
+        let summary = transaction_summary(
+            context,
+            result.gas(),
+            self.caller_reimbursement.take(),
+            self.coinbase_reward.take(),
+        );
+        context.inspector_transaction_end(&summary);
+        self.inner.output(context, result)
+    }
+
+    fn end(
+        &self,
+        context: &mut Self::Context,
+        end_output: Result<Self::Output, Self::Error>,
+    ) -> Result<Self::Output, Self::Error> {
+        self.inner.end(context, end_output)
+    }
+
+    fn clear(&self, context: &mut Self::Context) {
+        self.inner.clear(context)
+    }
+}
+
+/// Builds the fee accounting of a transaction from its final gas and the values credited by
+/// the post execution handler.
+fn transaction_summary<CTX>(
+    context: &CTX,
+    gas: &Gas,
+    caller_reimbursement: U256,
+    coinbase_reward: U256,
+) -> TransactionSummary
+where
+    CTX: TransactionGetter + BlockGetter,
+{
+    let basefee = context.block().basefee() as u128;
+    let effective_gas_price = context.tx().effective_gas_price(basefee);
+    let gas_limit = context.tx().common_fields().gas_limit();
+    let gas_refunded = gas.refunded() as u64;
+
+    TransactionSummary {
+        gas_limit,
+        gas_used: gas.spent() - gas_refunded,
+        gas_refunded,
+        effective_gas_price,
+        gas_cost: U256::from(effective_gas_price) * U256::from(gas_limit),
+        caller_reimbursement,
+        coinbase_reward,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::{call, inspector_evm, run_with_inspector, CALLER, GAS_LIMIT};
+    use database::{BenchmarkDB, InMemoryDB};
+    use revm::{
+        bytecode::{
+            eof::{EofBody, TypesSection},
+            opcode, Bytecode, Eof,
+        },
+        interpreter::{gas::CALL_STIPEND, CallScheme, CreateScheme},
+        primitives::TxKind,
+        specification::hardfork::SpecId,
+        state::AccountInfo,
+    };
+    use std::sync::Arc;
+
+    #[derive(Default)]
+    struct DispatchRecorder {
+        dispatches: Vec<(Address, CallDispatch)>,
+    }
+
+    impl<CTX, INTR: InterpreterTypes> Inspector<CTX, INTR> for DispatchRecorder {
+        fn call_dispatch(
+            &mut self,
+            _context: &mut CTX,
+            inputs: &CallInputs,
+            dispatch: CallDispatch,
+        ) {
+            self.dispatches.push((inputs.bytecode_address, dispatch));
+        }
+    }
+
+    #[test]
+    fn call_dispatch_precompile_and_contract() {
+        // Calls the ecrecover precompile with empty input.
+        let bytecode = Bytecode::new_raw(Bytes::from([&call(0x01)[..], &[opcode::STOP]].concat()));
+        let context = Context::builder()
+            .with_db(BenchmarkDB::new_bytecode(bytecode))
+            .modify_tx_chained(|tx| {
+                tx.caller = Address::with_last_byte(1);
+                tx.transact_to = TxKind::Call(Address::ZERO);
+            });
+        let mut evm = InspectorMainEvm::new(
+            InspectorContext::new(context, DispatchRecorder::default()),
+            inspector_handler(),
+        );
+
+        let result = evm.transact().unwrap().result;
+        assert!(result.is_success());
+        assert_eq!(
+            evm.context.inspector.dispatches,
+            vec![
+                (Address::ZERO, CallDispatch::Contract),
+                (Address::with_last_byte(1), CallDispatch::Precompile),
+            ]
+        );
+    }
+
+    #[test]
+    fn call_dispatch_reads_accounts_not_in_journal() {
+        let contract = Address::with_last_byte(0x11);
+        let empty = Address::with_last_byte(0xee);
+        let eoa = Address::with_last_byte(0xe0);
+        let code = [&call(0xee)[..], &[opcode::POP], &call(0xe0), &[opcode::POP]].concat();
+
+        let mut evm = inspector_evm([(contract, code)], DispatchRecorder::default());
+        evm.context
+            .db()
+            .insert_account_info(eoa, AccountInfo::from_balance(U256::from(1)));
+
+        let result = evm.transact().unwrap().result;
+        assert!(result.is_success());
+        assert_eq!(
+            evm.context.inspector.dispatches,
+            vec![
+                (contract, CallDispatch::Contract),
+                (empty, CallDispatch::Empty),
+                (eoa, CallDispatch::Eoa),
+            ]
+        );
+    }
+
+    #[derive(Default)]
+    struct AccessRecorder {
+        accesses: Vec<(u8, bool)>,
+    }
+
+    impl<CTX, INTR: InterpreterTypes> Inspector<CTX, INTR> for AccessRecorder {
+        fn access_cost(
+            &mut self,
+            _interp: &mut Interpreter<INTR>,
+            _context: &mut CTX,
+            opcode: u8,
+            is_cold: bool,
+        ) {
+            self.accesses.push((opcode, is_cold));
+        }
+    }
+
+    #[test]
+    fn access_cost_cold_then_warm_sload() {
+        let bytecode = Bytecode::new_raw(Bytes::from(vec![
+            opcode::PUSH1,
+            0x00,
+            opcode::SLOAD,
+            opcode::PUSH1,
+            0x00,
+            opcode::SLOAD,
+            opcode::STOP,
+        ]));
+        let accesses = |force_cold_access| {
+            let context = Context::builder()
+                .with_db(BenchmarkDB::new_bytecode(bytecode.clone()))
+                .modify_cfg_chained(|cfg| cfg.force_cold_access = force_cold_access)
+                .modify_tx_chained(|tx| {
+                    tx.caller = Address::with_last_byte(1);
+                    tx.transact_to = TxKind::Call(Address::ZERO);
+                });
+            let mut evm = InspectorMainEvm::new(
+                InspectorContext::new(context, AccessRecorder::default()),
+                inspector_handler(),
+            );
+
+            let result = evm.transact().unwrap().result;
+            assert!(result.is_success());
+            evm.context.inspector.accesses
+        };
+
+        assert_eq!(
+            accesses(false),
+            vec![(opcode::SLOAD, true), (opcode::SLOAD, false)]
+        );
+        // The second load is priced cold as well.
+        assert_eq!(
+            accesses(true),
+            vec![(opcode::SLOAD, true), (opcode::SLOAD, true)]
+        );
+    }
+
+    #[derive(Default)]
+    struct CallRecorder {
+        calls: Vec<(u64, u64)>,
+    }
+
+    impl<CTX, INTR: InterpreterTypes> Inspector<CTX, INTR> for CallRecorder {
+        fn call(&mut self, _context: &mut CTX, inputs: &mut CallInputs) -> Option<CallOutcome> {
+            self.calls.push((inputs.gas_limit, inputs.stipend));
+            None
+        }
+    }
+
+    #[test]
+    fn call_stipend_on_value_transfer() {
+        // Calls itself with one wei and zero gas, so the callee runs on the stipend alone.
+        let bytecode = Bytecode::new_raw(Bytes::from(vec![
+            opcode::PUSH1,
+            0x00,
+            opcode::PUSH1,
+            0x00,
+            opcode::PUSH1,
+            0x00,
+            opcode::PUSH1,
+            0x00,
+            opcode::PUSH1,
+            0x01,
+            opcode::PUSH1,
+            0x00,
+            opcode::PUSH1,
+            0x00,
+            opcode::CALL,
+            opcode::STOP,
+        ]));
+        let context = Context::builder()
+            .with_db(BenchmarkDB::new_bytecode(bytecode))
+            .modify_tx_chained(|tx| {
+                tx.caller = Address::with_last_byte(1);
+                tx.transact_to = TxKind::Call(Address::ZERO);
+            });
+        let mut evm = InspectorMainEvm::new(
+            InspectorContext::new(context, CallRecorder::default()),
+            inspector_handler(),
+        );
+
+        let result = evm.transact().unwrap().result;
+        assert!(result.is_success());
+
+        let calls = &evm.context.inspector.calls;
+        assert_eq!(calls.len(), 2);
+        // Top level call has no stipend.
+        assert_eq!(calls[0].1, 0);
+        // Nested call gets exactly the stipend.
+        assert_eq!(calls[1], (CALL_STIPEND, CALL_STIPEND));
+    }
+
+    #[derive(Default)]
+    struct HaltRecorder {
+        step_end: Vec<InstructionResult>,
+        halts: Vec<InstructionResult>,
+    }
+
+    impl<CTX, INTR: InterpreterTypes> Inspector<CTX, INTR> for HaltRecorder {
+        fn step_end(&mut self, interp: &mut Interpreter<INTR>, _context: &mut CTX) {
+            self.step_end.push(interp.control.instruction_result());
+        }
+
+        fn halt(
+            &mut self,
+            _interp: &mut Interpreter<INTR>,
+            _context: &mut CTX,
+            result: InstructionResult,
+        ) {
+            self.halts.push(result);
+        }
+    }
+
+    #[test]
+    fn halt_on_stack_underflow() {
+        let bytecode = Bytecode::new_raw(Bytes::from(vec![opcode::ADD]));
+        let context = Context::builder()
+            .with_db(BenchmarkDB::new_bytecode(bytecode))
+            .modify_tx_chained(|tx| {
+                tx.caller = Address::with_last_byte(1);
+                tx.transact_to = TxKind::Call(Address::ZERO);
+            });
+        let mut evm = InspectorMainEvm::new(
+            InspectorContext::new(context, HaltRecorder::default()),
+            inspector_handler(),
+        );
+
+        let result = evm.transact().unwrap().result;
+        assert!(matches!(
+            result,
+            ExecutionResult::Halt {
+                reason: HaltReason::StackUnderflow,
+                ..
+            }
+        ));
+        let inspector = &evm.context.inspector;
+        assert_eq!(inspector.step_end, vec![InstructionResult::StackUnderflow]);
+        assert_eq!(inspector.halts, vec![InstructionResult::StackUnderflow]);
+    }
+
+    #[derive(Default)]
+    struct OriginRecorder {
+        origins: Vec<(Address, Address, u128)>,
+    }
+
+    impl<CTX: Host, INTR: InterpreterTypes> Inspector<CTX, INTR> for OriginRecorder {
+        fn call(&mut self, context: &mut CTX, inputs: &mut CallInputs) -> Option<CallOutcome> {
+            self.origins.push((
+                inputs.caller,
+                context.tx_origin(),
+                context.effective_gas_price(),
+            ));
+            None
+        }
+    }
+
+    #[test]
+    fn tx_origin_in_nested_call() {
+        // Calls the ecrecover precompile with empty input.
+        let bytecode = Bytecode::new_raw(Bytes::from([&call(0x01)[..], &[opcode::STOP]].concat()));
+        let origin = Address::with_last_byte(1);
+        let context = Context::builder()
+            .with_db(BenchmarkDB::new_bytecode(bytecode))
+            .modify_tx_chained(|tx| {
+                tx.caller = origin;
+                tx.transact_to = TxKind::Call(Address::ZERO);
+                tx.gas_limit = 100_000;
+                tx.gas_price = U256::from(7);
+            });
+        let mut evm = InspectorMainEvm::new(
+            InspectorContext::new(context, OriginRecorder::default()),
+            inspector_handler(),
+        );
+
+        let result = evm.transact().unwrap().result;
+        assert!(result.is_success());
+        assert_eq!(
+            evm.context.inspector.origins,
+            vec![(origin, origin, 7), (Address::ZERO, origin, 7)]
+        );
+    }
+
+    #[derive(Default)]
+    struct BeneficiaryRecorder {
+        beneficiaries: Vec<(Address, bool, U256)>,
+    }
+
+    impl<CTX, INTR: InterpreterTypes> Inspector<CTX, INTR> for BeneficiaryRecorder {
+        fn selfdestruct_beneficiary(&mut self, target: Address, created: bool, balance: U256) {
+            self.beneficiaries.push((target, created, balance));
+        }
+    }
+
+    fn selfdestruct_to(target: Address) -> Vec<(Address, bool, U256)> {
+        let bytecode = Bytecode::new_raw(Bytes::from(vec![
+            opcode::PUSH1,
+            target.0[19],
+            opcode::SELFDESTRUCT,
+        ]));
+        let context = Context::builder()
+            .with_db(BenchmarkDB::new_bytecode(bytecode))
+            .modify_tx_chained(|tx| {
+                tx.caller = Address::with_last_byte(1);
+                tx.transact_to = TxKind::Call(Address::ZERO);
+                tx.gas_limit = 100_000;
+            });
+        let mut evm = InspectorMainEvm::new(
+            InspectorContext::new(context, BeneficiaryRecorder::default()),
+            inspector_handler(),
+        );
+
+        let result = evm.transact().unwrap().result;
+        assert!(result.is_success());
+        core::mem::take(&mut evm.context.inspector.beneficiaries)
+    }
+
+    #[test]
+    fn selfdestruct_beneficiary_created() {
+        let fresh = Address::with_last_byte(0xbe);
+        assert_eq!(
+            selfdestruct_to(fresh),
+            vec![(fresh, true, U256::from(10_000_000))]
+        );
+
+        // Caller of the transaction, it keeps its balance as gas price is zero.
+        let existing = Address::with_last_byte(1);
+        assert_eq!(
+            selfdestruct_to(existing),
+            vec![(existing, false, U256::from(20_000_000))]
+        );
+    }
+
+    #[test]
+    fn transact_with_inspector_matches_manual_setup() {
+        // Calls the identity precompile.
+        let bytecode = Bytecode::new_raw(Bytes::from([&call(0x04)[..], &[opcode::STOP]].concat()));
+        let tx = TxEnv {
+            caller: Address::with_last_byte(1),
+            transact_to: TxKind::Call(Address::ZERO),
+            gas_limit: 100_000,
+            ..Default::default()
+        };
+
+        let context = Context::builder()
+            .with_db(BenchmarkDB::new_bytecode(bytecode.clone()))
+            .with_tx(tx.clone());
+        let mut evm = InspectorMainEvm::new(
+            InspectorContext::new(context, DispatchRecorder::default()),
+            inspector_handler(),
+        );
+        let expected = evm.transact().unwrap().result;
+
+        let mut inspector = DispatchRecorder::default();
+        let result = transact_with_inspector(
+            Context::builder().with_db(BenchmarkDB::new_bytecode(bytecode)),
+            &mut inspector,
+            tx,
+        )
+        .unwrap();
+
+        assert!(result.is_success());
+        assert_eq!(result, expected);
+        assert_eq!(inspector.dispatches, evm.context.inspector.dispatches);
+    }
+
+    #[derive(Default)]
+    struct MemoryRecorder {
+        expansions: Vec<(usize, usize, u64)>,
+    }
+
+    impl<CTX, INTR: InterpreterTypes> Inspector<CTX, INTR> for MemoryRecorder {
+        fn memory_expansion(&mut self, old_size: usize, new_size: usize, gas_cost: u64) {
+            self.expansions.push((old_size, new_size, gas_cost));
+        }
+    }
+
+    #[test]
+    fn memory_expansion_on_high_offset_mstore() {
+        // Stores a word at offset 0x1000, then reads it back without expanding further.
+        let bytecode = Bytecode::new_raw(Bytes::from(vec![
+            opcode::PUSH1,
+            0x2a,
+            opcode::PUSH2,
+            0x10,
+            0x00,
+            opcode::MSTORE,
+            opcode::PUSH2,
+            0x10,
+            0x00,
+            opcode::MLOAD,
+            opcode::STOP,
+        ]));
+        let context = Context::builder()
+            .with_db(BenchmarkDB::new_bytecode(bytecode))
+            .modify_tx_chained(|tx| {
+                tx.caller = Address::with_last_byte(1);
+                tx.transact_to = TxKind::Call(Address::ZERO);
+            });
+        let mut evm = InspectorMainEvm::new(
+            InspectorContext::new(context, MemoryRecorder::default()),
+            inspector_handler(),
+        );
+
+        let result = evm.transact().unwrap().result;
+        assert!(result.is_success());
+        // 129 words: 3 * 129 + 129^2 / 512.
+        assert_eq!(evm.context.inspector.expansions, vec![(0, 4128, 419)]);
+    }
+
+    #[derive(Default)]
+    struct LogInjector;
+
+    impl<CTX: Host, INTR: InterpreterTypes> Inspector<CTX, INTR> for LogInjector {
+        fn step(&mut self, interp: &mut Interpreter<INTR>, context: &mut CTX) {
+            let opcode = interp.bytecode.opcode();
+            if opcode == opcode::REVERT || opcode == opcode::STOP {
+                context.push_log(Log::new_unchecked(
+                    interp.input.target_address(),
+                    vec![],
+                    Bytes::from(vec![opcode]),
+                ));
+            }
+        }
+    }
+
+    #[test]
+    fn push_log_reverted_with_frame() {
+        let entry = Address::with_last_byte(0x11);
+        let reverting = Address::with_last_byte(0xaa);
+
+        // Emits a LOG0, calls `reverting` and stops.
+        let entry_code = [
+            &[opcode::PUSH1, 0x00, opcode::PUSH1, 0x00, opcode::LOG0][..],
+            &call(0xaa),
+            &[opcode::STOP],
+        ]
+        .concat();
+        let reverting_code = vec![opcode::PUSH1, 0x00, opcode::PUSH1, 0x00, opcode::REVERT];
+
+        let (output, _) = run_with_inspector(
+            [(entry, entry_code), (reverting, reverting_code)],
+            LogInjector,
+        );
+        assert!(output.result.is_success());
+        // The log injected in the reverted frame is discarded.
+        assert_eq!(
+            output.result.logs(),
+            [
+                Log::new_unchecked(entry, vec![], Bytes::new()),
+                Log::new_unchecked(entry, vec![], Bytes::from(vec![opcode::STOP])),
+            ]
+        );
+    }
+
+    #[derive(Default)]
+    struct CreateRecorder {
+        schemes: Vec<CreateScheme>,
+    }
+
+    impl<CTX, INTR: InterpreterTypes> Inspector<CTX, INTR> for CreateRecorder {
+        fn create(
+            &mut self,
+            _context: &mut CTX,
+            inputs: &mut CreateInputs,
+        ) -> Option<CreateOutcome> {
+            self.schemes.push(inputs.scheme);
+            None
+        }
+    }
+
+    #[test]
+    fn create_scheme_reports_salt() {
+        // Runs CREATE and CREATE2 with salt `0x2a`, both with empty init code.
+        let bytecode = Bytecode::new_raw(Bytes::from(vec![
+            opcode::PUSH1,
+            0x00,
+            opcode::PUSH1,
+            0x00,
+            opcode::PUSH1,
+            0x00,
+            opcode::CREATE,
+            opcode::POP,
+            opcode::PUSH1,
+            0x2a,
+            opcode::PUSH1,
+            0x00,
+            opcode::PUSH1,
+            0x00,
+            opcode::PUSH1,
+            0x00,
+            opcode::CREATE2,
+            opcode::POP,
+            opcode::STOP,
+        ]));
+        let context = Context::builder()
+            .with_db(BenchmarkDB::new_bytecode(bytecode))
+            .modify_tx_chained(|tx| {
+                tx.caller = Address::with_last_byte(1);
+                tx.transact_to = TxKind::Call(Address::ZERO);
+                tx.gas_limit = 200_000;
+            });
+        let mut evm = InspectorMainEvm::new(
+            InspectorContext::new(context, CreateRecorder::default()),
+            inspector_handler(),
+        );
+
+        let result = evm.transact().unwrap().result;
+        assert!(result.is_success());
+        let schemes = &evm.context.inspector.schemes;
+        assert_eq!(
+            schemes,
+            &[
+                CreateScheme::Create,
+                CreateScheme::Create2 {
+                    salt: U256::from(0x2a)
+                }
+            ]
+        );
+        assert_eq!(schemes[0].salt(), None);
+        assert_eq!(schemes[1].salt(), Some(U256::from(0x2a)));
+    }
+
+    #[derive(Default)]
+    struct ReturnDataRecorder {
+        snapshots: Vec<(Address, Vec<u8>)>,
+    }
+
+    impl<CTX, INTR: InterpreterTypes> Inspector<CTX, INTR> for ReturnDataRecorder {
+        fn step(&mut self, interp: &mut Interpreter<INTR>, _context: &mut CTX) {
+            if interp.bytecode.pc() == 0 || interp.bytecode.opcode() == opcode::STOP {
+                self.snapshots.push((
+                    interp.input.target_address(),
+                    interp.return_data_slice().to_vec(),
+                ));
+            }
+        }
+    }
+
+    #[test]
+    fn return_data_slice_after_subcall() {
+        let entry = Address::with_last_byte(0x11);
+        let callee = Address::with_last_byte(0xaa);
+
+        // Calls `callee` twice and stops.
+        let entry_code = [
+            &call(0xaa)[..],
+            &[opcode::POP],
+            &call(0xaa),
+            &[opcode::POP, opcode::STOP],
+        ]
+        .concat();
+        // Returns 64 bytes, `0x11` and `0x22` as words.
+        let callee_code = vec![
+            opcode::PUSH1,
+            0x11,
+            opcode::PUSH1,
+            0x00,
+            opcode::MSTORE,
+            opcode::PUSH1,
+            0x22,
+            opcode::PUSH1,
+            0x20,
+            opcode::MSTORE,
+            opcode::PUSH1,
+            0x40,
+            opcode::PUSH1,
+            0x00,
+            opcode::RETURN,
+        ];
+
+        let (output, inspector) = run_with_inspector(
+            [(entry, entry_code), (callee, callee_code)],
+            ReturnDataRecorder::default(),
+        );
+        assert!(output.result.is_success());
+
+        let mut returned = [0u8; 64];
+        returned[31] = 0x11;
+        returned[63] = 0x22;
+        // Every new frame starts with empty return data.
+        assert_eq!(
+            inspector.snapshots,
+            vec![
+                (entry, vec![]),
+                (callee, vec![]),
+                (callee, vec![]),
+                (entry, returned.to_vec()),
+            ]
+        );
+    }
+
+    #[derive(Default)]
+    struct CallGasRecorder {
+        calls: Vec<(Address, u64, u64)>,
+    }
+
+    impl<CTX, INTR: InterpreterTypes> Inspector<CTX, INTR> for CallGasRecorder {
+        fn call_end(&mut self, _context: &mut CTX, inputs: &CallInputs, outcome: &mut CallOutcome) {
+            self.calls.push((
+                inputs.target_address,
+                outcome.gas_forwarded(),
+                outcome.gas_consumed(),
+            ));
+        }
+    }
+
+    #[test]
+    fn call_outcome_gas_forwarded_and_consumed() {
+        let entry = Address::with_last_byte(0x11);
+        let callee = Address::with_last_byte(0xaa);
+
+        // Calls `callee` with all available gas and stops.
+        let entry_code = [&call(0xaa)[..], &[opcode::STOP]].concat();
+        // Spends 5 gas.
+        let callee_code = vec![opcode::PUSH1, 0x00, opcode::POP, opcode::STOP];
+
+        let (output, inspector) = run_with_inspector(
+            [(entry, entry_code), (callee, callee_code)],
+            CallGasRecorder::default(),
+        );
+        assert!(output.result.is_success());
+
+        let calls = &inspector.calls;
+        assert_eq!(calls.len(), 2);
+        let (address, forwarded, consumed) = calls[0];
+        assert_eq!(address, callee);
+        assert_eq!(consumed, 5);
+        assert!(forwarded > 900_000);
+        // The transaction frame gets the gas limit minus the intrinsic gas.
+        assert_eq!(calls[1].0, entry);
+        assert_eq!(calls[1].1, GAS_LIMIT - 21_000);
+    }
+
+    #[derive(Default)]
+    struct CallRefundRecorder {
+        calls: Vec<(Address, i64)>,
+    }
+
+    impl<CTX, INTR: InterpreterTypes> Inspector<CTX, INTR> for CallRefundRecorder {
+        fn call_end(&mut self, _context: &mut CTX, inputs: &CallInputs, outcome: &mut CallOutcome) {
+            self.calls
+                .push((inputs.target_address, outcome.gas_refund()));
+        }
+    }
+
+    #[test]
+    fn call_outcome_gas_refund() {
+        let entry = Address::with_last_byte(0x11);
+        let callee = Address::with_last_byte(0xaa);
+
+        // Calls `callee` with all available gas and stops.
+        let entry_code = [&call(0xaa)[..], &[opcode::STOP]].concat();
+        // Clears storage slot zero.
+        let callee_code = vec![
+            opcode::PUSH1,
+            0x00,
+            opcode::PUSH1,
+            0x00,
+            opcode::SSTORE,
+            opcode::STOP,
+        ];
+
+        let mut evm = inspector_evm(
+            [(entry, entry_code), (callee, callee_code)],
+            CallRefundRecorder::default(),
+        );
+        evm.context
+            .db()
+            .insert_account_storage(callee, U256::ZERO, U256::from(1))
+            .unwrap();
+
+        let result = evm.transact().unwrap().result;
+        assert!(result.is_success());
+
+        // The clear refund is reported by the callee and carried into the entry frame.
+        assert_eq!(
+            evm.context.inspector.calls,
+            vec![(callee, 4_800), (entry, 4_800)]
+        );
+    }
+
+    #[derive(Default)]
+    struct RootFrameRecorder {
+        frames: Vec<(Address, bool)>,
+    }
+
+    impl<CTX, INTR: InterpreterTypes> Inspector<CTX, INTR> for RootFrameRecorder {
+        fn frame_start(&mut self, _context: &mut CTX, inputs: &FrameInput, is_root: bool) {
+            let FrameInput::Call(inputs) = inputs else {
+                panic!("Only calls expected");
+            };
+            self.frames.push((inputs.target_address, is_root));
+        }
+    }
+
+    #[test]
+    fn frame_start_marks_root_frame() {
+        let entry = Address::with_last_byte(0x11);
+        let callee = Address::with_last_byte(0xaa);
+
+        // Calls `callee` with all available gas and stops.
+        let entry_code = [&call(0xaa)[..], &[opcode::STOP]].concat();
+        let mut evm = inspector_evm(
+            [(entry, entry_code), (callee, vec![opcode::STOP])],
+            RootFrameRecorder::default(),
+        );
+
+        for _ in 0..2 {
+            assert!(evm.transact().unwrap().result.is_success());
+        }
+        assert_eq!(
+            evm.context.inspector.frames,
+            vec![
+                (entry, true),
+                (callee, false),
+                (entry, true),
+                (callee, false)
+            ]
+        );
+    }
+
+    #[derive(Default)]
+    struct StaticViolationRecorder {
+        violations: Vec<(u8, Address)>,
+        call_results: Vec<(Address, InstructionResult)>,
+    }
+
+    impl<CTX, INTR: InterpreterTypes> Inspector<CTX, INTR> for StaticViolationRecorder {
+        fn static_violation(
+            &mut self,
+            _interp: &mut Interpreter<INTR>,
+            _context: &mut CTX,
+            opcode: u8,
+            target: Address,
+        ) {
+            self.violations.push((opcode, target));
+        }
+
+        fn call_end(&mut self, _context: &mut CTX, inputs: &CallInputs, outcome: &mut CallOutcome) {
+            self.call_results
+                .push((inputs.target_address, outcome.result.result));
+        }
+    }
+
+    #[test]
+    fn static_violation_on_sstore_in_staticcall() {
+        let entry = Address::with_last_byte(0x11);
+        let callee = Address::with_last_byte(0xbb);
+
+        // Calls `callee` in static context with all available gas and stops.
+        let entry_code = vec![
+            opcode::PUSH1,
+            0x00,
+            opcode::PUSH1,
+            0x00,
+            opcode::PUSH1,
+            0x00,
+            opcode::PUSH1,
+            0x00,
+            opcode::PUSH1,
+            0xbb,
+            opcode::GAS,
+            opcode::STATICCALL,
+            opcode::STOP,
+        ];
+        // Stores 1 in slot zero.
+        let callee_code = vec![
+            opcode::PUSH1,
+            0x01,
+            opcode::PUSH1,
+            0x00,
+            opcode::SSTORE,
+            opcode::STOP,
+        ];
+
+        let (output, inspector) = run_with_inspector(
+            [(entry, entry_code), (callee, callee_code)],
+            StaticViolationRecorder::default(),
+        );
+        assert!(output.result.is_success());
+        assert_eq!(inspector.violations, vec![(opcode::SSTORE, callee)]);
+        assert_eq!(
+            inspector.call_results,
+            vec![
+                (callee, InstructionResult::StateChangeDuringStaticCall),
+                (entry, InstructionResult::Stop)
+            ]
+        );
+    }
+
+    #[derive(Default)]
+    struct CallStackRecorder {
+        stacks: Vec<Vec<FrameInfo>>,
+    }
+
+    impl<CTX: Host, INTR: InterpreterTypes> Inspector<CTX, INTR> for CallStackRecorder {
+        fn step(&mut self, interp: &mut Interpreter<INTR>, context: &mut CTX) {
+            if interp.bytecode.opcode() == opcode::STOP {
+                self.stacks.push(context.call_stack().to_vec());
+            }
+        }
+    }
+
+    #[test]
+    fn call_stack_in_nested_call() {
+        let entry = Address::with_last_byte(0x11);
+        let callee = Address::with_last_byte(0xaa);
+
+        // Calls `callee` with all available gas and a value of 5, then stops.
+        let mut entry_code = call(0xaa).to_vec();
+        entry_code[9] = 0x05;
+        entry_code.push(opcode::STOP);
+
+        let mut evm = inspector_evm(
+            [(entry, entry_code), (callee, vec![opcode::STOP])],
+            CallStackRecorder::default(),
+        );
+        evm.context.db().load_account(entry).unwrap().info.balance = U256::from(10);
+
+        assert!(evm.transact().unwrap().result.is_success());
+        let root = FrameInfo {
+            address: entry,
+            caller: CALLER,
+            scheme: Some(CallScheme::Call),
+            value: U256::ZERO,
+        };
+        let nested = FrameInfo {
+            address: callee,
+            caller: entry,
+            scheme: Some(CallScheme::Call),
+            value: U256::from(5),
+        };
+        assert_eq!(
+            evm.context.inspector.stacks,
+            vec![vec![root.clone(), nested], vec![root]]
+        );
+        assert!(evm.context.inner.call_stack.is_empty());
+    }
+
+    #[derive(Default)]
+    struct TransactionStartRecorder {
+        starts: Vec<(u64, u64)>,
+    }
+
+    impl<CTX, INTR: InterpreterTypes> Inspector<CTX, INTR> for TransactionStartRecorder {
+        fn transaction_start(&mut self, _context: &mut CTX, intrinsic_gas: u64, gas_limit: u64) {
+            self.starts.push((intrinsic_gas, gas_limit));
+        }
+    }
+
+    #[test]
+    fn transaction_start_reports_intrinsic_gas() {
+        let transact = |disable_gas_metering: bool| {
+            let context = Context::builder()
+                .with_db(BenchmarkDB::new_bytecode(Bytecode::new_raw(Bytes::from(
+                    vec![opcode::STOP],
+                ))))
+                .modify_tx_chained(|tx| {
+                    tx.caller = Address::with_last_byte(1);
+                    tx.transact_to = TxKind::Call(Address::ZERO);
+                    tx.data = Bytes::from_static(&[0, 0, 1, 2, 3]);
+                    tx.gas_limit = 100_000;
+                })
+                .modify_cfg_chained(|cfg| cfg.disable_gas_metering = disable_gas_metering);
+            let mut evm = InspectorMainEvm::new(
+                InspectorContext::new(context, TransactionStartRecorder::default()),
+                inspector_handler(),
+            );
+
+            assert!(evm.transact().unwrap().result.is_success());
+            evm.context.inspector.starts
+        };
+
+        let expected =
+            gas::validate_initial_tx_gas(SpecId::PRAGUE, &[0, 0, 1, 2, 3], false, None, 0);
+        assert_eq!(expected, 21_000 + 2 * 4 + 3 * 16);
+        assert_eq!(transact(false), vec![(expected, 100_000)]);
+        // No intrinsic gas is charged, even though the first frame gets unlimited gas.
+        assert_eq!(transact(true), vec![(0, 100_000)]);
+    }
+
+    #[derive(Default)]
+    struct CallSkipper {
+        skipped: Address,
+        stepped: Vec<Address>,
+    }
+
+    impl<CTX> Inspector<CTX, EthInterpreter> for CallSkipper {
+        fn step(&mut self, interp: &mut Interpreter<EthInterpreter>, _context: &mut CTX) {
+            self.stepped.push(interp.input.target_address());
+        }
+
+        fn call(&mut self, _context: &mut CTX, inputs: &mut CallInputs) -> Option<CallOutcome> {
+            (inputs.target_address == self.skipped)
+                .then(|| skip_call(inputs, true, 1_000, Bytes::from_static(&[1, 2, 3])))
+        }
+    }
+
+    #[test]
+    fn skipped_call_does_not_run_callee() {
+        let entry = Address::with_last_byte(0x11);
+        let callee = Address::with_last_byte(0xaa);
+
+        // Calls the callee and stores the call result and return data size.
+        let entry_code = [
+            &call(0xaa)[..],
+            &[
+                opcode::PUSH1,
+                0x00,
+                opcode::SSTORE,
+                opcode::RETURNDATASIZE,
+                opcode::PUSH1,
+                0x01,
+                opcode::SSTORE,
+                opcode::STOP,
+            ],
+        ]
+        .concat();
+        // Stores one in slot zero.
+        let callee_code = vec![
+            opcode::PUSH1,
+            0x01,
+            opcode::PUSH1,
+            0x00,
+            opcode::SSTORE,
+            opcode::STOP,
+        ];
+
+        let inspector = CallSkipper {
+            skipped: callee,
+            ..Default::default()
+        };
+        let (ResultAndState { result, state }, inspector) =
+            run_with_inspector([(entry, entry_code), (callee, callee_code)], inspector);
+        assert!(result.is_success());
+        let storage = &state[&entry].storage;
+        assert_eq!(storage[&U256::ZERO].present_value, U256::from(1));
+        assert_eq!(storage[&U256::from(1)].present_value, U256::from(3));
+        assert!(state[&callee].storage.is_empty());
+
+        let stepped = &inspector.stepped;
+        assert!(!stepped.is_empty());
+        assert!(!stepped.contains(&callee));
+    }
+
+    #[derive(Default)]
+    struct TransactionEndRecorder {
+        summaries: Vec<TransactionSummary>,
+    }
+
+    impl<CTX, INTR: InterpreterTypes> Inspector<CTX, INTR> for TransactionEndRecorder {
+        fn transaction_end(&mut self, summary: &TransactionSummary) {
+            self.summaries.push(*summary);
+        }
+    }
+
+    #[test]
+    fn transaction_end_reconciles_fees() {
+        let caller = Address::with_last_byte(1);
+        let contract = Address::with_last_byte(0xcc);
+        let beneficiary = Address::with_last_byte(0xbb);
+        let balance = U256::from(10_000_000);
+
+        // Clears storage slot zero.
+        let bytecode = Bytecode::new_raw(Bytes::from(vec![
+            opcode::PUSH1,
+            0x00,
+            opcode::PUSH1,
+            0x00,
+            opcode::SSTORE,
+            opcode::STOP,
+        ]));
+        let mut db = InMemoryDB::default();
+        db.insert_account_info(caller, AccountInfo::from_balance(balance));
+        db.insert_account_info(
+            contract,
+            AccountInfo::new(U256::ZERO, 1, bytecode.hash_slow(), bytecode),
+        );
+        db.insert_account_storage(contract, U256::ZERO, U256::from(1))
+            .unwrap();
+
+        let context = Context::builder()
+            .with_db(db)
+            .modify_block_chained(|block| {
+                block.basefee = 7;
+                block.beneficiary = beneficiary;
+            })
+            .modify_tx_chained(|tx| {
+                tx.caller = caller;
+                tx.transact_to = TxKind::Call(contract);
+                tx.gas_price = U256::from(10);
+                tx.gas_limit = 100_000;
+            });
+        let mut evm = InspectorMainEvm::new(
+            InspectorContext::new(context, TransactionEndRecorder::default()),
+            inspector_handler(),
+        );
+
+        let ResultAndState { result, state } = evm.transact().unwrap();
+        let ExecutionResult::Success {
+            gas_used,
+            gas_refunded,
+            ..
+        } = result
+        else {
+            panic!("transaction failed");
+        };
+        let [summary] = evm.context.inspector.summaries[..] else {
+            panic!("expected a single summary");
+        };
+
+        assert!(gas_refunded > 0);
+        assert_eq!(summary.gas_limit, 100_000);
+        assert_eq!(summary.gas_used, gas_used);
+        assert_eq!(summary.gas_refunded, gas_refunded);
+        assert_eq!(summary.effective_gas_price, 10);
+        assert_eq!(summary.gas_cost, U256::from(10 * 100_000));
+        assert_eq!(
+            summary.gas_cost - summary.caller_reimbursement,
+            U256::from(10 * gas_used)
+        );
+        assert_eq!(summary.coinbase_reward, U256::from(3 * gas_used));
+        assert_eq!(
+            balance - state[&caller].info.balance,
+            summary.gas_cost - summary.caller_reimbursement
+        );
+        assert_eq!(state[&beneficiary].info.balance, summary.coinbase_reward);
+    }
+
+    #[derive(Default)]
+    struct InputTruncator {
+        final_inputs: Vec<Bytes>,
+    }
+
+    impl<CTX, INTR: InterpreterTypes> Inspector<CTX, INTR> for InputTruncator {
+        fn call(&mut self, _context: &mut CTX, inputs: &mut CallInputs) -> Option<CallOutcome> {
+            inputs.input = inputs.input.slice(..4);
+            None
+        }
+
+        fn frame_input_final(&mut self, _context: &mut CTX, inputs: &FrameInput) {
+            let FrameInput::Call(inputs) = inputs else {
+                panic!("Only calls expected");
+            };
+            self.final_inputs.push(inputs.input.clone());
+        }
+    }
+
+    #[test]
+    fn frame_input_final_reports_modified_input() {
+        let contract = Address::with_last_byte(0x11);
+
+        // Stores the input size in slot zero.
+        let code = vec![
+            opcode::CALLDATASIZE,
+            opcode::PUSH1,
+            0x00,
+            opcode::SSTORE,
+            opcode::STOP,
+        ];
+        let mut evm = inspector_evm([(contract, code)], InputTruncator::default());
+        evm.context.inner.tx.data = Bytes::from_static(&[1, 2, 3, 4, 5, 6, 7, 8]);
+
+        let output = evm.transact().unwrap();
+        assert!(output.result.is_success());
+        assert_eq!(
+            output.state[&contract].storage[&U256::ZERO].present_value,
+            U256::from(4)
+        );
+        assert_eq!(
+            evm.context.inspector.final_inputs,
+            vec![Bytes::from_static(&[1, 2, 3, 4])]
+        );
+    }
+
+    #[derive(Default)]
+    struct FrameLogsRecorder {
+        log_count: usize,
+        frames: Vec<(Address, usize)>,
+    }
+
+    impl<CTX, INTR: InterpreterTypes> Inspector<CTX, INTR> for FrameLogsRecorder {
+        fn frame_logs(&mut self, logs: &[Log]) {
+            self.log_count = logs.len();
+        }
+
+        fn call_end(
+            &mut self,
+            _context: &mut CTX,
+            inputs: &CallInputs,
+            _outcome: &mut CallOutcome,
+        ) {
+            self.frames.push((inputs.target_address, self.log_count));
+        }
+    }
+
+    #[test]
+    fn frame_logs_attributed_to_frames() {
+        let entry = Address::with_last_byte(0x11);
+        let logging = Address::with_last_byte(0xaa);
+        let reverting = Address::with_last_byte(0xbb);
+
+        let log0 = [opcode::PUSH1, 0x00, opcode::PUSH1, 0x00, opcode::LOG0];
+        // Emits two logs, calls `logging`, calls `reverting` and stops.
+        let entry_code = [
+            &log0[..],
+            &log0,
+            &call(0xaa),
+            &[opcode::POP],
+            &call(0xbb),
+            &[opcode::POP, opcode::STOP],
+        ]
+        .concat();
+        // Emits a log and stops.
+        let logging_code = [&log0[..], &[opcode::STOP]].concat();
+        // Emits a log and reverts.
+        let reverting_code = [
+            &log0[..],
+            &[opcode::PUSH1, 0x00, opcode::PUSH1, 0x00, opcode::REVERT],
+        ]
+        .concat();
+
+        let (output, inspector) = run_with_inspector(
+            [
+                (entry, entry_code),
+                (logging, logging_code),
+                (reverting, reverting_code),
+            ],
+            FrameLogsRecorder::default(),
+        );
+        assert!(output.result.is_success());
+        assert_eq!(output.result.logs().len(), 3);
+        assert_eq!(
+            inspector.frames,
+            vec![(logging, 1), (reverting, 0), (entry, 3)]
+        );
+    }
+
+    #[derive(Default)]
+    struct CreateRevertRecorder {
+        snapshots: Vec<(Address, InstructionResult, EvmState)>,
+    }
+
+    impl<CTX, INTR: InterpreterTypes> Inspector<CTX, INTR> for CreateRevertRecorder {
+        fn create_revert_snapshot_enabled(&self) -> bool {
+            true
+        }
+
+        fn create_revert_snapshot(
+            &mut self,
+            address: Address,
+            result: &InterpreterResult,
+            changes: &EvmState,
+        ) {
+            self.snapshots
+                .push((address, result.result, changes.clone()));
+        }
+    }
+
+    #[test]
+    fn create_revert_snapshot_has_constructor_sstores() {
+        let caller = Address::with_last_byte(1);
+        // Stores `0x2a` at slot one and `0x07` at slot two, then reverts.
+        let init_code = Bytes::from(vec![
+            opcode::PUSH1,
+            0x2a,
+            opcode::PUSH1,
+            0x01,
+            opcode::SSTORE,
+            opcode::PUSH1,
+            0x07,
+            opcode::PUSH1,
+            0x02,
+            opcode::SSTORE,
+            opcode::PUSH1,
+            0x00,
+            opcode::PUSH1,
+            0x00,
+            opcode::REVERT,
+        ]);
+
+        let mut db = InMemoryDB::default();
+        db.insert_account_info(caller, AccountInfo::from_balance(U256::from(1_000_000)));
+        let context = Context::builder().with_db(db).modify_tx_chained(|tx| {
+            tx.caller = caller;
+            tx.transact_to = TxKind::Create;
+            tx.data = init_code;
+            tx.gas_limit = 200_000;
+        });
+        let mut evm = InspectorMainEvm::new(
+            InspectorContext::new(context, CreateRevertRecorder::default()),
+            inspector_handler(),
+        );
+
+        let output = evm.transact().unwrap();
+        assert!(matches!(output.result, ExecutionResult::Revert { .. }));
+        let created = caller.create(0);
+        assert!(output
+            .state
+            .get(&created)
+            .map_or(true, |account| account.storage.is_empty()));
+
+        let snapshots = &evm.context.inspector.snapshots;
+        assert_eq!(snapshots.len(), 1);
+        let (address, result, changes) = &snapshots[0];
+        assert_eq!(*address, created);
+        assert_eq!(*result, InstructionResult::Revert);
+        let storage = &changes[&created].storage;
+        assert_eq!(storage.len(), 2);
+        assert_eq!(storage[&U256::from(1)].present_value, U256::from(0x2a));
+        assert_eq!(storage[&U256::from(2)].present_value, U256::from(0x07));
+    }
+
+    #[derive(Default)]
+    struct JumpRecorder {
+        edges: Vec<(usize, usize, bool, bool)>,
+    }
+
+    impl<CTX, INTR: InterpreterTypes> Inspector<CTX, INTR> for JumpRecorder {
+        fn jump(
+            &mut self,
+            _interp: &mut Interpreter<INTR>,
+            _context: &mut CTX,
+            from_pc: usize,
+            to_pc: usize,
+            conditional: bool,
+            taken: bool,
+        ) {
+            self.edges.push((from_pc, to_pc, conditional, taken));
+        }
+    }
+
+    #[test]
+    fn jump_edges_of_loop() {
+        let code = vec![
+            opcode::PUSH1,
+            0x02,
+            // Loop body, decrements the counter.
+            opcode::JUMPDEST,
+            opcode::PUSH1,
+            0x01,
+            opcode::SWAP1,
+            opcode::SUB,
+            opcode::DUP1,
+            opcode::PUSH1,
+            0x02,
+            opcode::JUMPI,
+            // Exits the loop.
+            opcode::PUSH1,
+            0x0f,
+            opcode::JUMP,
+            opcode::INVALID,
+            opcode::JUMPDEST,
+            opcode::STOP,
+        ];
+        let context = Context::builder()
+            .with_db(BenchmarkDB::new_bytecode(Bytecode::new_raw(Bytes::from(
+                code,
+            ))))
+            .modify_tx_chained(|tx| {
+                tx.caller = Address::with_last_byte(1);
+                tx.transact_to = TxKind::Call(Address::ZERO);
+                tx.gas_limit = 100_000;
+            });
+        let mut evm = InspectorMainEvm::new(
+            InspectorContext::new(context, JumpRecorder::default()),
+            inspector_handler(),
+        );
+
+        assert!(evm.transact().unwrap().result.is_success());
+        assert_eq!(
+            evm.context.inspector.edges,
+            vec![
+                (10, 2, true, true),
+                (10, 2, true, false),
+                (13, 15, false, true)
+            ]
+        );
+    }
+
+    #[derive(Default)]
+    struct InvalidJumpRecorder {
+        invalid_jumps: Vec<(usize, usize)>,
+        halts: Vec<InstructionResult>,
+    }
+
+    impl<CTX, INTR: InterpreterTypes> Inspector<CTX, INTR> for InvalidJumpRecorder {
+        fn invalid_jump(&mut self, from_pc: usize, to_pc: usize) {
+            self.invalid_jumps.push((from_pc, to_pc));
+        }
+
+        fn halt(
+            &mut self,
+            _interp: &mut Interpreter<INTR>,
+            _context: &mut CTX,
+            result: InstructionResult,
+        ) {
+            self.halts.push(result);
+        }
+    }
+
+    #[test]
+    fn invalid_jump_into_push_data() {
+        // Jumps to the `JUMPDEST` byte that is the immediate of `PUSH1`.
+        let code = vec![
+            opcode::PUSH1,
+            opcode::JUMPDEST,
+            opcode::POP,
+            opcode::PUSH1,
+            0x01,
+            opcode::JUMP,
+            opcode::STOP,
+        ];
+        let context = Context::builder()
+            .with_db(BenchmarkDB::new_bytecode(Bytecode::new_raw(Bytes::from(
+                code,
+            ))))
+            .modify_tx_chained(|tx| {
+                tx.caller = Address::with_last_byte(1);
+                tx.transact_to = TxKind::Call(Address::ZERO);
+                tx.gas_limit = 100_000;
+            });
+        let mut evm = InspectorMainEvm::new(
+            InspectorContext::new(context, InvalidJumpRecorder::default()),
+            inspector_handler(),
+        );
+
+        assert!(matches!(
+            evm.transact().unwrap().result,
+            ExecutionResult::Halt {
+                reason: HaltReason::InvalidJump,
+                ..
+            }
+        ));
+        assert_eq!(evm.context.inspector.invalid_jumps, vec![(5, 1)]);
+        assert_eq!(
+            evm.context.inspector.halts,
+            vec![InstructionResult::InvalidJump]
+        );
+    }
+
+    #[derive(Default)]
+    struct EofCallRecorder {
+        schemes: Vec<(CallScheme, bool)>,
+        opcodes: Vec<u8>,
+        return_data_word: Option<U256>,
+    }
+
+    impl<CTX, INTR: InterpreterTypes> Inspector<CTX, INTR> for EofCallRecorder {
+        fn step(&mut self, interp: &mut Interpreter<INTR>, _context: &mut CTX) {
+            let opcode = interp.bytecode.opcode();
+            // Word loaded by the preceding `RETURNDATALOAD`.
+            if self.opcodes.last() == Some(&opcode::RETURNDATALOAD) {
+                self.return_data_word = interp.stack.top().copied();
+            }
+            self.opcodes.push(opcode);
+        }
+
+        fn call(&mut self, _context: &mut CTX, inputs: &mut CallInputs) -> Option<CallOutcome> {
+            self.schemes.push((inputs.scheme, inputs.is_eof));
+            None
+        }
+    }
+
+    #[test]
+    fn eof_extcall_is_traced() {
+        let caller = Address::with_last_byte(0x01);
+        let entry = Address::with_last_byte(0xaa);
+        let callee = Address::with_last_byte(0xbb);
+
+        // EXTCALL(callee) then loads the first word of the return data.
+        let entry_code = Bytes::from_static(&[
+            opcode::PUSH0,
+            opcode::PUSH0,
+            opcode::PUSH0,
+            opcode::PUSH1,
+            0xbb,
+            opcode::EXTCALL,
+            opcode::POP,
+            opcode::PUSH0,
+            opcode::RETURNDATALOAD,
+            opcode::POP,
+            opcode::STOP,
+        ]);
+        let eof = Eof::new(EofBody {
+            types_section: vec![TypesSection::new(0, 0x80, 4)],
+            code_section: vec![entry_code.len()],
+            code: entry_code,
+            container_section: vec![],
+            data_section: Bytes::new(),
+            is_data_filled: true,
+        });
+        // Returns `0x2a` as a word.
+        let callee_code = Bytecode::new_raw(Bytes::from_static(&[
+            opcode::PUSH1,
+            0x2a,
+            opcode::PUSH0,
+            opcode::MSTORE,
+            opcode::PUSH1,
+            0x20,
+            opcode::PUSH0,
+            opcode::RETURN,
+        ]));
+
+        let mut db = InMemoryDB::default();
+        db.insert_account_info(caller, AccountInfo::from_balance(U256::from(1_000_000)));
+        let entry_code = Bytecode::Eof(Arc::new(eof));
+        for (address, code) in [(entry, entry_code), (callee, callee_code)] {
+            db.insert_account_info(
+                address,
+                AccountInfo::new(U256::ZERO, 1, code.hash_slow(), code),
+            );
+        }
+
+        let context = Context::builder()
+            .with_db(db)
+            .modify_cfg_chained(|cfg| cfg.spec = SpecId::OSAKA)
+            .modify_tx_chained(|tx| {
+                tx.caller = caller;
+                tx.transact_to = TxKind::Call(entry);
+                tx.gas_limit = 200_000;
+            });
+        let mut evm = InspectorMainEvm::new(
+            InspectorContext::new(context, EofCallRecorder::default()),
+            inspector_handler(),
+        );
+
+        assert!(evm.transact().unwrap().result.is_success());
+        let inspector = &evm.context.inspector;
+        assert_eq!(
+            inspector.schemes,
+            vec![(CallScheme::Call, false), (CallScheme::ExtCall, true)]
+        );
+        assert!(inspector.schemes[1].0.is_ext());
+        assert!(inspector.opcodes.contains(&opcode::RETURNDATALOAD));
+        assert_eq!(inspector.return_data_word, Some(U256::from(0x2a)));
+    }
+}
@@ -5,18 +5,46 @@
 #[cfg(not(feature = "std"))]
 extern crate alloc as std;
 
+mod breakpoint;
+#[cfg(feature = "serde")]
+mod call_tracer;
+mod call_whitelist;
+#[cfg(feature = "std")]
+mod channel;
 #[cfg(all(feature = "std", feature = "serde-json"))]
 mod eip3155;
+mod failure_cause;
+mod filter;
 mod gas;
+mod gas_budget;
 mod inspector;
 mod noop;
+mod reentrancy;
+mod resource_high_water;
+mod storage_access;
+#[cfg(test)]
+mod test_utils;
+mod trace_digest;
 
 pub use inspector::*;
 
 /// [Inspector] implementations.
 pub mod inspectors {
+    pub use super::breakpoint::{Breakpoint, BreakpointHit, BreakpointInspector};
+    #[cfg(feature = "serde")]
+    pub use super::call_tracer::{CallFrame, CallKind, CallTracerConfig, CallTracerInspector};
+    pub use super::call_whitelist::CallWhitelistInspector;
+    #[cfg(feature = "std")]
+    pub use super::channel::{ChannelTracerInspector, TraceEvent};
     #[cfg(all(feature = "std", feature = "serde-json"))]
     pub use super::eip3155::TracerEip3155;
+    pub use super::failure_cause::FailureCauseInspector;
+    pub use super::filter::FilteredInspector;
     pub use super::gas::GasInspector;
+    pub use super::gas_budget::PerAddressGasBudgetInspector;
     pub use super::noop::NoOpInspector;
+    pub use super::reentrancy::{Reentrancy, ReentrancyInspector};
+    pub use super::resource_high_water::{FramePeaks, ResourceHighWaterInspector};
+    pub use super::storage_access::{AccessKind, StorageAccessInspector};
+    pub use super::trace_digest::TraceDigestInspector;
 }
@@ -0,0 +1,168 @@
+//! ReentrancyInspector. Reports addresses re-entered while an earlier frame is still active.
+use crate::Inspector;
+use revm::{
+    interpreter::{
+        CallInputs, CallOutcome, CallScheme, CreateInputs, CreateOutcome, EOFCreateInputs,
+        InterpreterTypes,
+    },
+    primitives::Address,
+};
+use std::vec::Vec;
+
+/// Reentrant entry reported by [`ReentrancyInspector`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Reentrancy {
+    /// Address that was re-entered.
+    pub address: Address,
+    /// Addresses of the active frames, from the transaction frame to the reentrant one.
+    ///
+    /// Create frames are not included.
+    pub path: Vec<Address>,
+    /// Whether the reentrant call is static and can't change state.
+    pub is_static: bool,
+}
+
+/// [Inspector] that reports every call entering an address that already has an active frame.
+///
+/// Delegate calls run in the storage context of the caller and are not reported.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct ReentrancyInspector {
+    /// Target address of every active frame, `None` for create frames.
+    stack: Vec<Option<Address>>,
+    /// Reentrant entries in execution order.
+    reports: Vec<Reentrancy>,
+}
+
+impl ReentrancyInspector {
+    /// Creates a new inspector.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the reentrant entries found so far.
+    pub fn reports(&self) -> &[Reentrancy] {
+        &self.reports
+    }
+
+    /// Returns the reentrant entries found so far and clears them.
+    pub fn take_reports(&mut self) -> Vec<Reentrancy> {
+        core::mem::take(&mut self.reports)
+    }
+}
+
+impl<CTX, INTR: InterpreterTypes> Inspector<CTX, INTR> for ReentrancyInspector {
+    fn call(&mut self, _context: &mut CTX, inputs: &mut CallInputs) -> Option<CallOutcome> {
+        let address = inputs.target_address;
+        let is_delegate = matches!(
+            inputs.scheme,
+            CallScheme::CallCode | CallScheme::DelegateCall | CallScheme::ExtDelegateCall
+        );
+        let is_reentrant = !is_delegate && self.stack.contains(&Some(address));
+
+        self.stack.push(Some(address));
+        if is_reentrant {
+            self.reports.push(Reentrancy {
+                address,
+                path: self.stack.iter().flatten().copied().collect(),
+                is_static: inputs.is_static,
+            });
+        }
+        None
+    }
+
+    fn call_end(&mut self, _context: &mut CTX, _inputs: &CallInputs, _outcome: &mut CallOutcome) {
+        self.stack.pop();
+    }
+
+    fn create(&mut self, _context: &mut CTX, _inputs: &mut CreateInputs) -> Option<CreateOutcome> {
+        self.stack.push(None);
+        None
+    }
+
+    fn create_end(
+        &mut self,
+        _context: &mut CTX,
+        _inputs: &CreateInputs,
+        _outcome: &mut CreateOutcome,
+    ) {
+        self.stack.pop();
+    }
+
+    fn eofcreate(
+        &mut self,
+        _context: &mut CTX,
+        _inputs: &mut EOFCreateInputs,
+    ) -> Option<CreateOutcome> {
+        self.stack.push(None);
+        None
+    }
+
+    fn eofcreate_end(
+        &mut self,
+        _context: &mut CTX,
+        _inputs: &EOFCreateInputs,
+        _outcome: &mut CreateOutcome,
+    ) {
+        self.stack.pop();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::run_with_inspector;
+    use revm::bytecode::opcode;
+
+    const CONTRACT: Address = Address::with_last_byte(0xaa);
+
+    /// Runs a contract that calls itself once with `call_opcode` and returns the reports.
+    fn self_call(call_opcode: u8) -> Vec<Reentrancy> {
+        // Stops if called with calldata, otherwise calls itself with one byte of calldata.
+        let mut code = vec![
+            opcode::CALLDATASIZE,
+            opcode::PUSH1,
+            0x00,
+            opcode::JUMPI,
+            opcode::PUSH1,
+            0x00,
+            opcode::PUSH1,
+            0x00,
+            opcode::PUSH1,
+            0x01,
+            opcode::PUSH1,
+            0x00,
+        ];
+        if call_opcode == opcode::CALL {
+            code.extend([opcode::PUSH1, 0x00]);
+        }
+        code.extend([opcode::ADDRESS, opcode::GAS, call_opcode, opcode::STOP]);
+        code[2] = code.len() as u8;
+        code.extend([opcode::JUMPDEST, opcode::STOP]);
+
+        let (output, mut inspector) =
+            run_with_inspector([(CONTRACT, code)], ReentrancyInspector::new());
+        assert!(output.result.is_success());
+        inspector.take_reports()
+    }
+
+    #[test]
+    fn self_call_reported() {
+        assert_eq!(
+            self_call(opcode::CALL),
+            [Reentrancy {
+                address: CONTRACT,
+                path: vec![CONTRACT, CONTRACT],
+                is_static: false,
+            }]
+        );
+        assert_eq!(
+            self_call(opcode::STATICCALL),
+            [Reentrancy {
+                address: CONTRACT,
+                path: vec![CONTRACT, CONTRACT],
+                is_static: true,
+            }]
+        );
+        assert!(self_call(opcode::DELEGATECALL).is_empty());
+    }
+}
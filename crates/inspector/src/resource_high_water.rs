@@ -0,0 +1,194 @@
+//! ResourceHighWaterInspector. Records the peak memory size and stack depth of every frame.
+use crate::Inspector;
+use revm::{
+    interpreter::{
+        interpreter_types::{MemoryTrait, StackTrait},
+        CallInputs, CallOutcome, CreateInputs, CreateOutcome, EOFCreateInputs, Interpreter,
+        InterpreterTypes,
+    },
+    primitives::Address,
+};
+use std::vec::Vec;
+
+/// Peak resource usage of a frame reported by [`ResourceHighWaterInspector`].
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct FramePeaks {
+    /// Depth of the frame, `0` for the transaction frame.
+    pub depth: usize,
+    /// Call target, or created address if the create succeeded.
+    pub address: Option<Address>,
+    /// Peak memory size in bytes.
+    pub memory: usize,
+    /// Peak number of items on the stack.
+    pub stack: usize,
+}
+
+/// [Inspector] that records the peak memory size and stack depth reached within each frame.
+///
+/// Frames are reported in the order they are entered. Usage of a subframe doesn't count
+/// towards its parent, as every frame has its own stack and memory context.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct ResourceHighWaterInspector {
+    /// Peaks of every frame entered so far.
+    frames: Vec<FramePeaks>,
+    /// Index in `frames` of every active frame.
+    active: Vec<usize>,
+}
+
+impl ResourceHighWaterInspector {
+    /// Creates a new inspector.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the peaks of every frame entered so far.
+    pub fn frames(&self) -> &[FramePeaks] {
+        &self.frames
+    }
+
+    /// Consumes the inspector and returns the peaks of every frame.
+    pub fn into_frames(self) -> Vec<FramePeaks> {
+        self.frames
+    }
+
+    fn enter(&mut self, address: Option<Address>) {
+        self.active.push(self.frames.len());
+        self.frames.push(FramePeaks {
+            depth: self.active.len() - 1,
+            address,
+            ..Default::default()
+        });
+    }
+
+    fn exit(&mut self) -> Option<&mut FramePeaks> {
+        let index = self.active.pop()?;
+        self.frames.get_mut(index)
+    }
+
+    fn record<INTR: InterpreterTypes>(&mut self, interp: &Interpreter<INTR>) {
+        let Some(frame) = self
+            .active
+            .last()
+            .and_then(|index| self.frames.get_mut(*index))
+        else {
+            return;
+        };
+        frame.memory = frame.memory.max(interp.memory.size());
+        frame.stack = frame.stack.max(interp.stack.len());
+    }
+}
+
+impl<CTX, INTR: InterpreterTypes> Inspector<CTX, INTR> for ResourceHighWaterInspector {
+    fn step(&mut self, interp: &mut Interpreter<INTR>, _context: &mut CTX) {
+        self.record(interp);
+    }
+
+    fn step_end(&mut self, interp: &mut Interpreter<INTR>, _context: &mut CTX) {
+        self.record(interp);
+    }
+
+    fn call(&mut self, _context: &mut CTX, inputs: &mut CallInputs) -> Option<CallOutcome> {
+        self.enter(Some(inputs.target_address));
+        None
+    }
+
+    fn call_end(&mut self, _context: &mut CTX, _inputs: &CallInputs, _outcome: &mut CallOutcome) {
+        self.exit();
+    }
+
+    fn create(&mut self, _context: &mut CTX, _inputs: &mut CreateInputs) -> Option<CreateOutcome> {
+        self.enter(None);
+        None
+    }
+
+    fn create_end(
+        &mut self,
+        _context: &mut CTX,
+        _inputs: &CreateInputs,
+        outcome: &mut CreateOutcome,
+    ) {
+        if let Some(frame) = self.exit() {
+            frame.address = outcome.address;
+        }
+    }
+
+    fn eofcreate(
+        &mut self,
+        _context: &mut CTX,
+        _inputs: &mut EOFCreateInputs,
+    ) -> Option<CreateOutcome> {
+        self.enter(None);
+        None
+    }
+
+    fn eofcreate_end(
+        &mut self,
+        _context: &mut CTX,
+        _inputs: &EOFCreateInputs,
+        outcome: &mut CreateOutcome,
+    ) {
+        if let Some(frame) = self.exit() {
+            frame.address = outcome.address;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::{call, run_with_inspector};
+    use revm::bytecode::opcode;
+
+    #[test]
+    fn records_peak_per_frame() {
+        let entry = Address::with_last_byte(0x11);
+        let callee = Address::with_last_byte(0xaa);
+
+        // Expands memory to 0x120 bytes, pops the stored values and calls `callee`.
+        let entry_code = [
+            &[
+                opcode::PUSH1,
+                0x01,
+                opcode::PUSH2,
+                0x01,
+                0x00,
+                opcode::MSTORE,
+            ][..],
+            &call(0xaa),
+            &[opcode::POP, opcode::STOP],
+        ]
+        .concat();
+        // Uses a single word of memory.
+        let callee_code = vec![
+            opcode::PUSH1,
+            0x01,
+            opcode::PUSH1,
+            0x00,
+            opcode::MSTORE8,
+            opcode::STOP,
+        ];
+
+        let (output, inspector) = run_with_inspector(
+            [(entry, entry_code), (callee, callee_code)],
+            ResourceHighWaterInspector::new(),
+        );
+        assert!(output.result.is_success());
+        assert_eq!(
+            inspector.frames(),
+            [
+                FramePeaks {
+                    depth: 0,
+                    address: Some(entry),
+                    memory: 0x120,
+                    stack: 7,
+                },
+                FramePeaks {
+                    depth: 1,
+                    address: Some(callee),
+                    memory: 0x20,
+                    stack: 2,
+                },
+            ]
+        );
+    }
+}
@@ -0,0 +1,112 @@
+//! StorageAccessInspector. Records storage accesses in execution order.
+use crate::Inspector;
+use revm::{
+    bytecode::opcode,
+    interpreter::{
+        interpreter_types::{InputsTrait, Jumps, LoopControl, StackTrait},
+        Interpreter, InterpreterTypes,
+    },
+    primitives::{Address, U256},
+};
+use std::vec::Vec;
+
+/// Kind of a storage access.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum AccessKind {
+    /// `SLOAD` of the slot.
+    Read,
+    /// `SSTORE` to the slot.
+    Write,
+}
+
+/// [Inspector] that records every storage access in the order it occurred.
+///
+/// Unlike an access list, accesses are not deduplicated: loading the same slot twice records
+/// it twice. Only executed accesses are recorded, an `SSTORE` failing in a static call is
+/// skipped. Accesses of reverted frames are kept.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct StorageAccessInspector {
+    accesses: Vec<(Address, U256, AccessKind)>,
+    pending: Option<(Address, U256, AccessKind)>,
+}
+
+impl StorageAccessInspector {
+    /// Creates a new inspector without recorded accesses.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the storage accesses as `(address, slot, kind)` in execution order.
+    pub fn accesses(&self) -> &[(Address, U256, AccessKind)] {
+        &self.accesses
+    }
+
+    /// Consumes the inspector and returns the recorded accesses.
+    pub fn into_accesses(self) -> Vec<(Address, U256, AccessKind)> {
+        self.accesses
+    }
+}
+
+impl<CTX, INTR: InterpreterTypes> Inspector<CTX, INTR> for StorageAccessInspector {
+    fn step(&mut self, interp: &mut Interpreter<INTR>, _context: &mut CTX) {
+        let kind = match interp.bytecode.opcode() {
+            opcode::SLOAD => AccessKind::Read,
+            opcode::SSTORE => AccessKind::Write,
+            _ => return,
+        };
+        // The slot is on top of the stack for both opcodes.
+        if let Some(slot) = interp.stack.top() {
+            self.pending = Some((interp.input.target_address(), *slot, kind));
+        }
+    }
+
+    fn step_end(&mut self, interp: &mut Interpreter<INTR>, _context: &mut CTX) {
+        if let Some(access) = self.pending.take() {
+            if interp.control.instruction_result().is_continue() {
+                self.accesses.push(access);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::run_with_inspector;
+
+    #[test]
+    fn keeps_order_and_duplicates() {
+        let contract = Address::with_last_byte(0xaa);
+        // SLOAD(1), SLOAD(1), SSTORE(2, 0x2a), SLOAD(1)
+        let code = vec![
+            opcode::PUSH1,
+            0x01,
+            opcode::SLOAD,
+            opcode::PUSH1,
+            0x01,
+            opcode::SLOAD,
+            opcode::PUSH1,
+            0x2a,
+            opcode::PUSH1,
+            0x02,
+            opcode::SSTORE,
+            opcode::PUSH1,
+            0x01,
+            opcode::SLOAD,
+            opcode::STOP,
+        ];
+
+        let (output, inspector) =
+            run_with_inspector([(contract, code)], StorageAccessInspector::new());
+        assert!(output.result.is_success());
+        assert_eq!(
+            inspector.accesses(),
+            &[
+                (contract, U256::from(1), AccessKind::Read),
+                (contract, U256::from(1), AccessKind::Read),
+                (contract, U256::from(2), AccessKind::Write),
+                (contract, U256::from(1), AccessKind::Read),
+            ]
+        );
+    }
+}
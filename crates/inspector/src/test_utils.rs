@@ -0,0 +1,88 @@
+//! Scaffolding shared by the inspector tests.
+use crate::{inspector_handler, GetInspector, InspectorContext, InspectorMainEvm};
+use database::InMemoryDB;
+use revm::{
+    bytecode::{opcode, Bytecode},
+    context_interface::result::{HaltReason, ResultAndState},
+    interpreter::interpreter::EthInterpreter,
+    primitives::{Address, Bytes, TxKind, U256},
+    state::AccountInfo,
+    Context, EthContext,
+};
+
+/// Caller of the transactions, funded in the database of [`inspector_evm`].
+pub(crate) const CALLER: Address = Address::with_last_byte(0x01);
+
+/// Gas limit of the transactions.
+pub(crate) const GAS_LIMIT: u64 = 1_000_000;
+
+/// EVM returned by [`inspector_evm`].
+pub(crate) type TestEvm<INSP> = InspectorMainEvm<INSP, EthContext<InMemoryDB>, InMemoryDB>;
+
+/// Code calling `target` with all the gas left, without value nor calldata.
+///
+/// The success flag of the call is left on the stack.
+pub(crate) fn call(target: u8) -> [u8; 14] {
+    [
+        opcode::PUSH1,
+        0x00,
+        opcode::PUSH1,
+        0x00,
+        opcode::PUSH1,
+        0x00,
+        opcode::PUSH1,
+        0x00,
+        opcode::PUSH1,
+        0x00,
+        opcode::PUSH1,
+        target,
+        opcode::GAS,
+        opcode::CALL,
+    ]
+}
+
+/// Returns an EVM with `inspector` attached, deploying `contracts` and calling the first one
+/// from [`CALLER`].
+pub(crate) fn inspector_evm<INSP>(
+    contracts: impl IntoIterator<Item = (Address, Vec<u8>)>,
+    inspector: INSP,
+) -> TestEvm<INSP>
+where
+    INSP: GetInspector<EthContext<InMemoryDB>, EthInterpreter>,
+{
+    let mut db = InMemoryDB::default();
+    db.insert_account_info(CALLER, AccountInfo::from_balance(U256::from(1_000_000)));
+    let mut target = None;
+    for (address, code) in contracts {
+        target.get_or_insert(address);
+        let bytecode = Bytecode::new_raw(Bytes::from(code));
+        db.insert_account_info(
+            address,
+            AccountInfo::new(U256::ZERO, 1, bytecode.hash_slow(), bytecode),
+        );
+    }
+    let target = target.expect("at least one contract");
+
+    let context = Context::builder().with_db(db).modify_tx_chained(|tx| {
+        tx.caller = CALLER;
+        tx.transact_to = TxKind::Call(target);
+        tx.gas_limit = GAS_LIMIT;
+    });
+    InspectorMainEvm::new(
+        InspectorContext::new(context, inspector),
+        inspector_handler(),
+    )
+}
+
+/// Runs the transaction of [`inspector_evm`] and returns its output and the inspector.
+pub(crate) fn run_with_inspector<INSP>(
+    contracts: impl IntoIterator<Item = (Address, Vec<u8>)>,
+    inspector: INSP,
+) -> (ResultAndState<HaltReason>, INSP)
+where
+    INSP: GetInspector<EthContext<InMemoryDB>, EthInterpreter>,
+{
+    let mut evm = inspector_evm(contracts, inspector);
+    let output = evm.transact().unwrap();
+    (output, evm.context.inspector)
+}
@@ -0,0 +1,98 @@
+//! TraceDigestInspector. Summarizes the executed instructions in a single hash.
+use crate::Inspector;
+use revm::{
+    interpreter::{
+        interpreter_types::{Jumps, LoopControl},
+        Interpreter, InterpreterTypes,
+    },
+    primitives::{keccak256, B256},
+};
+
+/// [Inspector] that folds every executed instruction into a running keccak256 digest.
+///
+/// For each step the digest becomes `keccak256(digest || pc || opcode || gas)`, where `pc` and
+/// the remaining `gas` are big-endian `u64`. Two runs executing the same instructions with the
+/// same gas produce the same digest, which makes it cheap to compare executions. Stack and
+/// memory contents are not part of the digest.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct TraceDigestInspector {
+    digest: B256,
+    steps: u64,
+}
+
+impl TraceDigestInspector {
+    /// Creates a new inspector with a zero digest.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the digest of the instructions executed so far.
+    pub fn digest(&self) -> B256 {
+        self.digest
+    }
+
+    /// Returns the number of instructions folded into the digest.
+    pub fn steps(&self) -> u64 {
+        self.steps
+    }
+
+    /// Resets the digest, e.g. before inspecting another transaction.
+    pub fn reset(&mut self) {
+        *self = Self::default();
+    }
+}
+
+impl<CTX, INTR: InterpreterTypes> Inspector<CTX, INTR> for TraceDigestInspector {
+    fn step(&mut self, interp: &mut Interpreter<INTR>, _context: &mut CTX) {
+        let mut buf = [0u8; 49];
+        buf[..32].copy_from_slice(self.digest.as_slice());
+        buf[32..40].copy_from_slice(&(interp.bytecode.pc() as u64).to_be_bytes());
+        buf[40] = interp.bytecode.opcode();
+        buf[41..].copy_from_slice(&interp.control.gas().remaining().to_be_bytes());
+        self.digest = keccak256(buf);
+        self.steps += 1;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::run_with_inspector;
+    use revm::{bytecode::opcode, primitives::Address};
+
+    fn digest(code: Vec<u8>) -> (B256, u64) {
+        let contract = Address::with_last_byte(0xaa);
+        let (output, inspector) =
+            run_with_inspector([(contract, code)], TraceDigestInspector::new());
+        assert!(output.result.is_success());
+        (inspector.digest(), inspector.steps())
+    }
+
+    #[test]
+    fn identical_runs_match() {
+        // Stores `0x2a` at slot zero.
+        let code = vec![
+            opcode::PUSH1,
+            0x2a,
+            opcode::PUSH1,
+            0x00,
+            opcode::SSTORE,
+            opcode::STOP,
+        ];
+        let (first, steps) = digest(code.clone());
+        assert_eq!(steps, 4);
+        assert_ne!(first, B256::ZERO);
+        assert_eq!(digest(code.clone()), (first, steps));
+
+        // Stack values are not part of the digest.
+        let mut other_value = code.clone();
+        other_value[1] = 0x2b;
+        assert_eq!(digest(other_value), (first, steps));
+
+        let mut modified = code;
+        modified.insert(0, opcode::JUMPDEST);
+        let (modified_digest, modified_steps) = digest(modified);
+        assert_eq!(modified_steps, steps + 1);
+        assert_ne!(modified_digest, first);
+    }
+}
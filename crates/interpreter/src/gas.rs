@@ -2,9 +2,13 @@
 
 mod calc;
 mod constants;
+mod spec_constants;
+mod static_cost;
 
 pub use calc::*;
 pub use constants::*;
+pub use spec_constants::GasConstants;
+pub use static_cost::static_gas_cost;
 
 /// Represents the state of gas during execution.
 #[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
@@ -18,6 +22,9 @@ pub struct Gas {
     refunded: i64,
     /// Memoisation of values for memory expansion cost.
     memory: MemoryGas,
+    /// Whether costs are ignored, see [`Gas::set_unmetered`].
+    #[cfg_attr(feature = "serde", serde(default))]
+    unmetered: bool,
 }
 
 impl Gas {
@@ -29,6 +36,7 @@ impl Gas {
             remaining: limit,
             refunded: 0,
             memory: MemoryGas::new(),
+            unmetered: false,
         }
     }
 
@@ -40,6 +48,7 @@ impl Gas {
             remaining: 0,
             refunded: 0,
             memory: MemoryGas::new(),
+            unmetered: false,
         }
     }
 
@@ -49,6 +58,21 @@ impl Gas {
         self.limit
     }
 
+    /// Disables gas metering.
+    ///
+    /// Recorded and erased costs are ignored, so execution never runs out of gas and the
+    /// remaining gas stays the same.
+    #[inline]
+    pub fn set_unmetered(&mut self) {
+        self.unmetered = true;
+    }
+
+    /// Returns `true` if gas metering is disabled, see [`Gas::set_unmetered`].
+    #[inline]
+    pub const fn is_unmetered(&self) -> bool {
+        self.unmetered
+    }
+
     /// Returns the **last** memory expansion cost.
     #[inline]
     #[deprecated = "memory expansion cost is not tracked anymore; \
@@ -84,6 +108,9 @@ impl Gas {
     /// Erases a gas cost from the totals.
     #[inline]
     pub fn erase_cost(&mut self, returned: u64) {
+        if self.unmetered {
+            return;
+        }
         self.remaining += returned;
     }
 
@@ -109,8 +136,19 @@ impl Gas {
     /// Related to EIP-3529: Reduction in refunds
     #[inline]
     pub fn set_final_refund(&mut self, is_london: bool) {
-        let max_refund_quotient = if is_london { 5 } else { 2 };
-        self.refunded = (self.refunded() as u64).min(self.spent() / max_refund_quotient) as i64;
+        self.set_final_refund_with_quotient(if is_london { 5 } else { 2 });
+    }
+
+    /// Set a refund value for final refund, limited to `1 / max_refund_quotient` of gas spend.
+    ///
+    /// A `max_refund_quotient` of zero disables the refund.
+    #[inline]
+    pub fn set_final_refund_with_quotient(&mut self, max_refund_quotient: u64) {
+        let max_refund = self
+            .spent()
+            .checked_div(max_refund_quotient)
+            .unwrap_or_default();
+        self.refunded = (self.refunded() as u64).min(max_refund) as i64;
     }
 
     /// Set a refund value. This overrides the current refund value.
@@ -125,6 +163,9 @@ impl Gas {
     #[inline]
     #[must_use = "prefer using `gas!` instead to return an out-of-gas error on failure"]
     pub fn record_cost(&mut self, cost: u64) -> bool {
+        if self.unmetered {
+            return true;
+        }
         let (remaining, overflow) = self.remaining.overflowing_sub(cost);
         let success = !overflow;
         if success {
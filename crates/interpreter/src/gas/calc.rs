@@ -62,7 +62,13 @@ pub fn sstore_refund(spec_id: SpecId, vals: &SStoreResult) -> i64 {
 /// `CREATE2` opcode cost calculation.
 #[inline]
 pub const fn create2_cost(len: usize) -> Option<u64> {
-    CREATE.checked_add(tri!(cost_per_word(len, KECCAK256WORD)))
+    create2_cost_with_base(CREATE, len)
+}
+
+/// `CREATE2` opcode cost calculation with a custom base cost replacing [`CREATE`].
+#[inline]
+pub const fn create2_cost_with_base(base_cost: u64, len: usize) -> Option<u64> {
+    base_cost.checked_add(tri!(cost_per_word(len, KECCAK256WORD)))
 }
 
 #[inline]
@@ -286,15 +292,43 @@ pub const fn selfdestruct_cost(spec_id: SpecId, res: StateLoad<SelfDestructResul
 /// as they were present before SPURIOUS_DRAGON hardfork.
 #[inline]
 pub const fn call_cost(spec_id: SpecId, transfers_value: bool, account_load: AccountLoad) -> u64 {
-    // Account access.
-    let mut gas = if spec_id.is_enabled_in(SpecId::BERLIN) {
-        warm_cold_cost_with_delegation(account_load.load)
+    call_cost_with_base(
+        call_base_cost(spec_id),
+        spec_id,
+        transfers_value,
+        account_load,
+    )
+}
+
+/// Base cost of call-family opcodes, charged regardless of the target account and value.
+///
+/// After berlin it is the warm account access cost.
+#[inline]
+pub const fn call_base_cost(spec_id: SpecId) -> u64 {
+    if spec_id.is_enabled_in(SpecId::BERLIN) {
+        WARM_STORAGE_READ_COST
     } else if spec_id.is_enabled_in(SpecId::TANGERINE) {
         // EIP-150: Gas cost changes for IO-heavy operations
         700
     } else {
         40
-    };
+    }
+}
+
+/// Same as [`call_cost`] but with a custom base cost replacing [`call_base_cost`].
+#[inline]
+pub const fn call_cost_with_base(
+    base_cost: u64,
+    spec_id: SpecId,
+    transfers_value: bool,
+    account_load: AccountLoad,
+) -> u64 {
+    let mut gas = base_cost;
+
+    // Account access. Base cost covers the warm access of the target.
+    if spec_id.is_enabled_in(SpecId::BERLIN) {
+        gas += warm_cold_cost_with_delegation(account_load.load) - WARM_STORAGE_READ_COST;
+    }
 
     // Transfer value cost
     if transfers_value {
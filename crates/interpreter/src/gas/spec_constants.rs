@@ -0,0 +1,96 @@
+use super::calc::{call_base_cost, sload_cost};
+use super::constants::*;
+use specification::hardfork::SpecId;
+
+/// Gas constants that changed between hardforks, resolved for a spec.
+///
+/// Account and storage accesses are priced as warm, the cold surcharges are given separately
+/// and are zero before Berlin.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct GasConstants {
+    /// Cost of a warm `SLOAD`.
+    pub sload: u64,
+    /// Cost of an `SSTORE` setting a zero slot to a non-zero value.
+    pub sstore_set: u64,
+    /// Cost of an `SSTORE` changing the value of a non-zero slot.
+    pub sstore_reset: u64,
+    /// Refund of an `SSTORE` clearing a slot.
+    pub sstore_clears_refund: i64,
+    /// Surcharge of a cold storage slot access.
+    pub cold_sload: u64,
+    /// Surcharge of a cold account access.
+    pub cold_account_access: u64,
+    /// Base cost of the call-family opcodes.
+    pub call_base: u64,
+    /// Refund of `SELFDESTRUCT`.
+    pub selfdestruct_refund: i64,
+    /// Transaction cost of a non-zero calldata byte.
+    pub transaction_non_zero_data: u64,
+    /// Quotient of the gas spent capping the final refund.
+    pub max_refund_quotient: u64,
+}
+
+impl GasConstants {
+    /// Returns the gas constants of the given spec.
+    pub const fn for_spec(spec: SpecId) -> Self {
+        let is_berlin = spec.is_enabled_in(SpecId::BERLIN);
+        let is_london = spec.is_enabled_in(SpecId::LONDON);
+        Self {
+            sload: sload_cost(spec, false),
+            sstore_set: SSTORE_SET,
+            sstore_reset: if is_berlin {
+                WARM_SSTORE_RESET
+            } else {
+                SSTORE_RESET
+            },
+            // EIP-3529: Reduction in refunds
+            sstore_clears_refund: if is_london {
+                (SSTORE_RESET - COLD_SLOAD_COST + ACCESS_LIST_STORAGE_KEY) as i64
+            } else {
+                REFUND_SSTORE_CLEARS
+            },
+            cold_sload: if is_berlin {
+                COLD_SLOAD_COST - WARM_STORAGE_READ_COST
+            } else {
+                0
+            },
+            cold_account_access: if is_berlin {
+                COLD_ACCOUNT_ACCESS_COST - WARM_STORAGE_READ_COST
+            } else {
+                0
+            },
+            call_base: call_base_cost(spec),
+            selfdestruct_refund: if is_london { 0 } else { SELFDESTRUCT },
+            // EIP-2028: Transaction data gas cost reduction
+            transaction_non_zero_data: if spec.is_enabled_in(SpecId::ISTANBUL) {
+                TRANSACTION_NON_ZERO_DATA_INIT
+            } else {
+                TRANSACTION_NON_ZERO_DATA_FRONTIER
+            },
+            max_refund_quotient: if is_london { 5 } else { 2 },
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn sstore_constants_change_in_berlin() {
+        let istanbul = GasConstants::for_spec(SpecId::ISTANBUL);
+        let berlin = GasConstants::for_spec(SpecId::BERLIN);
+
+        assert_eq!(istanbul.sload, 800);
+        assert_eq!(berlin.sload, 100);
+        assert_eq!(istanbul.sstore_reset, 5000);
+        assert_eq!(berlin.sstore_reset, 2900);
+        assert_eq!(istanbul.sstore_set, berlin.sstore_set);
+        assert_eq!(istanbul.cold_sload, 0);
+        assert_eq!(berlin.cold_sload, 2000);
+
+        let london = GasConstants::for_spec(SpecId::LONDON);
+        assert_eq!(berlin.sstore_clears_refund, 15000);
+        assert_eq!(london.sstore_clears_refund, 4800);
+    }
+}
@@ -0,0 +1,183 @@
+use super::calc::{call_base_cost, sload_cost};
+use super::constants::*;
+use bytecode::opcode::{self, OpCode};
+use specification::hardfork::{SpecId, SpecId::*};
+
+/// Returns the static gas cost of the opcode at the given spec.
+///
+/// This is the part of the cost known without executing the instruction. Costs depending on
+/// memory expansion, copied or hashed length, exponent size, value transfer, account creation
+/// or gas forwarded to a subcall are not included. From Berlin, account and storage accesses
+/// are priced as warm, the cold access surcharge being dynamic.
+///
+/// Returns `None` if the cost is purely dynamic, as for `SSTORE` and `INVALID`, or if the
+/// opcode is not enabled at `spec`.
+pub const fn static_gas_cost(op: OpCode, spec: SpecId) -> Option<u64> {
+    let (enabled_in, cost) = match op.get() {
+        opcode::STOP | opcode::RETURN => (FRONTIER, ZERO),
+        opcode::REVERT => (BYZANTIUM, ZERO),
+        opcode::ADD | opcode::SUB => (FRONTIER, VERYLOW),
+        opcode::MUL
+        | opcode::DIV
+        | opcode::SDIV
+        | opcode::MOD
+        | opcode::SMOD
+        | opcode::SIGNEXTEND => (FRONTIER, LOW),
+        opcode::ADDMOD | opcode::MULMOD => (FRONTIER, MID),
+        opcode::EXP => (FRONTIER, EXP),
+        opcode::LT
+        | opcode::GT
+        | opcode::SLT
+        | opcode::SGT
+        | opcode::EQ
+        | opcode::ISZERO
+        | opcode::AND
+        | opcode::OR
+        | opcode::XOR
+        | opcode::NOT
+        | opcode::BYTE => (FRONTIER, VERYLOW),
+        opcode::SHL | opcode::SHR | opcode::SAR => (CONSTANTINOPLE, VERYLOW),
+        opcode::KECCAK256 => (FRONTIER, KECCAK256),
+        opcode::ADDRESS
+        | opcode::ORIGIN
+        | opcode::CALLER
+        | opcode::CALLVALUE
+        | opcode::CALLDATASIZE
+        | opcode::CODESIZE
+        | opcode::GASPRICE
+        | opcode::COINBASE
+        | opcode::TIMESTAMP
+        | opcode::NUMBER
+        | opcode::DIFFICULTY
+        | opcode::GASLIMIT
+        | opcode::POP
+        | opcode::PC
+        | opcode::MSIZE
+        | opcode::GAS => (FRONTIER, BASE),
+        opcode::RETURNDATASIZE => (BYZANTIUM, BASE),
+        opcode::CHAINID => (ISTANBUL, BASE),
+        opcode::BASEFEE => (LONDON, BASE),
+        opcode::PUSH0 => (SHANGHAI, BASE),
+        opcode::BLOBBASEFEE => (CANCUN, BASE),
+        opcode::CALLDATALOAD
+        | opcode::CALLDATACOPY
+        | opcode::CODECOPY
+        | opcode::MLOAD
+        | opcode::MSTORE
+        | opcode::MSTORE8 => (FRONTIER, VERYLOW),
+        opcode::PUSH1..=opcode::PUSH32 | opcode::DUP1..=opcode::DUP16 => (FRONTIER, VERYLOW),
+        opcode::SWAP1..=opcode::SWAP16 => (FRONTIER, VERYLOW),
+        opcode::RETURNDATACOPY => (BYZANTIUM, VERYLOW),
+        opcode::BLOBHASH | opcode::MCOPY => (CANCUN, VERYLOW),
+        opcode::SELFBALANCE => (ISTANBUL, LOW),
+        opcode::BALANCE => (
+            FRONTIER,
+            if spec.is_enabled_in(BERLIN) {
+                WARM_STORAGE_READ_COST
+            } else if spec.is_enabled_in(ISTANBUL) {
+                700
+            } else if spec.is_enabled_in(TANGERINE) {
+                400
+            } else {
+                20
+            },
+        ),
+        opcode::EXTCODESIZE | opcode::EXTCODECOPY => (
+            FRONTIER,
+            if spec.is_enabled_in(BERLIN) {
+                WARM_STORAGE_READ_COST
+            } else if spec.is_enabled_in(TANGERINE) {
+                700
+            } else {
+                20
+            },
+        ),
+        opcode::EXTCODEHASH => (
+            CONSTANTINOPLE,
+            if spec.is_enabled_in(BERLIN) {
+                WARM_STORAGE_READ_COST
+            } else if spec.is_enabled_in(ISTANBUL) {
+                700
+            } else {
+                400
+            },
+        ),
+        opcode::BLOCKHASH => (FRONTIER, BLOCKHASH),
+        opcode::SLOAD => (FRONTIER, sload_cost(spec, false)),
+        opcode::TLOAD | opcode::TSTORE => (CANCUN, WARM_STORAGE_READ_COST),
+        opcode::JUMP => (FRONTIER, MID),
+        opcode::JUMPI => (FRONTIER, HIGH),
+        opcode::JUMPDEST => (FRONTIER, JUMPDEST),
+        opcode::LOG0..=opcode::LOG4 => (FRONTIER, LOG * (op.get() - opcode::LOG0 + 1) as u64),
+        opcode::CREATE => (FRONTIER, CREATE),
+        opcode::CREATE2 => (PETERSBURG, CREATE),
+        opcode::CALL | opcode::CALLCODE => (FRONTIER, call_base_cost(spec)),
+        opcode::DELEGATECALL => (HOMESTEAD, call_base_cost(spec)),
+        opcode::STATICCALL => (BYZANTIUM, call_base_cost(spec)),
+        opcode::SELFDESTRUCT => (
+            FRONTIER,
+            if spec.is_enabled_in(TANGERINE) {
+                5000
+            } else {
+                0
+            },
+        ),
+        // EOF
+        opcode::DATALOAD => (OSAKA, DATA_LOAD_GAS),
+        opcode::DATALOADN => (OSAKA, DATA_LOADN_GAS),
+        opcode::DATASIZE | opcode::RJUMP => (OSAKA, BASE),
+        opcode::DATACOPY
+        | opcode::DUPN
+        | opcode::SWAPN
+        | opcode::EXCHANGE
+        | opcode::RETURNDATALOAD => (OSAKA, VERYLOW),
+        opcode::RJUMPI | opcode::RJUMPV => (OSAKA, CONDITION_JUMP_GAS),
+        opcode::CALLF | opcode::JUMPF => (OSAKA, LOW),
+        opcode::RETF => (OSAKA, RETF_GAS),
+        opcode::EOFCREATE => (OSAKA, EOF_CREATE_GAS),
+        opcode::RETURNCONTRACT => (OSAKA, ZERO),
+        opcode::EXTCALL | opcode::EXTDELEGATECALL | opcode::EXTSTATICCALL => {
+            (OSAKA, WARM_STORAGE_READ_COST)
+        }
+        // `SSTORE` cost depends on the original and current values of the slot and
+        // `INVALID` consumes all the remaining gas.
+        _ => return None,
+    };
+    if spec.is_enabled_in(enabled_in) {
+        Some(cost)
+    } else {
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn static_costs() {
+        assert_eq!(static_gas_cost(OpCode::ADD, FRONTIER), Some(3));
+        assert_eq!(static_gas_cost(OpCode::ADD, PRAGUE), Some(3));
+
+        assert_eq!(static_gas_cost(OpCode::SLOAD, FRONTIER), Some(50));
+        assert_eq!(static_gas_cost(OpCode::SLOAD, TANGERINE), Some(200));
+        assert_eq!(static_gas_cost(OpCode::SLOAD, ISTANBUL), Some(800));
+        assert_eq!(static_gas_cost(OpCode::SLOAD, BERLIN), Some(100));
+
+        assert_eq!(static_gas_cost(OpCode::BALANCE, FRONTIER), Some(20));
+        assert_eq!(static_gas_cost(OpCode::BALANCE, TANGERINE), Some(400));
+        assert_eq!(static_gas_cost(OpCode::BALANCE, ISTANBUL), Some(700));
+        assert_eq!(static_gas_cost(OpCode::BALANCE, CANCUN), Some(100));
+
+        assert_eq!(static_gas_cost(OpCode::LOG2, CANCUN), Some(3 * LOG));
+    }
+
+    #[test]
+    fn dynamic_or_disabled() {
+        assert_eq!(static_gas_cost(OpCode::SSTORE, CANCUN), None);
+        assert_eq!(static_gas_cost(OpCode::INVALID, CANCUN), None);
+        assert_eq!(static_gas_cost(OpCode::PUSH0, MERGE), None);
+        assert_eq!(static_gas_cost(OpCode::PUSH0, SHANGHAI), Some(2));
+        assert_eq!(static_gas_cost(OpCode::TLOAD, SHANGHAI), None);
+    }
+}
@@ -98,6 +98,10 @@ pub enum InstructionResult {
     EofAuxDataTooSmall,
     /// `EXT*CALL` target address needs to be padded with 0s.
     InvalidEXTCALLTarget,
+    /// Exceeded the maximum number of logs per transaction.
+    LogLimitExceeded,
+    /// Execution was stopped by an inspector breakpoint.
+    Breakpoint,
 }
 
 impl From<TransferError> for InstructionResult {
@@ -156,6 +160,8 @@ impl From<HaltReason> for InstructionResult {
             HaltReason::EofAuxDataTooSmall => Self::EofAuxDataTooSmall,
             HaltReason::SubRoutineStackOverflow => Self::SubRoutineStackOverflow,
             HaltReason::InvalidEXTCALLTarget => Self::InvalidEXTCALLTarget,
+            HaltReason::LogLimitExceeded => Self::LogLimitExceeded,
+            HaltReason::Breakpoint => Self::Breakpoint,
         }
     }
 }
@@ -215,6 +221,8 @@ macro_rules! return_error {
             | $crate::InstructionResult::EofAuxDataTooSmall
             | $crate::InstructionResult::EofAuxDataOverflow
             | $crate::InstructionResult::InvalidEXTCALLTarget
+            | $crate::InstructionResult::LogLimitExceeded
+            | $crate::InstructionResult::Breakpoint
     };
 }
 
@@ -246,6 +254,15 @@ impl InstructionResult {
     pub const fn is_error(self) -> bool {
         matches!(self, return_error!())
     }
+
+    /// Returns whether the result is an exceptional halt of the EVM, see [`SuccessOrHalt::Halt`].
+    ///
+    /// Unlike [`InstructionResult::is_error`], this excludes
+    /// [`InstructionResult::FatalExternalError`], which is a failure of the database.
+    #[inline]
+    pub const fn is_halt(self) -> bool {
+        self.is_error() && !matches!(self, InstructionResult::FatalExternalError)
+    }
 }
 
 /// Internal result that are not ex
@@ -389,6 +406,8 @@ impl<HaltReasonT: HaltReasonTrait> From<InstructionResult> for SuccessOrHalt<Hal
             InstructionResult::InvalidEXTCALLTarget => {
                 Self::Halt(HaltReason::InvalidEXTCALLTarget.into())
             }
+            InstructionResult::LogLimitExceeded => Self::Halt(HaltReason::LogLimitExceeded.into()),
+            InstructionResult::Breakpoint => Self::Halt(HaltReason::Breakpoint.into()),
             InstructionResult::InvalidExtDelegateCallTarget => {
                 Self::Internal(InternalResult::InvalidExtDelegateCallTarget)
             }
@@ -398,7 +417,8 @@ impl<HaltReasonT: HaltReasonTrait> From<InstructionResult> for SuccessOrHalt<Hal
 
 #[cfg(test)]
 mod tests {
-    use crate::InstructionResult;
+    use crate::{InstructionResult, SuccessOrHalt};
+    use context_interface::result::HaltReason;
 
     #[test]
     fn all_results_are_covered() {
@@ -423,6 +443,7 @@ mod tests {
             assert!(result.is_ok());
             assert!(!result.is_revert());
             assert!(!result.is_error());
+            assert!(!result.is_halt());
         }
 
         let revert_results = vec![
@@ -435,6 +456,7 @@ mod tests {
             assert!(!result.is_ok());
             assert!(result.is_revert());
             assert!(!result.is_error());
+            assert!(!result.is_halt());
         }
 
         let error_results = vec![
@@ -459,13 +481,51 @@ mod tests {
             InstructionResult::CreateContractSizeLimit,
             InstructionResult::CreateContractStartingWithEF,
             InstructionResult::CreateInitCodeSizeLimit,
-            InstructionResult::FatalExternalError,
+            InstructionResult::LogLimitExceeded,
+            InstructionResult::Breakpoint,
         ];
 
         for result in error_results {
             assert!(!result.is_ok());
             assert!(!result.is_revert());
             assert!(result.is_error());
+            assert!(result.is_halt());
+        }
+
+        let fatal = InstructionResult::FatalExternalError;
+        assert!(fatal.is_error());
+        assert!(!fatal.is_halt());
+    }
+
+    #[test]
+    fn categories() {
+        // (result, is_ok, is_revert, is_halt, is_error)
+        let cases = [
+            (InstructionResult::Continue, true, false, false, false),
+            (InstructionResult::Stop, true, false, false, false),
+            (InstructionResult::Revert, false, true, false, false),
+            (InstructionResult::OutOfGas, false, false, true, true),
+            (InstructionResult::OpcodeNotFound, false, false, true, true),
+            (InstructionResult::InvalidFEOpcode, false, false, true, true),
+            (InstructionResult::CallOrCreate, false, false, false, false),
+            (
+                InstructionResult::FatalExternalError,
+                false,
+                false,
+                false,
+                true,
+            ),
+        ];
+        for (result, ok, revert, halt, error) in cases {
+            assert_eq!(result.is_ok(), ok, "{result:?}");
+            assert_eq!(result.is_revert(), revert, "{result:?}");
+            assert_eq!(result.is_halt(), halt, "{result:?}");
+            assert_eq!(result.is_error(), error, "{result:?}");
+            assert_eq!(
+                result.is_halt(),
+                SuccessOrHalt::<HaltReason>::from(result).is_halt(),
+                "{result:?}"
+            );
         }
     }
 }
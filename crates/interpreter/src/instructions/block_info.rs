@@ -50,10 +50,7 @@ pub fn difficulty<WIRE: InterpreterTypes, H: Host + ?Sized>(
     gas!(interpreter, gas::BASE);
     if interpreter.runtime_flag.spec_id().is_enabled_in(MERGE) {
         // Unwrap is safe as this fields is checked in validation handler.
-        push!(
-            interpreter,
-            (host.block().prevrandao().unwrap()).into_u256()
-        );
+        push!(interpreter, (host.prevrandao().unwrap()).into_u256());
     } else {
         push!(interpreter, host.block().difficulty());
     }
@@ -188,7 +188,8 @@ pub fn extcall_gas_calc<WIRE: InterpreterTypes, H: Host + ?Sized>(
     };
     // account_load.is_empty will be accounted if there is transfer value
     // Berlin can be hardcoded as extcall came after berlin.
-    let call_cost = gas::call_cost(
+    let call_cost = gas::call_cost_with_base(
+        host.cfg().call_base_cost(),
         interpreter.runtime_flag.spec_id(),
         transfers_value,
         account_load,
@@ -276,6 +277,7 @@ pub fn extcall<WIRE: InterpreterTypes, H: Host + ?Sized>(
         InterpreterAction::NewFrame(FrameInput::Call(Box::new(CallInputs {
             input,
             gas_limit,
+            stipend: 0,
             target_address,
             caller: interpreter.input.target_address(),
             bytecode_address: target_address,
@@ -314,6 +316,7 @@ pub fn extdelegatecall<WIRE: InterpreterTypes, H: Host + ?Sized>(
         InterpreterAction::NewFrame(FrameInput::Call(Box::new(CallInputs {
             input,
             gas_limit,
+            stipend: 0,
             target_address: interpreter.input.target_address(),
             caller: interpreter.input.caller_address(),
             bytecode_address: target_address,
@@ -352,6 +355,7 @@ pub fn extstaticcall<WIRE: InterpreterTypes, H: Host + ?Sized>(
         InterpreterAction::NewFrame(FrameInput::Call(Box::new(CallInputs {
             input,
             gas_limit,
+            stipend: 0,
             target_address,
             caller: interpreter.input.target_address(),
             bytecode_address: target_address,
@@ -407,10 +411,13 @@ pub fn create<WIRE: InterpreterTypes, const IS_CREATE2: bool, H: Host + ?Sized>(
     let scheme = if IS_CREATE2 {
         popn!([salt], interpreter);
         // SAFETY: `len` is reasonable in size as gas for it is already deducted.
-        gas_or_fail!(interpreter, gas::create2_cost(len));
+        gas_or_fail!(
+            interpreter,
+            gas::create2_cost_with_base(host.cfg().create_base_cost(), len)
+        );
         CreateScheme::Create2 { salt }
     } else {
-        gas!(interpreter, gas::CREATE);
+        gas!(interpreter, host.cfg().create_base_cost());
         CreateScheme::Create
     };
 
@@ -421,6 +428,7 @@ pub fn create<WIRE: InterpreterTypes, const IS_CREATE2: bool, H: Host + ?Sized>(
         .runtime_flag
         .spec_id()
         .is_enabled_in(SpecId::TANGERINE)
+        && !host.cfg().is_call_gas_retention_disabled()
     {
         // Take remaining gas and deduce l64 part of it.
         gas_limit -= gas_limit / 64
@@ -467,24 +475,29 @@ pub fn call<WIRE: InterpreterTypes, H: Host + ?Sized>(
             .set_instruction_result(InstructionResult::FatalExternalError);
         return;
     };
-    let Some(mut gas_limit) =
-        calc_call_gas(interpreter, account_load, has_transfer, local_gas_limit)
-    else {
+    let Some(mut gas_limit) = calc_call_gas(
+        interpreter,
+        account_load,
+        has_transfer,
+        local_gas_limit,
+        host.cfg().call_base_cost(),
+        host.cfg().is_call_gas_retention_disabled(),
+    ) else {
         return;
     };
 
     gas!(interpreter, gas_limit);
 
     // Add call stipend if there is value to be transferred.
-    if has_transfer {
-        gas_limit = gas_limit.saturating_add(gas::CALL_STIPEND);
-    }
+    let stipend = if has_transfer { gas::CALL_STIPEND } else { 0 };
+    gas_limit = gas_limit.saturating_add(stipend);
 
     // Call host to interact with target contract
     interpreter.control.set_next_action(
         InterpreterAction::NewFrame(FrameInput::Call(Box::new(CallInputs {
             input,
             gas_limit,
+            stipend,
             target_address: to,
             caller: interpreter.input.target_address(),
             bytecode_address: to,
@@ -520,23 +533,33 @@ pub fn call_code<WIRE: InterpreterTypes, H: Host + ?Sized>(
     };
     // Set `is_empty` to false as we are not creating this account.
     load.is_empty = false;
-    let Some(mut gas_limit) = calc_call_gas(interpreter, load, !value.is_zero(), local_gas_limit)
-    else {
+    let Some(mut gas_limit) = calc_call_gas(
+        interpreter,
+        load,
+        !value.is_zero(),
+        local_gas_limit,
+        host.cfg().call_base_cost(),
+        host.cfg().is_call_gas_retention_disabled(),
+    ) else {
         return;
     };
 
     gas!(interpreter, gas_limit);
 
     // Add call stipend if there is value to be transferred.
-    if !value.is_zero() {
-        gas_limit = gas_limit.saturating_add(gas::CALL_STIPEND);
-    }
+    let stipend = if value.is_zero() {
+        0
+    } else {
+        gas::CALL_STIPEND
+    };
+    gas_limit = gas_limit.saturating_add(stipend);
 
     // Call host to interact with target contract
     interpreter.control.set_next_action(
         InterpreterAction::NewFrame(FrameInput::Call(Box::new(CallInputs {
             input,
             gas_limit,
+            stipend,
             target_address: interpreter.input.target_address(),
             caller: interpreter.input.target_address(),
             bytecode_address: to,
@@ -572,7 +595,14 @@ pub fn delegate_call<WIRE: InterpreterTypes, H: Host + ?Sized>(
     };
     // Set is_empty to false as we are not creating this account.
     load.is_empty = false;
-    let Some(gas_limit) = calc_call_gas(interpreter, load, false, local_gas_limit) else {
+    let Some(gas_limit) = calc_call_gas(
+        interpreter,
+        load,
+        false,
+        local_gas_limit,
+        host.cfg().call_base_cost(),
+        host.cfg().is_call_gas_retention_disabled(),
+    ) else {
         return;
     };
 
@@ -583,6 +613,7 @@ pub fn delegate_call<WIRE: InterpreterTypes, H: Host + ?Sized>(
         InterpreterAction::NewFrame(FrameInput::Call(Box::new(CallInputs {
             input,
             gas_limit,
+            stipend: 0,
             target_address: interpreter.input.target_address(),
             caller: interpreter.input.caller_address(),
             bytecode_address: to,
@@ -618,7 +649,14 @@ pub fn static_call<WIRE: InterpreterTypes, H: Host + ?Sized>(
     };
     // Set `is_empty` to false as we are not creating this account.
     load.is_empty = false;
-    let Some(gas_limit) = calc_call_gas(interpreter, load, false, local_gas_limit) else {
+    let Some(gas_limit) = calc_call_gas(
+        interpreter,
+        load,
+        false,
+        local_gas_limit,
+        host.cfg().call_base_cost(),
+        host.cfg().is_call_gas_retention_disabled(),
+    ) else {
         return;
     };
     gas!(interpreter, gas_limit);
@@ -628,6 +666,7 @@ pub fn static_call<WIRE: InterpreterTypes, H: Host + ?Sized>(
         InterpreterAction::NewFrame(FrameInput::Call(Box::new(CallInputs {
             input,
             gas_limit,
+            stipend: 0,
             target_address: to,
             caller: interpreter.input.target_address(),
             bytecode_address: to,
@@ -44,14 +44,23 @@ pub fn resize_memory(
     Some(offset..offset + len)
 }
 
+/// Charges the call cost and returns the gas limit forwarded to the callee.
+///
+/// `base_cost` replaces the spec defined [`gas::call_base_cost`].
+///
+/// If `disable_gas_retention` is set, the EIP-150 rule retaining 1/64 of the remaining gas is
+/// skipped and all requested gas is forwarded, as before Tangerine Whistle.
 #[inline]
 pub fn calc_call_gas(
     interpreter: &mut Interpreter<impl InterpreterTypes>,
     account_load: AccountLoad,
     has_transfer: bool,
     local_gas_limit: u64,
+    base_cost: u64,
+    disable_gas_retention: bool,
 ) -> Option<u64> {
-    let call_cost = gas::call_cost(
+    let call_cost = gas::call_cost_with_base(
+        base_cost,
         interpreter.runtime_flag.spec_id(),
         has_transfer,
         account_load,
@@ -59,15 +68,16 @@ pub fn calc_call_gas(
     gas!(interpreter, call_cost, None);
 
     // EIP-150: Gas cost changes for IO-heavy operations
-    let gas_limit = if interpreter.runtime_flag.spec_id().is_enabled_in(TANGERINE) {
-        // Take l64 part of gas_limit
-        min(
-            interpreter.control.gas().remaining_63_of_64_parts(),
-            local_gas_limit,
-        )
-    } else {
-        local_gas_limit
-    };
+    let gas_limit =
+        if interpreter.runtime_flag.spec_id().is_enabled_in(TANGERINE) && !disable_gas_retention {
+            // Take l64 part of gas_limit
+            min(
+                interpreter.control.gas().remaining_63_of_64_parts(),
+                local_gas_limit,
+            )
+        } else {
+            local_gas_limit
+        };
 
     Some(gas_limit)
 }
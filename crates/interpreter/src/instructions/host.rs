@@ -7,6 +7,7 @@ use crate::{
     },
     Host, InstructionResult,
 };
+use context_interface::Cfg;
 use core::cmp::min;
 use primitives::{Bytes, Log, LogData, B256, U256};
 use specification::hardfork::SpecId::*;
@@ -47,13 +48,13 @@ pub fn selfbalance<WIRE: InterpreterTypes, H: Host + ?Sized>(
 ) {
     check!(interpreter, ISTANBUL);
     gas!(interpreter, gas::LOW);
-    let Some(balance) = host.balance(interpreter.input.target_address()) else {
+    let Some(balance) = host.self_balance(interpreter.input.target_address()) else {
         interpreter
             .control
             .set_instruction_result(InstructionResult::FatalExternalError);
         return;
     };
-    push!(interpreter, balance.data);
+    push!(interpreter, balance);
 }
 
 pub fn extcodesize<WIRE: InterpreterTypes, H: Host + ?Sized>(
@@ -269,6 +270,16 @@ pub fn log<const N: usize, H: Host + ?Sized>(
             .set_instruction_result(InstructionResult::StackUnderflow);
         return;
     };
+    if host
+        .cfg()
+        .max_logs_per_tx()
+        .is_some_and(|max| host.log_count() >= max)
+    {
+        interpreter
+            .control
+            .set_instruction_result(InstructionResult::LogLimitExceeded);
+        return;
+    }
 
     let log = Log {
         address: interpreter.input.target_address(),
@@ -150,6 +150,14 @@ impl<IW: InterpreterTypes, H: Host> CustomInstruction for Instruction<IW, H> {
 }
 
 impl<IW: InterpreterTypes> Interpreter<IW> {
+    /// Returns the return data of the last call or create made by this frame.
+    ///
+    /// It is empty when the frame starts.
+    #[inline]
+    pub fn return_data_slice(&self) -> &[u8] {
+        self.return_data.buffer()
+    }
+
     /// Executes the instruction at the current instruction pointer.
     ///
     /// Internally it will increment instruction pointer by one.
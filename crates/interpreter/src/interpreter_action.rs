@@ -4,8 +4,9 @@ mod create_inputs;
 mod create_outcome;
 mod eof_create_inputs;
 
-pub use call_inputs::{CallInputs, CallScheme, CallValue};
+pub use call_inputs::{CallInputs, CallInputsBuilder, CallInputsError, CallValue};
 pub use call_outcome::CallOutcome;
+pub use context_interface::CallScheme;
 pub use create_inputs::CreateInputs;
 pub use create_outcome::CreateOutcome;
 pub use eof_create_inputs::{EOFCreateInputs, EOFCreateKind};
@@ -1,4 +1,6 @@
-use core::ops::Range;
+use crate::gas::CALL_STIPEND;
+use context_interface::CallScheme;
+use core::{fmt, ops::Range};
 use primitives::{Address, Bytes, U256};
 
 /// Inputs for a call.
@@ -13,6 +15,11 @@ pub struct CallInputs {
     pub return_memory_offset: Range<usize>,
     /// The gas limit of the call.
     pub gas_limit: u64,
+    /// The gas stipend included in `gas_limit`.
+    ///
+    /// This is [`CALL_STIPEND`][crate::gas::CALL_STIPEND] for `CALL` and `CALLCODE` that transfer
+    /// value, and zero otherwise.
+    pub stipend: u64,
     /// The account address of bytecode that is going to be executed.
     ///
     /// Previously `context.code_address`.
@@ -42,6 +49,12 @@ pub struct CallInputs {
 }
 
 impl CallInputs {
+    /// Returns a new [`CallInputsBuilder`].
+    #[inline]
+    pub fn builder() -> CallInputsBuilder {
+        CallInputsBuilder::default()
+    }
+
     /// Returns `true` if the call will transfer a non-zero value.
     #[inline]
     pub fn transfers_value(&self) -> bool {
@@ -89,38 +102,155 @@ impl CallInputs {
     }
 }
 
-/// Call scheme.
-#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
-#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
-pub enum CallScheme {
-    /// `CALL`.
-    Call,
-    /// `CALLCODE`
-    CallCode,
-    /// `DELEGATECALL`
-    DelegateCall,
-    /// `STATICCALL`
-    StaticCall,
-    /// `EXTCALL`
-    ExtCall,
-    /// `EXTSTATICCALL`
-    ExtStaticCall,
-    /// `EXTDELEGATECALL`
-    ExtDelegateCall,
+/// Builder for [`CallInputs`].
+///
+/// Fields that are implied by the [`CallScheme`] are derived on [`build`][Self::build]:
+///
+/// - `STATICCALL` and `EXTSTATICCALL` are always static and never transfer value.
+/// - `DELEGATECALL` and `EXTDELEGATECALL` carry an apparent value.
+/// - `CALL` and `CALLCODE` that transfer value add [`CALL_STIPEND`] to the gas limit.
+/// - `EXT*CALL` schemes are marked as EOF calls.
+/// - The bytecode address defaults to the target address.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct CallInputsBuilder {
+    input: Bytes,
+    return_memory_offset: Range<usize>,
+    gas_limit: u64,
+    bytecode_address: Option<Address>,
+    target_address: Address,
+    caller: Address,
+    value: U256,
+    scheme: CallScheme,
+    is_static: bool,
 }
 
-impl CallScheme {
-    /// Returns true if it is EOF EXT*CALL.
-    pub fn is_ext(&self) -> bool {
-        matches!(
-            self,
-            Self::ExtCall | Self::ExtStaticCall | Self::ExtDelegateCall
-        )
+impl Default for CallInputsBuilder {
+    fn default() -> Self {
+        Self {
+            input: Bytes::new(),
+            return_memory_offset: 0..0,
+            gas_limit: 0,
+            bytecode_address: None,
+            target_address: Address::ZERO,
+            caller: Address::ZERO,
+            value: U256::ZERO,
+            scheme: CallScheme::Call,
+            is_static: false,
+        }
+    }
+}
+
+impl CallInputsBuilder {
+    /// Sets the call data.
+    pub fn input(mut self, input: Bytes) -> Self {
+        self.input = input;
+        self
+    }
+
+    /// Sets the return memory offset.
+    pub fn return_memory_offset(mut self, return_memory_offset: Range<usize>) -> Self {
+        self.return_memory_offset = return_memory_offset;
+        self
+    }
+
+    /// Sets the gas forwarded to the call, excluding the stipend. Defaults to zero.
+    pub fn gas_limit(mut self, gas_limit: u64) -> Self {
+        self.gas_limit = gas_limit;
+        self
+    }
+
+    /// Sets the address of the bytecode that is going to be executed.
+    ///
+    /// Defaults to the target address.
+    pub fn bytecode_address(mut self, bytecode_address: Address) -> Self {
+        self.bytecode_address = Some(bytecode_address);
+        self
+    }
+
+    /// Sets the target address.
+    pub fn target_address(mut self, target_address: Address) -> Self {
+        self.target_address = target_address;
+        self
+    }
+
+    /// Sets the caller.
+    pub fn caller(mut self, caller: Address) -> Self {
+        self.caller = caller;
+        self
+    }
+
+    /// Sets the call value.
+    pub fn value(mut self, value: U256) -> Self {
+        self.value = value;
+        self
+    }
+
+    /// Sets the call scheme. Defaults to [`CallScheme::Call`].
+    pub fn scheme(mut self, scheme: CallScheme) -> Self {
+        self.scheme = scheme;
+        self
     }
 
-    /// Returns true if it is ExtDelegateCall.
-    pub fn is_ext_delegate_call(&self) -> bool {
-        matches!(self, Self::ExtDelegateCall)
+    /// Sets whether the call is initiated inside a static call.
+    pub fn is_static(mut self, is_static: bool) -> Self {
+        self.is_static = is_static;
+        self
+    }
+
+    /// Builds the [`CallInputs`].
+    ///
+    /// Returns an error if a value transfer is requested inside a static context.
+    pub fn build(self) -> Result<CallInputs, CallInputsError> {
+        let (value, is_static) = match self.scheme {
+            CallScheme::StaticCall | CallScheme::ExtStaticCall => {
+                (CallValue::Transfer(U256::ZERO), true)
+            }
+            CallScheme::DelegateCall | CallScheme::ExtDelegateCall => {
+                (CallValue::Apparent(self.value), self.is_static)
+            }
+            CallScheme::Call | CallScheme::CallCode | CallScheme::ExtCall => {
+                if self.is_static && !self.value.is_zero() {
+                    return Err(CallInputsError::ValueTransferInStaticCall);
+                }
+                (CallValue::Transfer(self.value), self.is_static)
+            }
+        };
+
+        let stipend = match self.scheme {
+            CallScheme::Call | CallScheme::CallCode if !self.value.is_zero() => CALL_STIPEND,
+            _ => 0,
+        };
+
+        Ok(CallInputs {
+            input: self.input,
+            return_memory_offset: self.return_memory_offset,
+            gas_limit: self.gas_limit.saturating_add(stipend),
+            stipend,
+            bytecode_address: self.bytecode_address.unwrap_or(self.target_address),
+            target_address: self.target_address,
+            caller: self.caller,
+            value,
+            scheme: self.scheme,
+            is_static,
+            is_eof: self.scheme.is_ext(),
+        })
+    }
+}
+
+/// Errors returned by [`CallInputsBuilder::build`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum CallInputsError {
+    /// Non-zero value transfer inside a static call.
+    ValueTransferInStaticCall,
+}
+
+impl core::error::Error for CallInputsError {}
+
+impl fmt::Display for CallInputsError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::ValueTransferInStaticCall => write!(f, "value transfer inside a static call"),
+        }
     }
 }
 
@@ -182,3 +312,72 @@ impl CallValue {
         matches!(self, Self::Apparent(_))
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn build_static_call() {
+        let target = Address::with_last_byte(2);
+        let inputs = CallInputs::builder()
+            .caller(Address::with_last_byte(1))
+            .target_address(target)
+            .value(U256::from(10))
+            .gas_limit(100_000)
+            .scheme(CallScheme::StaticCall)
+            .build()
+            .unwrap();
+
+        assert_eq!(inputs.value, CallValue::Transfer(U256::ZERO));
+        assert!(inputs.is_static);
+        assert!(!inputs.is_eof);
+        assert_eq!(inputs.bytecode_address, target);
+        assert_eq!(inputs.gas_limit, 100_000);
+        assert_eq!(inputs.stipend, 0);
+    }
+
+    #[test]
+    fn build_stipend() {
+        let build = |scheme, value| {
+            let inputs = CallInputs::builder()
+                .value(U256::from(value))
+                .gas_limit(100_000)
+                .scheme(scheme)
+                .build()
+                .unwrap();
+            (inputs.gas_limit, inputs.stipend)
+        };
+
+        let with_stipend = (100_000 + CALL_STIPEND, CALL_STIPEND);
+        assert_eq!(build(CallScheme::Call, 1), with_stipend);
+        assert_eq!(build(CallScheme::CallCode, 1), with_stipend);
+        assert_eq!(build(CallScheme::Call, 0), (100_000, 0));
+        assert_eq!(build(CallScheme::DelegateCall, 1), (100_000, 0));
+        assert_eq!(build(CallScheme::ExtCall, 1), (100_000, 0));
+    }
+
+    #[test]
+    fn build_delegate_call() {
+        let inputs = CallInputs::builder()
+            .target_address(Address::with_last_byte(1))
+            .bytecode_address(Address::with_last_byte(2))
+            .value(U256::from(10))
+            .scheme(CallScheme::DelegateCall)
+            .build()
+            .unwrap();
+
+        assert_eq!(inputs.value, CallValue::Apparent(U256::from(10)));
+        assert_eq!(inputs.bytecode_address, Address::with_last_byte(2));
+        assert!(!inputs.transfers_value());
+    }
+
+    #[test]
+    fn build_value_transfer_in_static_context() {
+        let result = CallInputs::builder()
+            .value(U256::from(1))
+            .is_static(true)
+            .build();
+        assert_eq!(result, Err(CallInputsError::ValueTransferInStaticCall));
+    }
+}
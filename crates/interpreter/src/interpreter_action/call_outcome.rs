@@ -22,6 +22,7 @@ impl CallOutcome {
     /// Constructs a new [`CallOutcome`].
     ///
     /// Creates an instance of [`CallOutcome`] with the given interpreter result and memory offset.
+    /// The refunded gas is taken from the gas of the result.
     ///
     /// # Arguments
     ///
@@ -88,4 +89,27 @@ impl CallOutcome {
     pub fn memory_length(&self) -> usize {
         self.memory_offset.len()
     }
+
+    /// Returns the gas limit of the callee, including the call stipend.
+    pub fn gas_forwarded(&self) -> u64 {
+        self.result.gas.limit()
+    }
+
+    /// Returns the gas spent by the callee, before refunds.
+    pub fn gas_consumed(&self) -> u64 {
+        self.result.gas.spent()
+    }
+
+    /// Returns the gas refund accumulated by the callee, including its successful subcalls.
+    pub fn gas_refund(&self) -> i64 {
+        self.result.gas.refunded()
+    }
+
+    /// Returns the forwarded gas that was not consumed by the callee.
+    ///
+    /// A large value relative to [`Self::gas_forwarded`] means the caller forwarded much
+    /// more gas than needed.
+    pub fn unused_gas(&self) -> u64 {
+        self.gas_forwarded().saturating_sub(self.gas_consumed())
+    }
 }
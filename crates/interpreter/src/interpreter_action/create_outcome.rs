@@ -72,4 +72,11 @@ impl CreateOutcome {
     pub fn gas(&self) -> &Gas {
         &self.result.gas
     }
+
+    /// Returns the gas refund accumulated by the frame, including its successful subcalls.
+    ///
+    /// It is only added to the refund of the caller if the frame succeeded.
+    pub fn gas_refund(&self) -> i64 {
+        self.result.gas.refunded()
+    }
 }
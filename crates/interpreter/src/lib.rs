@@ -27,7 +27,10 @@ pub mod table;
 
 // Reexport primary types.
 pub use context_interface::{
-    host::{DummyHost, Host, SStoreResult, SelfDestructResult, StateLoad},
+    host::{
+        DummyHost, EmptyAccountCallResult, FrameInfo, Host, SStoreResult, SelfDestructResult,
+        StateLoad,
+    },
     CreateScheme,
 };
 pub use gas::Gas;
@@ -37,8 +40,8 @@ pub use interpreter::{
     EMPTY_SHARED_MEMORY, STACK_LIMIT,
 };
 pub use interpreter_action::{
-    CallInputs, CallOutcome, CallScheme, CallValue, CreateInputs, CreateOutcome, EOFCreateInputs,
-    EOFCreateKind, FrameInput, InterpreterAction,
+    CallInputs, CallInputsBuilder, CallInputsError, CallOutcome, CallScheme, CallValue,
+    CreateInputs, CreateOutcome, EOFCreateInputs, EOFCreateKind, FrameInput, InterpreterAction,
 };
 pub use interpreter_types::InterpreterTypes;
 pub use specification::constants::{MAX_CODE_SIZE, MAX_INITCODE_SIZE};
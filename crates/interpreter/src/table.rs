@@ -3,8 +3,8 @@
 use crate::{
     instructions::{control, instruction},
     interpreter::Interpreter,
-    interpreter_types::InterpreterTypes,
-    Host,
+    interpreter_types::{InterpreterTypes, LoopControl},
+    Host, InstructionResult,
 };
 use std::boxed::Box;
 
@@ -126,6 +126,90 @@ pub const fn make_instruction_table<WIRE: InterpreterTypes, H: Host + ?Sized>(
     }
 }
 
+/// Instruction that charges a static gas cost before it is executed.
+///
+/// Used for custom opcodes, whose function does not charge gas itself.
+pub struct GasInstruction<W: InterpreterTypes, H: ?Sized> {
+    /// Function executing the instruction.
+    pub instruction: Instruction<W, H>,
+    /// Gas charged before the instruction is executed.
+    pub gas: u64,
+}
+
+impl<W: InterpreterTypes, H: ?Sized> Clone for GasInstruction<W, H> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<W: InterpreterTypes, H: ?Sized> Copy for GasInstruction<W, H> {}
+
+impl<W: InterpreterTypes, H: Host> CustomInstruction for GasInstruction<W, H> {
+    type Wire = W;
+    type Host = H;
+
+    #[inline]
+    fn exec(&self, interpreter: &mut Interpreter<Self::Wire>, host: &mut Self::Host) {
+        if self.gas != 0 && !interpreter.control.gas().record_cost(self.gas) {
+            interpreter
+                .control
+                .set_instruction_result(InstructionResult::OutOfGas);
+            return;
+        }
+        (self.instruction)(interpreter, host);
+    }
+
+    #[inline]
+    fn from_base(instruction: Instruction<Self::Wire, Self::Host>) -> Self {
+        Self {
+            instruction,
+            gas: 0,
+        }
+    }
+}
+
+/// Builder of an instruction table with custom opcodes.
+///
+/// It starts from the mainnet instruction table, where instructions charge their own gas.
+/// The built table can be run by [`Interpreter::run`].
+pub struct InstructionTableBuilder<W: InterpreterTypes, H: ?Sized> {
+    table: CustomInstructionTable<GasInstruction<W, H>>,
+}
+
+impl<W: InterpreterTypes, H: Host> Default for InstructionTableBuilder<W, H> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<W: InterpreterTypes, H: Host> InstructionTableBuilder<W, H> {
+    /// Creates a new builder with the mainnet instructions.
+    pub fn new() -> Self {
+        Self {
+            table: make_custom_instruction_table(
+                &make_instruction_table::<W, H>(),
+                GasInstruction::from_base,
+            ),
+        }
+    }
+
+    /// Sets the instruction of `opcode`, charging `gas` before it is executed.
+    pub fn with_instruction(
+        mut self,
+        opcode: u8,
+        instruction: Instruction<W, H>,
+        gas: u64,
+    ) -> Self {
+        self.table[opcode as usize] = GasInstruction { instruction, gas };
+        self
+    }
+
+    /// Returns the instruction table.
+    pub fn build(self) -> CustomInstructionTable<GasInstruction<W, H>> {
+        self.table
+    }
+}
+
 /// Make boxed instruction table that calls `f` closure for every instruction.
 #[inline]
 pub fn make_custom_instruction_table<W, H, FN, CI: CustomInstruction<Wire = W, Host = H>>(
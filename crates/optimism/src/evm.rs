@@ -4,7 +4,7 @@ use crate::{
     },
     L1BlockInfo, OpSpec, OpTransaction,
 };
-use inspector::{InspectorContext, InspectorEthFrame};
+use inspector::{InspectorContext, InspectorEthFrame, InspectorExecution};
 use revm::{
     context::{block::BlockEnv, tx::TxEnv, CfgEnv, Context},
     context_interface::result::{EVMError, InvalidTransaction},
@@ -35,13 +35,15 @@ pub type InspectorOpEvm<DB, INSP> = Evm<
         OpError<DB>,
         OpValidation<InspCtxType<INSP, DB>, OpError<DB>>,
         OpPreExecution<InspCtxType<INSP, DB>, OpError<DB>>,
-        OpExecution<
-            InspCtxType<INSP, DB>,
-            OpError<DB>,
-            InspectorEthFrame<
+        InspectorExecution<
+            OpExecution<
                 InspCtxType<INSP, DB>,
                 OpError<DB>,
-                OpPrecompileProvider<InspCtxType<INSP, DB>, OpError<DB>>,
+                InspectorEthFrame<
+                    InspCtxType<INSP, DB>,
+                    OpError<DB>,
+                    OpPrecompileProvider<InspCtxType<INSP, DB>, OpError<DB>>,
+                >,
             >,
         >,
     >,
@@ -31,7 +31,6 @@ use revm::{
         FrameInput, Gas,
     },
     primitives::{hash_map::HashMap, U256},
-    specification::hardfork::SpecId,
     state::Account,
     Database,
 };
@@ -312,7 +311,7 @@ where
         if !is_gas_refund_disabled {
             exec_result
                 .gas_mut()
-                .set_final_refund(context.cfg().spec().is_enabled_in(SpecId::LONDON));
+                .set_final_refund_with_quotient(context.cfg().max_refund_quotient());
         }
     }
 
@@ -16,7 +16,16 @@ pub const IDENTITY_PER_WORD: u64 = 3;
 ///
 /// See: <https://etherscan.io/address/0000000000000000000000000000000000000004>
 pub fn identity_run(input: &Bytes, gas_limit: u64) -> PrecompileResult {
-    let gas_used = calc_linear_cost_u32(input.len(), IDENTITY_BASE, IDENTITY_PER_WORD);
+    identity_run_with_per_word_cost(input, gas_limit, IDENTITY_PER_WORD)
+}
+
+/// Same as [`identity_run`] but charges `per_word` gas per word instead of [`IDENTITY_PER_WORD`].
+pub fn identity_run_with_per_word_cost(
+    input: &Bytes,
+    gas_limit: u64,
+    per_word: u64,
+) -> PrecompileResult {
+    let gas_used = calc_linear_cost_u32(input.len(), IDENTITY_BASE, per_word);
     if gas_used > gas_limit {
         return Err(PrecompileError::OutOfGas.into());
     }
@@ -72,6 +72,8 @@ pub enum PrecompileError {
     BlobMismatchedVersion,
     /// The proof verification failed
     BlobVerifyKzgProofFailed,
+    /// The input is larger than the configured maximum input size of the precompile
+    InputTooLarge,
     /// Catch-all variant for other errors
     Other(String),
 }
@@ -111,6 +113,7 @@ impl fmt::Display for PrecompileError {
             Self::BlobInvalidInputLength => "invalid blob input length",
             Self::BlobMismatchedVersion => "mismatched blob version",
             Self::BlobVerifyKzgProofFailed => "verifying blob kzg proof failed",
+            Self::InputTooLarge => "precompile input too large",
             Self::Other(s) => s,
         };
         f.write_str(s)
@@ -59,6 +59,7 @@ impl Precompiles {
             PrecompileSpecId::BERLIN => Self::berlin(),
             PrecompileSpecId::CANCUN => Self::cancun(),
             PrecompileSpecId::PRAGUE => Self::prague(),
+            PrecompileSpecId::OSAKA => Self::osaka(),
             PrecompileSpecId::LATEST => Self::latest(),
         }
     }
@@ -176,9 +177,20 @@ impl Precompiles {
         })
     }
 
+    /// Returns precompiles for Osaka spec.
+    pub fn osaka() -> &'static Self {
+        static INSTANCE: OnceBox<Precompiles> = OnceBox::new();
+        INSTANCE.get_or_init(|| {
+            let mut precompiles = Self::prague().clone();
+            // EIP-7883: ModExp gas cost increase
+            precompiles.extend([modexp::OSAKA]);
+            Box::new(precompiles)
+        })
+    }
+
     /// Returns the precompiles for the latest spec.
     pub fn latest() -> &'static Self {
-        Self::prague()
+        Self::osaka()
     }
 
     /// Returns an iterator over the precompiles addresses.
@@ -274,6 +286,7 @@ pub enum PrecompileSpecId {
     BERLIN,
     CANCUN,
     PRAGUE,
+    OSAKA,
     LATEST,
 }
 
@@ -295,7 +308,8 @@ impl PrecompileSpecId {
             ISTANBUL | MUIR_GLACIER => Self::ISTANBUL,
             BERLIN | LONDON | ARROW_GLACIER | GRAY_GLACIER | MERGE | SHANGHAI => Self::BERLIN,
             CANCUN => Self::CANCUN,
-            PRAGUE | OSAKA => Self::PRAGUE,
+            PRAGUE => Self::PRAGUE,
+            OSAKA => Self::OSAKA,
             LATEST => Self::LATEST,
         }
     }
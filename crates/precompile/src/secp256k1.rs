@@ -8,7 +8,8 @@ use crate::{
     utilities::right_pad, PrecompileError, PrecompileOutput, PrecompileResult,
     PrecompileWithAddress,
 };
-use primitives::{alloy_primitives::B512, Bytes, B256};
+use primitives::{alloy_primitives::B512, Address, Bytes, B256};
+use std::vec::Vec;
 
 pub const ECRECOVER: PrecompileWithAddress =
     PrecompileWithAddress(crate::u64_to_address(1), ec_recover_run);
@@ -31,16 +32,102 @@ pub fn ec_recover_run(input: &Bytes, gas_limit: u64) -> PrecompileResult {
     let recid = input[63] - 27;
     let sig = <&B512>::try_from(&input[64..128]).unwrap();
 
+    let out = ecrecover(sig, recid, msg)
+        .map(|o| o.to_vec().into())
+        .unwrap_or_default();
+    Ok(PrecompileOutput::new(ECRECOVER_BASE, out))
+}
+
+/// Recovers the signer of every `(message hash, signature)` pair.
+///
+/// Signatures are encoded as `r || s || v`, where `v` is either the recovery id (`0` or `1`)
+/// or the recovery id offset by 27. Invalid signatures recover to `None`.
+///
+/// Uses the same backend as the [`ECRECOVER`] precompile and charges no gas.
+pub fn batch_ecrecover(msgs: &[(B256, [u8; 65])]) -> Vec<Option<Address>> {
+    msgs.iter()
+        .map(|(msg, sig)| {
+            let recid = match sig[64] {
+                v @ (0 | 1) => v,
+                v @ (27 | 28) => v - 27,
+                _ => return None,
+            };
+            let sig = <&B512>::try_from(&sig[..64]).unwrap();
+            ecrecover(sig, recid, msg).map(|out| Address::from_slice(&out[12..]))
+        })
+        .collect()
+}
+
+/// Recovers the left padded address of the signer with the enabled backend.
+fn ecrecover(sig: &B512, recid: u8, msg: &B256) -> Option<B256> {
     cfg_if::cfg_if! {
         if #[cfg(feature = "secp256k1")] {
-            let res = bitcoin_secp256k1::ecrecover(sig, recid, msg);
+            bitcoin_secp256k1::ecrecover(sig, recid, msg).ok()
         } else if #[cfg(feature = "libsecp256k1")] {
-            let res = parity_libsecp256k1::ecrecover(sig, recid, msg);
+            parity_libsecp256k1::ecrecover(sig, recid, msg).ok()
         } else {
-            let res = k256::ecrecover(sig, recid, msg);
+            k256::ecrecover(sig, recid, msg).ok()
         }
-    };
+    }
+}
 
-    let out = res.map(|o| o.to_vec().into()).unwrap_or_default();
-    Ok(PrecompileOutput::new(ECRECOVER_BASE, out))
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use primitives::{address, b256, hex};
+
+    #[test]
+    fn test_batch_ecrecover() {
+        let msgs = [
+            (
+                b256!("456e9aea5e197a1f1af7a3e85a3212fa4049a3ba34c2289b4c860fc0b0c64ef3"),
+                hex!("9242685bf161793cc25603c231bc2f568eb630ea16aa137d2664ac80388256084f8ae3bd7535248d0bd448298cc2e2071e56992d0774dc340c368ae950852ada1c"),
+            ),
+            // Signed by the private key `1`, with `v` as recovery id.
+            (
+                b256!("692e3fbb06193c3a65b6ccb60c9ec6fb32af21c16d3f6ac10039258c2a5d4d2d"),
+                hex!("f01d6b9018ab421dd410404cb869072065522bf85734008f105cf385a023a80f6e24665a06a8bbb90b6e4bd63702c786ce94556c0868dd73bf516fdc9e86bf4a01"),
+            ),
+            // Invalid `v`.
+            (
+                b256!("692e3fbb06193c3a65b6ccb60c9ec6fb32af21c16d3f6ac10039258c2a5d4d2d"),
+                hex!("f01d6b9018ab421dd410404cb869072065522bf85734008f105cf385a023a80f6e24665a06a8bbb90b6e4bd63702c786ce94556c0868dd73bf516fdc9e86bf4a1d"),
+            ),
+            // Zero `r` and `s`.
+            (
+                b256!("692e3fbb06193c3a65b6ccb60c9ec6fb32af21c16d3f6ac10039258c2a5d4d2d"),
+                [0; 65],
+            ),
+            // Signed by the private key `2`.
+            (
+                b256!("45318970bfff215a328f56895f3a97d4f276a44c24c135c12c37867a1f667b8a"),
+                hex!("54006a630e70fca8d45a696d7dba3a1cb7d81cc830cfce3be0f634373aac3f0a7337791ac605f9ecf5c01acdb1f5f31917a7c7870ab09c66c46b07de4747901c1b"),
+            ),
+        ];
+
+        assert_eq!(
+            batch_ecrecover(&msgs),
+            [
+                Some(address!("7156526fbd7a3c72969b54f64e42c10fbb768c8a")),
+                Some(address!("7e5f4552091a69125d5dfcb7b8c2659029395bdf")),
+                None,
+                None,
+                Some(address!("2b5ad5c4795c026514f8317c7a215e218dccd6cf")),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_batch_ecrecover_matches_precompile() {
+        let msg = b256!("456e9aea5e197a1f1af7a3e85a3212fa4049a3ba34c2289b4c860fc0b0c64ef3");
+        let sig = hex!("9242685bf161793cc25603c231bc2f568eb630ea16aa137d2664ac80388256084f8ae3bd7535248d0bd448298cc2e2071e56992d0774dc340c368ae950852ada1c");
+
+        let mut input = msg.to_vec();
+        input.extend_from_slice(&B256::with_last_byte(sig[64]).0);
+        input.extend_from_slice(&sig[..64]);
+        let output = ec_recover_run(&input.into(), 3_000).unwrap();
+
+        let recovered = batch_ecrecover(&[(msg, sig)])[0].unwrap();
+        assert_eq!(&output.bytes[12..], recovered.as_slice());
+    }
 }
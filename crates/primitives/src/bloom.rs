@@ -0,0 +1,43 @@
+use crate::{Bloom, Log};
+
+/// Computes the logs bloom over the address and topics of each log.
+///
+/// See the yellow paper, section 4.3.1.
+pub fn logs_bloom(logs: &[Log]) -> Bloom {
+    let mut bloom = Bloom::default();
+    for log in logs {
+        bloom.accrue_log(log);
+    }
+    bloom
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{address, b256, hex, Address, Bytes, LogData};
+    use std::vec;
+
+    #[test]
+    fn logs_bloom_matches_reference() {
+        let logs = [
+            Log {
+                address: address!("22341ae42d6dd7384bc8584e50419ea3ac75b83f"),
+                data: LogData::new_unchecked(
+                    vec![b256!(
+                        "04491edcd115127caedbd478e2e7895ed80c7847e903431f94f9cfa579cad47f"
+                    )],
+                    Bytes::new(),
+                ),
+            },
+            Log {
+                address: Address::with_last_byte(1),
+                data: LogData::default(),
+            },
+        ];
+        let expected = Bloom::from(hex!(
+            "00000000000000000081000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000002000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000020000000001000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000040000000000000000000000000000000000000000000000000000000000000000000000000000000000000000010000000000000000000000000000000000400000000000000000100000000000000000000000000000000"
+        ));
+        assert_eq!(logs_bloom(&logs), expected);
+        assert_eq!(logs_bloom(&[]), Bloom::default());
+    }
+}
@@ -19,6 +19,34 @@ pub const BLOCKHASH_SERVE_WINDOW: usize = 8192;
 /// This is named `HISTORY_STORAGE_ADDRESS` in the EIP.
 pub const BLOCKHASH_STORAGE_ADDRESS: Address = address!("0F792be4B0c0cb4DAE440Ef133E90C0eCD48CCCC");
 
+/// EIP-4788: Beacon block root in the EVM
+///
+/// The address of the beacon roots contract.
+pub const BEACON_ROOTS_ADDRESS: Address = address!("000F3df6D732807Ef1319fB7B8bB8522d0Beac02");
+
+/// EIP-4788: Beacon block root in the EVM
+///
+/// Number of roots kept in the ring buffer of the beacon roots contract.
+pub const BEACON_ROOTS_HISTORY_BUFFER_LENGTH: u64 = 8191;
+
+/// Caller of the system calls made at block boundaries.
+pub const SYSTEM_ADDRESS: Address = address!("fffffffffffffffffffffffffffffffffffffffe");
+
+/// Gas limit of the system calls made at block boundaries.
+pub const SYSTEM_CALL_GAS_LIMIT: u64 = 30_000_000;
+
+/// EIP-7002: Execution layer triggerable withdrawals
+///
+/// The address of the withdrawal request predeploy contract.
+pub const WITHDRAWAL_REQUEST_PREDEPLOY_ADDRESS: Address =
+    address!("00000961Ef480Eb55e80D19ad83579A64c007002");
+
+/// EIP-7251: Increase the MAX_EFFECTIVE_BALANCE
+///
+/// The address of the consolidation request predeploy contract.
+pub const CONSOLIDATION_REQUEST_PREDEPLOY_ADDRESS: Address =
+    address!("0000BBdDc7CE488642fb579F8B00f3a590007251");
+
 /// The address of precompile 3, which is handled specially in a few places
 pub const PRECOMPILE3: Address =
     Address::new([0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 3]);
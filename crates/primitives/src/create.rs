@@ -0,0 +1,81 @@
+use crate::{Address, B256};
+
+/// Returns the address of a contract deployed with `CREATE` by `sender` at `nonce`.
+///
+/// It is `keccak256(rlp([sender, nonce]))[12..]`, as computed by the interpreter.
+#[inline]
+pub fn create_address(sender: Address, nonce: u64) -> Address {
+    sender.create(nonce)
+}
+
+/// Returns the address of a contract deployed with `CREATE2` by `sender`.
+///
+/// It is `keccak256(0xff || sender || salt || init_code_hash)[12..]` as defined in
+/// [EIP-1014](https://eips.ethereum.org/EIPS/eip-1014), as computed by the interpreter.
+#[inline]
+pub fn create2_address(sender: Address, salt: B256, init_code_hash: B256) -> Address {
+    sender.create2(salt, init_code_hash)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{address, b256, hex, keccak256};
+
+    #[test]
+    fn create_vectors() {
+        let sender = address!("6ac7ea33f8831ea9dcc53393aaa88b25a785dbf0");
+        let expected = [
+            address!("cd234a471b72ba2f1ccf0a70fcaba648a5eecd8d"),
+            address!("343c43a37d37dff08ae8c4a11544c718abb4fcf8"),
+            address!("f778b86fa74e846c4f0a1fbd1335fe81c00a0c91"),
+            address!("fffd933a0bc612844eaf0c6fe3e5b8e9b6c1d19c"),
+        ];
+        for (nonce, expected) in expected.into_iter().enumerate() {
+            assert_eq!(create_address(sender, nonce as u64), expected);
+        }
+    }
+
+    #[test]
+    fn create2_vectors() {
+        // Examples of EIP-1014.
+        let vectors = [
+            (
+                Address::ZERO,
+                B256::ZERO,
+                hex!("00").as_slice(),
+                address!("4D1A2e2bB4F88F0250f26Ffff098B0b30B26BF38"),
+            ),
+            (
+                address!("deadbeef00000000000000000000000000000000"),
+                B256::ZERO,
+                hex!("00").as_slice(),
+                address!("B928f69Bb1D91Cd65274e3c79d8986362984fDA3"),
+            ),
+            (
+                address!("deadbeef00000000000000000000000000000000"),
+                b256!("000000000000000000000000feed000000000000000000000000000000000000"),
+                hex!("00").as_slice(),
+                address!("D04116cDd17beBE565EB2422F2497E06cC1C9833"),
+            ),
+            (
+                Address::ZERO,
+                B256::ZERO,
+                hex!("deadbeef").as_slice(),
+                address!("70f2b2914A2a4b783FaEFb75f459A580616Fcb5e"),
+            ),
+            (
+                Address::ZERO,
+                B256::ZERO,
+                hex!("").as_slice(),
+                address!("E33C0C7F7df4809055C3ebA6c09CFe4BaF1BD9e0"),
+            ),
+        ];
+        for (sender, salt, init_code, expected) in vectors {
+            assert_eq!(
+                create2_address(sender, salt, keccak256(init_code)),
+                expected
+            );
+        }
+    }
+}
@@ -4,12 +4,16 @@
 #![cfg_attr(not(test), warn(unused_crate_dependencies))]
 #![cfg_attr(not(feature = "std"), no_std)]
 
+mod bloom;
 mod constants;
+mod create;
+pub use bloom::logs_bloom;
 pub use constants::*;
+pub use create::{create2_address, create_address};
 
 pub use alloy_primitives::{
     self, address, b256, bytes, fixed_bytes, hex, hex_literal, keccak256, ruint, uint, Address,
-    Bytes, FixedBytes, Log, LogData, TxKind, B256, I256, U256,
+    Bloom, Bytes, FixedBytes, Log, LogData, TxKind, B256, I256, U256,
 };
 
 pub use alloy_primitives::map::{self, hash_map, hash_set, HashMap, HashSet};
@@ -0,0 +1,341 @@
+use crate::Evm;
+use bytecode::Bytecode;
+use context_interface::{
+    block::BlockSetter,
+    journaled_state::Journal,
+    result::{ExecutionResult, HaltReasonTrait, InvalidHeader, InvalidTransaction, ResultAndState},
+    transaction::TransactionSetter,
+    BlockGetter, CfgGetter, DatabaseGetter, ErrorGetter, JournalDBError, JournalGetter,
+    TransactionGetter,
+};
+use database_interface::{Database, DatabaseCommit};
+use handler::{EthHandler, FrameResult};
+use handler_interface::{
+    ExecutionHandler, Frame, PostExecutionHandler, PreExecutionHandler, ValidationHandler,
+};
+use interpreter::Host;
+use precompile::PrecompileErrors;
+use primitives::{hash_map::Entry, Address, HashMap, Log, U256};
+use state::{Account, EvmState, EvmStorageSlot};
+use std::vec::Vec;
+
+/// Override of an account applied before a bundle transaction.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct AccountOverride {
+    /// Replaces the balance.
+    pub balance: Option<U256>,
+    /// Replaces the nonce.
+    pub nonce: Option<u64>,
+    /// Replaces the code.
+    pub code: Option<Bytecode>,
+    /// Sets the given storage slots, other slots are left unchanged.
+    pub storage: HashMap<U256, U256>,
+}
+
+/// Transaction of a bundle with its overrides.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct BundleTx<TX, BLOCK> {
+    /// Transaction to execute.
+    pub tx: TX,
+    /// Block the transaction is executed in. If `None`, the block of the context is used.
+    pub block: Option<BLOCK>,
+    /// Account overrides applied before the transaction.
+    pub state: HashMap<Address, AccountOverride>,
+    /// Whether the transaction may fail without aborting the bundle.
+    pub revert_allowed: bool,
+}
+
+impl<TX, BLOCK> BundleTx<TX, BLOCK> {
+    /// Creates a bundle transaction without overrides.
+    pub fn new(tx: TX) -> Self {
+        Self {
+            tx,
+            block: None,
+            state: HashMap::default(),
+            revert_allowed: false,
+        }
+    }
+}
+
+/// Output of [`Evm::simulate_bundle`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct BundleOutput<HALT> {
+    /// Result of every executed transaction, in bundle order.
+    pub results: Vec<ExecutionResult<HALT>>,
+    /// State changed by the overrides and the executed transactions.
+    ///
+    /// Nothing is committed by [`Evm::simulate_bundle`], commit this state to apply the bundle.
+    pub state: EvmState,
+    /// Whether execution stopped at a failed transaction.
+    pub aborted: bool,
+}
+
+impl<ERROR, CTX, VAL, PREEXEC, EXEC, POSTEXEC, HALT>
+    Evm<ERROR, CTX, EthHandler<CTX, ERROR, VAL, PREEXEC, EXEC, POSTEXEC>>
+where
+    CTX: TransactionSetter
+        + TransactionGetter<Transaction: Clone>
+        + BlockSetter
+        + BlockGetter<Block: Clone>
+        + JournalGetter
+        + CfgGetter
+        + DatabaseGetter<Database: Database + DatabaseCommit + Clone>
+        + ErrorGetter<Error = ERROR>
+        + JournalGetter<
+            Journal: Journal<
+                FinalOutput = (EvmState, Vec<Log>, Vec<usize>),
+                Database = <CTX as DatabaseGetter>::Database,
+            >,
+        > + Host,
+    ERROR: From<InvalidTransaction>
+        + From<InvalidHeader>
+        + From<JournalDBError<CTX>>
+        + From<PrecompileErrors>,
+    VAL: ValidationHandler<Context = CTX, Error = ERROR>,
+    PREEXEC: PreExecutionHandler<Context = CTX, Error = ERROR>,
+    EXEC: ExecutionHandler<
+        Context = CTX,
+        Error = ERROR,
+        ExecResult = FrameResult,
+        Frame: Frame<FrameResult = FrameResult>,
+    >,
+    POSTEXEC: PostExecutionHandler<
+        Context = CTX,
+        Error = ERROR,
+        ExecResult = FrameResult,
+        Output = ResultAndState<HALT>,
+    >,
+    HALT: HaltReasonTrait,
+{
+    /// Executes `bundle` in order, applying the overrides of each transaction before it.
+    ///
+    /// If `abort_on_revert` is set, execution stops at the first transaction that does not
+    /// succeed and does not allow reverts. Otherwise all transactions are executed.
+    ///
+    /// Transactions run on a scratch copy of the context database, so that later transactions
+    /// observe the changes of earlier ones while the context database is left untouched. The
+    /// aggregate changes are returned in [`BundleOutput::state`], commit them to the context
+    /// database to apply the bundle. The block and transaction of the context are restored
+    /// afterwards.
+    pub fn simulate_bundle(
+        &mut self,
+        bundle: impl IntoIterator<
+            Item = BundleTx<<CTX as TransactionGetter>::Transaction, <CTX as BlockGetter>::Block>,
+        >,
+        abort_on_revert: bool,
+    ) -> Result<BundleOutput<HALT>, ERROR> {
+        let block = self.context.block().clone();
+        let tx = self.context.tx().clone();
+        let scratch = self.context.db().clone();
+        let db = core::mem::replace(self.context.db(), scratch);
+
+        let output = self.simulate_bundle_inner(bundle, &block, abort_on_revert);
+
+        *self.context.db() = db;
+        self.context.set_block(block);
+        self.context.set_tx(tx);
+        output
+    }
+
+    fn simulate_bundle_inner(
+        &mut self,
+        bundle: impl IntoIterator<
+            Item = BundleTx<<CTX as TransactionGetter>::Transaction, <CTX as BlockGetter>::Block>,
+        >,
+        block: &<CTX as BlockGetter>::Block,
+        abort_on_revert: bool,
+    ) -> Result<BundleOutput<HALT>, ERROR> {
+        let mut output = BundleOutput {
+            results: Vec::new(),
+            state: EvmState::default(),
+            aborted: false,
+        };
+
+        for bundle_tx in bundle {
+            let overrides = self.override_state(bundle_tx.state)?;
+            merge_state(&mut output.state, overrides.clone());
+            self.context.db().commit(overrides);
+
+            self.context
+                .set_block(bundle_tx.block.unwrap_or_else(|| block.clone()));
+            self.context.set_tx(bundle_tx.tx);
+            let ResultAndState { result, state } = self.transact()?;
+            merge_state(&mut output.state, state.clone());
+            self.context.db().commit(state);
+
+            let abort = abort_on_revert && !bundle_tx.revert_allowed && !result.is_success();
+            output.results.push(result);
+            if abort {
+                output.aborted = true;
+                break;
+            }
+        }
+        Ok(output)
+    }
+
+    /// Loads the overridden accounts from the database and applies the overrides.
+    fn override_state(
+        &mut self,
+        overrides: HashMap<Address, AccountOverride>,
+    ) -> Result<EvmState, ERROR> {
+        let db = self.context.db();
+        let mut state = EvmState::default();
+        for (address, account_override) in overrides {
+            let mut account = Account::from(db.basic(address)?.unwrap_or_default());
+            if let Some(balance) = account_override.balance {
+                account.info.balance = balance;
+            }
+            if let Some(nonce) = account_override.nonce {
+                account.info.nonce = nonce;
+            }
+            if let Some(code) = account_override.code {
+                account.info.code_hash = code.hash_slow();
+                account.info.code = Some(code);
+            }
+            for (slot, value) in account_override.storage {
+                let original = db.storage(address, slot)?;
+                account
+                    .storage
+                    .insert(slot, EvmStorageSlot::new_changed(original, value));
+            }
+            account.mark_touch();
+            state.insert(address, account);
+        }
+        Ok(state)
+    }
+}
+
+/// Merges the touched accounts of `state` into `aggregate`.
+///
+/// Storage slots keep the original value of their first change.
+fn merge_state(aggregate: &mut EvmState, state: EvmState) {
+    for (address, account) in state {
+        if !account.is_touched() {
+            continue;
+        }
+        match aggregate.entry(address) {
+            Entry::Vacant(entry) => {
+                entry.insert(account);
+            }
+            Entry::Occupied(mut entry) => {
+                let merged = entry.get_mut();
+                if account.is_created() || account.is_selfdestructed() {
+                    *merged = account;
+                    continue;
+                }
+                merged.info = account.info;
+                for (slot, value) in account.storage {
+                    match merged.storage.entry(slot) {
+                        Entry::Vacant(entry) => {
+                            entry.insert(value);
+                        }
+                        Entry::Occupied(mut entry) => {
+                            entry.get_mut().present_value = value.present_value;
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Context, MainEvm};
+    use bytecode::opcode;
+    use context::{block::BlockEnv, tx::TxEnv, CfgEnv};
+    use context_interface::result::HaltReason;
+    use database::InMemoryDB;
+    use primitives::{Bytes, TxKind};
+    use state::AccountInfo;
+
+    const CALLER: Address = Address::with_last_byte(0x01);
+    const REVERTING: Address = Address::with_last_byte(0xaa);
+    const STORING: Address = Address::with_last_byte(0xbb);
+
+    type BundleEvm = MainEvm<InMemoryDB, BlockEnv, TxEnv, CfgEnv>;
+
+    fn simulate(abort_on_revert: bool) -> (BundleOutput<HaltReason>, BundleEvm) {
+        let mut db = InMemoryDB::default();
+        let reverting = vec![opcode::PUSH1, 0x00, opcode::PUSH1, 0x00, opcode::REVERT];
+        // Stores the block number at slot zero.
+        let storing = vec![
+            opcode::NUMBER,
+            opcode::PUSH1,
+            0x00,
+            opcode::SSTORE,
+            opcode::STOP,
+        ];
+        for (address, code) in [(REVERTING, reverting), (STORING, storing)] {
+            let bytecode = Bytecode::new_raw(Bytes::from(code));
+            db.insert_account_info(
+                address,
+                AccountInfo::new(U256::ZERO, 1, bytecode.hash_slow(), bytecode),
+            );
+        }
+        let mut evm = MainEvm::new(Context::builder().with_db(db), EthHandler::default());
+
+        let tx = |nonce, to| TxEnv {
+            caller: CALLER,
+            transact_to: TxKind::Call(to),
+            nonce,
+            gas_limit: 100_000,
+            ..Default::default()
+        };
+        let mut first = BundleTx::new(tx(0, REVERTING));
+        first.state.insert(
+            CALLER,
+            AccountOverride {
+                balance: Some(U256::from(1_000_000)),
+                ..Default::default()
+            },
+        );
+        let mut second = BundleTx::new(tx(1, STORING));
+        second.block = Some(BlockEnv {
+            number: 42,
+            ..Default::default()
+        });
+
+        let output = evm
+            .simulate_bundle([first, second], abort_on_revert)
+            .unwrap();
+        (output, evm)
+    }
+
+    #[test]
+    fn bundle_continues_after_revert() {
+        let (output, mut evm) = simulate(false);
+        assert!(!output.aborted);
+        assert_eq!(output.results.len(), 2);
+        assert!(matches!(output.results[0], ExecutionResult::Revert { .. }));
+        assert!(output.results[1].is_success());
+
+        let caller = &output.state[&CALLER];
+        assert_eq!(caller.info.balance, U256::from(1_000_000));
+        assert_eq!(caller.info.nonce, 2);
+        assert_eq!(
+            output.state[&STORING].storage[&U256::ZERO].present_value,
+            U256::from(42)
+        );
+        // The block and transaction of the context are restored.
+        assert_eq!(evm.context.block().number, 0);
+        assert_eq!(evm.context.tx().caller, Address::ZERO);
+
+        // Nothing is committed until the caller asks for it.
+        let db = evm.context.db();
+        assert_eq!(db.basic(CALLER).unwrap(), None);
+        assert_eq!(db.storage(STORING, U256::ZERO).unwrap(), U256::ZERO);
+        db.commit(output.state);
+        assert_eq!(db.basic(CALLER).unwrap().unwrap().nonce, 2);
+        assert_eq!(db.storage(STORING, U256::ZERO).unwrap(), U256::from(42));
+    }
+
+    #[test]
+    fn bundle_aborts_on_revert() {
+        let (output, _) = simulate(true);
+        assert!(output.aborted);
+        assert_eq!(output.results.len(), 1);
+        assert!(!output.state.contains_key(&STORING));
+    }
+}
@@ -0,0 +1,293 @@
+use alloy_rlp::{Decodable, Encodable, Header};
+use context::tx::TxEnv;
+use context_interface::transaction::TransactionType;
+use core::fmt;
+use primitives::{keccak256, Address, Bytes, TxKind, B256, U256};
+use specification::{
+    eip2::SECP256K1N_HALF,
+    eip2930::AccessList,
+    eip7702::{Parity, Signature, SignedAuthorization},
+};
+use std::vec::Vec;
+
+/// Error returned by [`decode_tx`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum DecodeError {
+    /// The transaction is not valid RLP or has trailing bytes.
+    Rlp(alloy_rlp::Error),
+    /// The EIP-2718 transaction type is not supported.
+    UnsupportedType(u8),
+    /// The signature is malformed, not in the lower half of the curve order, or the signer
+    /// can't be recovered.
+    InvalidSignature,
+}
+
+impl From<alloy_rlp::Error> for DecodeError {
+    fn from(err: alloy_rlp::Error) -> Self {
+        Self::Rlp(err)
+    }
+}
+
+impl fmt::Display for DecodeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Rlp(err) => write!(f, "invalid transaction RLP: {err}"),
+            Self::UnsupportedType(ty) => write!(f, "unsupported transaction type {ty}"),
+            Self::InvalidSignature => f.write_str("invalid transaction signature"),
+        }
+    }
+}
+
+impl core::error::Error for DecodeError {}
+
+/// Decodes a raw signed transaction into a [`TxEnv`].
+///
+/// Both legacy transactions and [EIP-2718] typed transactions (types `0x01` to `0x04`) are
+/// supported. The caller is recovered from the signature, which must satisfy the [EIP-2]
+/// low `s` rule.
+///
+/// [EIP-2718]: https://eips.ethereum.org/EIPS/eip-2718
+/// [EIP-2]: https://eips.ethereum.org/EIPS/eip-2
+pub fn decode_tx(bytes: &[u8]) -> Result<TxEnv, DecodeError> {
+    match bytes.first() {
+        None => Err(alloy_rlp::Error::InputTooShort.into()),
+        Some(&ty) if ty >= alloy_rlp::EMPTY_LIST_CODE => decode_legacy(bytes),
+        Some(&ty) => decode_typed(ty, &bytes[1..]),
+    }
+}
+
+/// Decodes a legacy transaction, with or without [EIP-155] replay protection.
+///
+/// [EIP-155]: https://eips.ethereum.org/EIPS/eip-155
+fn decode_legacy(bytes: &[u8]) -> Result<TxEnv, DecodeError> {
+    let payload = decode_payload(bytes)?;
+    let mut fields = payload;
+
+    let mut tx = TxEnv {
+        tx_type: TransactionType::Legacy,
+        nonce: u64::decode(&mut fields)?,
+        gas_price: U256::decode(&mut fields)?,
+        gas_limit: u64::decode(&mut fields)?,
+        transact_to: TxKind::decode(&mut fields)?,
+        value: U256::decode(&mut fields)?,
+        data: Bytes::decode(&mut fields)?,
+        gas_priority_fee: None,
+        max_fee_per_blob_gas: None,
+        ..Default::default()
+    };
+    let unsigned = &payload[..payload.len() - fields.len()];
+
+    let v = u64::decode(&mut fields)?;
+    let (y_odd, chain_id) = match v {
+        27 | 28 => (v == 28, None),
+        35.. => ((v - 35) % 2 == 1, Some((v - 35) / 2)),
+        _ => return Err(DecodeError::InvalidSignature),
+    };
+    tx.chain_id = chain_id;
+
+    // The EIP-155 signing payload appends `chain_id, 0, 0` to the unsigned fields.
+    let mut suffix = Vec::new();
+    if let Some(chain_id) = chain_id {
+        chain_id.encode(&mut suffix);
+        0u8.encode(&mut suffix);
+        0u8.encode(&mut suffix);
+    }
+    let mut signing = Vec::new();
+    Header {
+        list: true,
+        payload_length: unsigned.len() + suffix.len(),
+    }
+    .encode(&mut signing);
+    signing.extend_from_slice(unsigned);
+    signing.extend_from_slice(&suffix);
+
+    tx.caller = recover_signer(&mut fields, Parity::Parity(y_odd), &signing)?;
+    finish(fields)?;
+    Ok(tx)
+}
+
+/// Decodes the payload of an [EIP-2718] typed transaction of type `ty`.
+///
+/// [EIP-2718]: https://eips.ethereum.org/EIPS/eip-2718
+fn decode_typed(ty: u8, bytes: &[u8]) -> Result<TxEnv, DecodeError> {
+    let tx_type = match ty {
+        0x01 => TransactionType::Eip2930,
+        0x02 => TransactionType::Eip1559,
+        0x03 => TransactionType::Eip4844,
+        0x04 => TransactionType::Eip7702,
+        _ => return Err(DecodeError::UnsupportedType(ty)),
+    };
+
+    let payload = decode_payload(bytes)?;
+    let mut fields = payload;
+
+    let mut tx = TxEnv {
+        tx_type,
+        chain_id: Some(u64::decode(&mut fields)?),
+        nonce: u64::decode(&mut fields)?,
+        max_fee_per_blob_gas: None,
+        ..Default::default()
+    };
+    if tx_type == TransactionType::Eip2930 {
+        tx.gas_price = U256::decode(&mut fields)?;
+        tx.gas_priority_fee = None;
+    } else {
+        tx.gas_priority_fee = Some(U256::decode(&mut fields)?);
+        tx.gas_price = U256::decode(&mut fields)?;
+    }
+    tx.gas_limit = u64::decode(&mut fields)?;
+    // Blob transactions can't create contracts.
+    tx.transact_to = if tx_type == TransactionType::Eip4844 {
+        TxKind::Call(Address::decode(&mut fields)?)
+    } else {
+        TxKind::decode(&mut fields)?
+    };
+    tx.value = U256::decode(&mut fields)?;
+    tx.data = Bytes::decode(&mut fields)?;
+    tx.access_list = AccessList::decode(&mut fields)?;
+    match tx_type {
+        TransactionType::Eip4844 => {
+            tx.max_fee_per_blob_gas = Some(U256::decode(&mut fields)?);
+            tx.blob_hashes = Vec::<B256>::decode(&mut fields)?;
+        }
+        TransactionType::Eip7702 => {
+            // Authorities are recovered during execution.
+            tx.authorization_list = Vec::<SignedAuthorization>::decode(&mut fields)?.into();
+        }
+        _ => {}
+    }
+    let unsigned = &payload[..payload.len() - fields.len()];
+
+    let mut signing = Vec::new();
+    signing.push(ty);
+    Header {
+        list: true,
+        payload_length: unsigned.len(),
+    }
+    .encode(&mut signing);
+    signing.extend_from_slice(unsigned);
+
+    let parity = match u8::decode(&mut fields)? {
+        0 => Parity::Parity(false),
+        1 => Parity::Parity(true),
+        _ => return Err(DecodeError::InvalidSignature),
+    };
+    tx.caller = recover_signer(&mut fields, parity, &signing)?;
+    finish(fields)?;
+    Ok(tx)
+}
+
+/// Decodes `r` and `s` and recovers the address that signed `keccak256(signing)`.
+fn recover_signer(buf: &mut &[u8], parity: Parity, signing: &[u8]) -> Result<Address, DecodeError> {
+    let r = U256::decode(buf)?;
+    let s = U256::decode(buf)?;
+    // EIP-2: Signatures with `s` in the upper half of the curve order are malleable.
+    if s > SECP256K1N_HALF {
+        return Err(DecodeError::InvalidSignature);
+    }
+    Signature::from_rs_and_parity(r, s, parity)
+        .and_then(|signature| signature.recover_address_from_prehash(&keccak256(signing)))
+        .map_err(|_| DecodeError::InvalidSignature)
+}
+
+/// Decodes the outer list of a transaction, which must span the whole input.
+fn decode_payload(mut buf: &[u8]) -> Result<&[u8], DecodeError> {
+    let payload = Header::decode_bytes(&mut buf, true)?;
+    finish(buf)?;
+    Ok(payload)
+}
+
+/// Returns an error if `buf` is not fully consumed.
+fn finish(buf: &[u8]) -> Result<(), DecodeError> {
+    if buf.is_empty() {
+        Ok(())
+    } else {
+        Err(alloy_rlp::Error::UnexpectedLength.into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use primitives::{address, bytes, hex};
+    use specification::eip2930::AccessListItem;
+
+    /// Address of the private key `0x4646..46`.
+    const SIGNER: Address = address!("9d8A62f656a8d1615C1294fd71e9CFb3E4855A4F");
+
+    #[test]
+    fn decode_legacy_tx() {
+        // Signed example of EIP-155.
+        let raw = hex!(
+            "f86c098504a817c800825208943535353535353535353535353535353535353535880de0b6b3a7640000"
+            "8025a028ef61340bd939bc2195fe537567866003e1a15d3c71ff63e1590620aa636276a067cbe9d8997f"
+            "761aecb703304b3800ccf555c9f3dc64214b297fb1966a3b6d83"
+        );
+
+        let tx = decode_tx(&raw).unwrap();
+        assert_eq!(tx.tx_type, TransactionType::Legacy);
+        assert_eq!(tx.caller, SIGNER);
+        assert_eq!(tx.nonce, 9);
+        assert_eq!(tx.gas_price, U256::from(20_000_000_000u64));
+        assert_eq!(tx.gas_limit, 21_000);
+        assert_eq!(tx.transact_to, TxKind::Call(Address::repeat_byte(0x35)));
+        assert_eq!(tx.value, U256::from(10u64.pow(18)));
+        assert!(tx.data.is_empty());
+        assert_eq!(tx.chain_id, Some(1));
+        assert_eq!(tx.gas_priority_fee, None);
+    }
+
+    #[test]
+    fn decode_eip1559_tx() {
+        let raw = hex!(
+            "02f8af010784773594008506fc23ac0082c350943535353535353535353535353535353535353535880d"
+            "e0b6b3a764000083c0ffeef838f7943535353535353535353535353535353535353535e1a00000000000"
+            "00000000000000000000000000000000000000000000000000000180a0ef256d9cd8ef121100ca18769b"
+            "4c46c399a8f35b6d24fa46b6de85424b463adca0605f08e8aa00243b2c21c07e416027cf7e6ec9bd421e"
+            "5d71881af037b6339d02"
+        );
+
+        let tx = decode_tx(&raw).unwrap();
+        assert_eq!(tx.tx_type, TransactionType::Eip1559);
+        assert_eq!(tx.caller, SIGNER);
+        assert_eq!(tx.chain_id, Some(1));
+        assert_eq!(tx.nonce, 7);
+        assert_eq!(tx.gas_priority_fee, Some(U256::from(2_000_000_000u64)));
+        assert_eq!(tx.gas_price, U256::from(30_000_000_000u64));
+        assert_eq!(tx.gas_limit, 50_000);
+        assert_eq!(tx.transact_to, TxKind::Call(Address::repeat_byte(0x35)));
+        assert_eq!(tx.value, U256::from(10u64.pow(18)));
+        assert_eq!(tx.data, bytes!("c0ffee"));
+        assert_eq!(
+            tx.access_list,
+            AccessList(vec![AccessListItem {
+                address: Address::repeat_byte(0x35),
+                storage_keys: vec![B256::with_last_byte(1)],
+            }])
+        );
+
+        assert_eq!(
+            decode_tx(&[&raw[..], &[0x00]].concat()),
+            Err(DecodeError::Rlp(alloy_rlp::Error::UnexpectedLength))
+        );
+        assert_eq!(
+            decode_tx(&[0x05, 0xc0]),
+            Err(DecodeError::UnsupportedType(5))
+        );
+    }
+
+    #[test]
+    fn reject_high_s_signature() {
+        // Transaction of `decode_eip1559_tx` with `s` replaced by `n - s` and the parity flipped,
+        // which recovers the same signer.
+        let raw = hex!(
+            "02f8af010784773594008506fc23ac0082c350943535353535353535353535353535353535353535880d"
+            "e0b6b3a764000083c0ffeef838f7943535353535353535353535353535353535353535e1a00000000000"
+            "00000000000000000000000000000000000000000000000000000101a0ef256d9cd8ef121100ca18769b"
+            "4c46c399a8f35b6d24fa46b6de85424b463adca09fa0f71755ffdbc4d3de3f81be9fd82f3c4013296d2a"
+            "42ca37b76e551a02a43f"
+        );
+
+        assert_eq!(decode_tx(&raw), Err(DecodeError::InvalidSignature));
+    }
+}
@@ -1,4 +1,3 @@
-use crate::{exec::EvmCommit, EvmExec};
 use context::{block::BlockEnv, tx::TxEnv, CfgEnv, Context, JournaledState};
 use context_interface::{
     block::BlockSetter,
@@ -8,18 +7,22 @@ use context_interface::{
         ResultAndState,
     },
     transaction::TransactionSetter,
-    BlockGetter, CfgGetter, DatabaseGetter, ErrorGetter, JournalDBError, JournalGetter,
+    Block, BlockGetter, Cfg, CfgGetter, DatabaseGetter, ErrorGetter, JournalDBError, JournalGetter,
     Transaction, TransactionGetter,
 };
 use database_interface::{Database, DatabaseCommit};
-use handler::{EthHandler, FrameResult};
+use handler::{EthHandler, EvmCommit, EvmExec, FrameResult};
 use handler_interface::{
     ExecutionHandler, Frame, FrameOrResultGen, Handler, PostExecutionHandler, PreExecutionHandler,
     ValidationHandler,
 };
 use interpreter::Host;
 use precompile::PrecompileErrors;
-use primitives::Log;
+use primitives::{
+    Address, Bytes, Log, TxKind, BEACON_ROOTS_ADDRESS, BLOCKHASH_STORAGE_ADDRESS,
+    CONSOLIDATION_REQUEST_PREDEPLOY_ADDRESS, WITHDRAWAL_REQUEST_PREDEPLOY_ADDRESS,
+};
+use specification::hardfork::SpecId;
 use state::EvmState;
 use std::vec::Vec;
 
@@ -40,6 +43,52 @@ impl<ERROR, CTX, HANDLER> Evm<ERROR, CTX, HANDLER> {
     }
 }
 
+impl<ERROR, BLOCK, TX, DB, JOURNAL, CHAIN, HANDLER>
+    Evm<ERROR, Context<BLOCK, TX, CfgEnv, DB, JOURNAL, CHAIN>, HANDLER>
+where
+    BLOCK: Block,
+    TX: Transaction,
+    DB: Database,
+    JOURNAL: Journal<Database = DB>,
+{
+    /// Sets the hardfork used by subsequent transactions.
+    ///
+    /// Precompiles and instruction tables are derived from the spec when a transaction starts.
+    pub fn set_spec(&mut self, spec: SpecId) {
+        self.context.modify_cfg(|cfg| cfg.spec = spec);
+    }
+
+    /// Returns the EVM with the hardfork set to `spec`.
+    ///
+    /// See [`Evm::set_spec`].
+    pub fn with_spec(mut self, spec: SpecId) -> Self {
+        self.set_spec(spec);
+        self
+    }
+}
+
+impl<ERROR, BLOCK, TX, CFG, DB, CHAIN, HANDLER>
+    Evm<ERROR, Context<BLOCK, TX, CFG, DB, JournaledState<DB>, CHAIN>, HANDLER>
+where
+    BLOCK: Clone,
+    TX: Clone,
+    CFG: Clone,
+    DB: Database + Clone,
+    <DB as Database>::Error: Clone,
+    CHAIN: Clone,
+    HANDLER: Clone,
+{
+    /// Returns an independent copy of the EVM.
+    ///
+    /// The handler, configuration, block, transaction and journaled state are copied, and the
+    /// fork gets a clone of the database as a snapshot. Changes made by either EVM, including commits,
+    /// are not visible to the other as long as the database clone does not share its state,
+    /// which is the case for in-memory databases like `CacheDB<EmptyDB>`.
+    pub fn fork(&self) -> Self {
+        Self::new(self.context.clone(), self.handler.clone())
+    }
+}
+
 impl<ERROR, CTX, VAL, PREEXEC, EXEC, POSTEXEC, HALT> EvmCommit
     for Evm<ERROR, CTX, EthHandler<CTX, ERROR, VAL, PREEXEC, EXEC, POSTEXEC>>
 where
@@ -51,7 +100,7 @@ where
         + ErrorGetter<Error = ERROR>
         + JournalGetter<
             Journal: Journal<
-                FinalOutput = (EvmState, Vec<Log>),
+                FinalOutput = (EvmState, Vec<Log>, Vec<usize>),
                 Database = <CTX as DatabaseGetter>::Database,
             >,
         > + Host,
@@ -98,7 +147,7 @@ where
         + ErrorGetter<Error = ERROR>
         + JournalGetter<
             Journal: Journal<
-                FinalOutput = (EvmState, Vec<Log>),
+                FinalOutput = (EvmState, Vec<Log>, Vec<usize>),
                 Database = <CTX as DatabaseGetter>::Database,
             >,
         > + Host,
@@ -156,7 +205,7 @@ where
         + ErrorGetter<Error = ERROR>
         + JournalGetter<
             Journal: Journal<
-                FinalOutput = (EvmState, Vec<Log>),
+                FinalOutput = (EvmState, Vec<Log>, Vec<usize>),
                 Database = <CTX as DatabaseGetter>::Database,
             >,
         > + Host,
@@ -282,6 +331,1949 @@ where
     }
 }
 
+/// Requests dequeued from the system contracts at the end of a block.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct BlockRequests {
+    /// Output of the EIP-7002 withdrawal request predeploy.
+    pub withdrawals: Bytes,
+    /// Output of the EIP-7251 consolidation request predeploy.
+    pub consolidations: Bytes,
+}
+
+impl<ERROR, CTX, VAL, PREEXEC, EXEC, POSTEXEC, HALT>
+    Evm<ERROR, CTX, EthHandler<CTX, ERROR, VAL, PREEXEC, EXEC, POSTEXEC>>
+where
+    CTX: TransactionSetter
+        + TransactionGetter<Transaction = TxEnv>
+        + BlockGetter
+        + JournalGetter
+        + CfgGetter
+        + DatabaseGetter
+        + ErrorGetter<Error = ERROR>
+        + JournalGetter<
+            Journal: Journal<
+                FinalOutput = (EvmState, Vec<Log>, Vec<usize>),
+                Database = <CTX as DatabaseGetter>::Database,
+            >,
+        > + Host,
+    ERROR: From<InvalidTransaction>
+        + From<InvalidHeader>
+        + From<JournalDBError<CTX>>
+        + From<PrecompileErrors>,
+    VAL: ValidationHandler<Context = CTX, Error = ERROR>,
+    PREEXEC: PreExecutionHandler<Context = CTX, Error = ERROR>,
+    EXEC: ExecutionHandler<
+        Context = CTX,
+        Error = ERROR,
+        ExecResult = FrameResult,
+        Frame: Frame<FrameResult = FrameResult>,
+    >,
+    POSTEXEC: PostExecutionHandler<
+        Context = CTX,
+        Error = ERROR,
+        ExecResult = FrameResult,
+        Output = ResultAndState<HALT>,
+    >,
+    HALT: HaltReasonTrait,
+{
+    /// Performs the system calls done at the start of the block.
+    ///
+    /// From Cancun, the EIP-4788 beacon roots contract is called with the parent beacon block
+    /// root. From Prague, the EIP-2935 history storage contract is called with the parent
+    /// block hash. Both go through [`Evm::system_call`].
+    ///
+    /// Returns the changed state. It should be committed before the first transaction
+    /// of the block is executed.
+    pub fn apply_pre_block(&mut self) -> Result<EvmState, ERROR> {
+        let mut state = EvmState::default();
+
+        let spec: SpecId = self.context.cfg().spec().into();
+        // EIP-4788: Beacon block root in the EVM
+        if spec.is_enabled_in(SpecId::CANCUN) {
+            if let Some(root) = self.context.block().parent_beacon_block_root() {
+                let data = Bytes::copy_from_slice(root.as_slice());
+                self.pre_block_system_call(BEACON_ROOTS_ADDRESS, data, &mut state)?;
+            }
+        }
+
+        // EIP-2935: Serve historical block hashes from state
+        if spec.is_enabled_in(SpecId::PRAGUE) {
+            if let Some(parent_number) = self.context.block().number().checked_sub(1) {
+                let parent_hash = self.context.journal().db().block_hash(parent_number)?;
+                let data = Bytes::copy_from_slice(parent_hash.as_slice());
+                self.pre_block_system_call(BLOCKHASH_STORAGE_ADDRESS, data, &mut state)?;
+            }
+        }
+        Ok(state)
+    }
+
+    /// Calls `address` from the configured [`Cfg::system_caller`] with `data`.
+    ///
+    /// The call runs with [`Cfg::system_call_gas_limit`] gas. It is not validated, nobody pays
+    /// for its gas, the beneficiary is not rewarded and the system caller account is left
+    /// unchanged. The transaction of the context is restored afterwards.
+    pub fn system_call(
+        &mut self,
+        address: Address,
+        data: Bytes,
+    ) -> Result<ResultAndState<HALT>, ERROR> {
+        let caller = self.context.cfg().system_caller();
+        let gas_limit = self.context.cfg().system_call_gas_limit();
+
+        let tx = self.context.tx().clone();
+        self.context.set_tx(TxEnv {
+            caller,
+            transact_to: TxKind::Call(address),
+            data,
+            gas_limit,
+            ..Default::default()
+        });
+        let output = self.system_call_inner();
+        self.clear();
+        self.context.set_tx(tx);
+
+        output.map(|mut output| {
+            output.state.remove(&caller);
+            output
+        })
+    }
+
+    /// Executes `tx` with the gas limit of the current block, as `eth_call` does.
+    ///
+    /// The gas limit of `tx` is overwritten. As with `eth_call`, the transaction is not validated:
+    /// the caller balance, nonce and the base fee are not checked, see
+    /// [`Evm::transact_preverified`]. The changed state is returned but not committed, and a call
+    /// needing more gas than the block provides halts with
+    /// [`HaltReason::OutOfGas`](context_interface::result::HaltReason::OutOfGas).
+    pub fn call_with_block_gas_limit(&mut self, tx: TxEnv) -> Result<ResultAndState<HALT>, ERROR> {
+        let gas_limit = self.context.block().gas_limit();
+        self.context.set_tx(TxEnv { gas_limit, ..tx });
+        self.transact_preverified()
+    }
+
+    /// Performs the system calls done at the end of the block.
+    ///
+    /// Returns the dequeued requests and the changed state. The state should be committed
+    /// after the last transaction of the block.
+    pub fn apply_post_block(&mut self) -> Result<(BlockRequests, EvmState), ERROR> {
+        let mut requests = BlockRequests::default();
+        let mut state = EvmState::default();
+
+        let spec: SpecId = self.context.cfg().spec().into();
+        // EIP-7002: Execution layer triggerable withdrawals
+        // EIP-7251: Increase the MAX_EFFECTIVE_BALANCE
+        if spec.is_enabled_in(SpecId::PRAGUE) {
+            requests.withdrawals =
+                self.dequeue_requests(WITHDRAWAL_REQUEST_PREDEPLOY_ADDRESS, &mut state)?;
+            requests.consolidations =
+                self.dequeue_requests(CONSOLIDATION_REQUEST_PREDEPLOY_ADDRESS, &mut state)?;
+        }
+        Ok((requests, state))
+    }
+
+    /// Makes a system call at the start of the block and adds its changes to `state`.
+    ///
+    /// The call fails silently if the contract at `address` is not deployed, and fails the
+    /// block if its execution did not succeed.
+    fn pre_block_system_call(
+        &mut self,
+        address: Address,
+        data: Bytes,
+        state: &mut EvmState,
+    ) -> Result<(), ERROR> {
+        let output = self.system_call(address, data)?;
+        if !is_deployed(&output.state, address) {
+            return Ok(());
+        }
+        if !output.result.is_success() {
+            return Err(InvalidHeader::SystemCallFailed { address }.into());
+        }
+        state.extend(output.state);
+        Ok(())
+    }
+
+    /// Calls the request predeploy at `address` and returns its output.
+    ///
+    /// Fails if the predeploy is not deployed or its execution did not succeed.
+    fn dequeue_requests(&mut self, address: Address, state: &mut EvmState) -> Result<Bytes, ERROR> {
+        let output = self.system_call(address, Bytes::new())?;
+        if !is_deployed(&output.state, address) || !output.result.is_success() {
+            return Err(InvalidHeader::SystemCallFailed { address }.into());
+        }
+
+        let requests = output.result.output().cloned().unwrap_or_default();
+        state.extend(output.state);
+        Ok(requests)
+    }
+
+    fn system_call_inner(&mut self) -> Result<ResultAndState<HALT>, ERROR> {
+        let context = &mut self.context;
+        let gas_limit = context.tx().common_fields().gas_limit();
+        let spec = context.cfg().spec().into();
+        context.journal().set_spec_id(spec);
+
+        let exec = self.handler.execution();
+        let frame_result = match exec.init_first_frame(context, gas_limit)? {
+            FrameOrResultGen::Frame(frame) => exec.run(context, frame)?,
+            FrameOrResultGen::Result(result) => result,
+        };
+        let exec_result = exec.last_frame_result(context, frame_result)?;
+
+        self.handler.post_execution().output(context, exec_result)
+    }
+}
+
+/// Returns whether the system call that produced `state` ran code at `address`.
+fn is_deployed(state: &EvmState, address: Address) -> bool {
+    state
+        .get(&address)
+        .is_some_and(|account| !account.info.is_empty_code_hash())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{BlockRequests, Context, MainEvm};
+    use bytecode::{opcode, Bytecode};
+    use context::{tx::TxEnv, BlockEnv, CfgEnv};
+    use context_interface::{
+        block::BlobParams,
+        result::{
+            EVMError, ExecutionResult, HaltReason, InvalidHeader, InvalidTransaction,
+            ResultAndState,
+        },
+        transaction::TransactionType,
+        Block, DatabaseGetter,
+    };
+    use core::cell::RefCell;
+    use database::{states::bundle_state::BundleRetention, BenchmarkDB, InMemoryDB, State};
+    use database_interface::{Database, DatabaseCommit};
+    use handler::{
+        calculate_intrinsic_gas, deduct_caller_balance, execute_block, reimburse_caller_balance,
+        EthHandler,
+    };
+    use interpreter::{
+        interpreter::EthInterpreter,
+        interpreter_types::{LoopControl, StackTrait},
+        table::InstructionTableBuilder,
+        EmptyAccountCallResult, InputsImpl, InstructionResult, Interpreter, SharedMemory,
+    };
+    use precompile::bn128;
+    use primitives::{
+        alloy_primitives::BloomInput, bytes, hex, logs_bloom, Address, Bytes, TxKind, B256,
+        BEACON_ROOTS_ADDRESS, BEACON_ROOTS_HISTORY_BUFFER_LENGTH, BLOCKHASH_STORAGE_ADDRESS,
+        CONSOLIDATION_REQUEST_PREDEPLOY_ADDRESS, SYSTEM_ADDRESS, U256,
+        WITHDRAWAL_REQUEST_PREDEPLOY_ADDRESS,
+    };
+    use specification::{
+        eip2930::{AccessList, AccessListItem},
+        eip4844::{GAS_PER_BLOB, VERSIONED_HASH_VERSION_KZG},
+        hardfork::SpecId,
+    };
+    use state::AccountInfo;
+    use std::{
+        rc::Rc,
+        sync::{Arc, Mutex},
+    };
+
+    /// Bytecode that counts down from `0xFFFF` to zero, consuming roughly 1.7M gas.
+    fn countdown_bytecode() -> Bytecode {
+        Bytecode::new_raw(Bytes::from(vec![
+            opcode::PUSH2,
+            0xFF,
+            0xFF,
+            opcode::JUMPDEST,
+            opcode::PUSH1,
+            0x01,
+            opcode::SWAP1,
+            opcode::SUB,
+            opcode::DUP1,
+            opcode::PUSH1,
+            0x03,
+            opcode::JUMPI,
+            opcode::STOP,
+        ]))
+    }
+
+    #[test]
+    fn execute_block_commits_transactions() {
+        // Emits a single LOG1 with topic `0x2a` and empty data.
+        let bytecode = Bytecode::new_raw(Bytes::from(vec![
+            opcode::PUSH1,
+            0x2a,
+            opcode::PUSH1,
+            0x00,
+            opcode::PUSH1,
+            0x00,
+            opcode::LOG1,
+            opcode::STOP,
+        ]));
+        let contract = Address::with_last_byte(0xcc);
+        let caller = Address::with_last_byte(1);
+
+        let mut db = InMemoryDB::default();
+        db.insert_account_info(
+            contract,
+            AccountInfo::new(U256::ZERO, 1, bytecode.hash_slow(), bytecode),
+        );
+        db.insert_account_info(caller, AccountInfo::from_balance(U256::from(1_000_000)));
+
+        let mut evm = MainEvm::new(Context::builder().with_db(db), EthHandler::default());
+        // The second transaction only passes validation if the first one was committed.
+        let txs = (0..2).map(|nonce| TxEnv {
+            caller,
+            transact_to: TxKind::Call(contract),
+            nonce,
+            gas_limit: 100_000,
+            ..Default::default()
+        });
+        let block_env = BlockEnv {
+            number: 1,
+            ..Default::default()
+        };
+        let block = execute_block(&mut evm, block_env, txs).unwrap();
+        assert_eq!(evm.context.block.number, 1);
+
+        assert_eq!(block.receipts.len(), 2);
+        assert!(block.receipts.iter().all(|receipt| receipt.status));
+        let gas_used = block.receipts[0].gas_used;
+        assert!(gas_used > 21_000);
+        assert_eq!(block.receipts[1].cumulative_gas_used, 2 * gas_used);
+        assert_eq!(block.gas_used, 2 * gas_used);
+
+        assert_eq!(block.logs_bloom, logs_bloom(&block.receipts[0].logs));
+        assert!(block
+            .logs_bloom
+            .contains_input(BloomInput::Raw(contract.as_slice())));
+    }
+
+    #[test]
+    fn disabled_gas_metering() {
+        let context = Context::builder()
+            .with_db(BenchmarkDB::new_bytecode(countdown_bytecode()))
+            .modify_cfg_chained(|cfg| cfg.disable_gas_metering = true)
+            .modify_tx_chained(|tx| {
+                tx.caller = Address::with_last_byte(1);
+                tx.transact_to = TxKind::Call(Address::ZERO);
+                tx.gas_limit = 100_000;
+            });
+        let mut evm = MainEvm::new(context, EthHandler::default());
+
+        let result = evm.transact().unwrap().result;
+        assert!(result.is_success());
+        assert_eq!(result.gas_used(), 0);
+    }
+
+    #[test]
+    fn disabled_gas_metering_nested_call() {
+        let caller = Address::with_last_byte(1);
+        let entry = Address::with_last_byte(0x11);
+        let callee = Address::with_last_byte(0xaa);
+        // Calls `callee` with 100 gas and stores the success flag in slot zero.
+        let entry_code = Bytecode::new_raw(Bytes::from(vec![
+            opcode::PUSH1,
+            0x00,
+            opcode::PUSH1,
+            0x00,
+            opcode::PUSH1,
+            0x00,
+            opcode::PUSH1,
+            0x00,
+            opcode::PUSH1,
+            0x00,
+            opcode::PUSH1,
+            0xaa,
+            opcode::PUSH1,
+            0x64,
+            opcode::CALL,
+            opcode::PUSH1,
+            0x00,
+            opcode::SSTORE,
+            opcode::STOP,
+        ]));
+
+        let transact = |disable_gas_metering: bool| {
+            let mut db = InMemoryDB::default();
+            db.insert_account_info(caller, AccountInfo::from_balance(U256::from(10_000_000)));
+            for (address, bytecode) in [(entry, entry_code.clone()), (callee, countdown_bytecode())]
+            {
+                db.insert_account_info(
+                    address,
+                    AccountInfo::new(U256::ZERO, 1, bytecode.hash_slow(), bytecode),
+                );
+            }
+            let context = Context::builder()
+                .with_db(db)
+                .modify_cfg_chained(|cfg| cfg.disable_gas_metering = disable_gas_metering)
+                .modify_tx_chained(|tx| {
+                    tx.caller = caller;
+                    tx.transact_to = TxKind::Call(entry);
+                    tx.gas_limit = 100_000;
+                });
+            let mut evm = MainEvm::new(context, EthHandler::default());
+            let result = evm.transact().unwrap();
+            assert!(result.result.is_success());
+            result.state[&entry].storage[&U256::ZERO].present_value
+        };
+
+        // The loop runs out of the 100 gas given to the call unless gas is not metered.
+        assert_eq!(transact(false), U256::ZERO);
+        assert_eq!(transact(true), U256::from(1));
+    }
+
+    #[test]
+    fn fork_is_isolated() {
+        use crate::EvmCommit;
+
+        let caller = Address::with_last_byte(1);
+        let recipient = Address::with_last_byte(2);
+        let mut db = InMemoryDB::default();
+        db.insert_account_info(caller, AccountInfo::from_balance(U256::from(1_000_000)));
+
+        let context = Context::builder().with_db(db).modify_tx_chained(|tx| {
+            tx.caller = caller;
+            tx.transact_to = TxKind::Call(recipient);
+            tx.value = U256::from(100);
+            tx.gas_limit = 100_000;
+        });
+        let mut evm = MainEvm::new(context, EthHandler::default());
+        evm.handler.post_execution.revert_gas_hook = Some(|_, gas| gas.spent());
+
+        let mut fork = evm.fork();
+        assert!(fork.handler.post_execution.revert_gas_hook.is_some());
+        assert!(fork.exec_commit().unwrap().is_success());
+        let balance = |evm: &mut MainEvm<_, _, _, _>| {
+            evm.context
+                .db()
+                .basic(recipient)
+                .unwrap()
+                .map(|info| info.balance)
+        };
+        assert_eq!(balance(&mut fork), Some(U256::from(100)));
+        assert_eq!(balance(&mut evm), None);
+
+        // The original still executes from the state before the fork.
+        assert!(evm.exec_commit().unwrap().is_success());
+        assert_eq!(balance(&mut evm), Some(U256::from(100)));
+        assert_eq!(balance(&mut fork), Some(U256::from(100)));
+    }
+
+    #[test]
+    fn intrinsic_gas_includes_access_list() {
+        let mut tx = TxEnv {
+            tx_type: TransactionType::Eip2930,
+            transact_to: TxKind::Call(Address::with_last_byte(0xaa)),
+            ..Default::default()
+        };
+        let without = calculate_intrinsic_gas(&tx, SpecId::CANCUN);
+        assert_eq!(without, 21_000);
+
+        tx.access_list = AccessList(vec![
+            AccessListItem {
+                address: Address::with_last_byte(0xaa),
+                storage_keys: vec![B256::ZERO, B256::with_last_byte(1)],
+            },
+            AccessListItem {
+                address: Address::with_last_byte(0xbb),
+                storage_keys: vec![B256::ZERO],
+            },
+        ]);
+        let with = calculate_intrinsic_gas(&tx, SpecId::CANCUN);
+        // Two addresses at 2400 gas and three storage keys at 1900 gas.
+        assert_eq!(with - without, 2 * 2400 + 3 * 1900);
+
+        // Legacy transactions have no access list.
+        tx.tx_type = TransactionType::Legacy;
+        assert_eq!(calculate_intrinsic_gas(&tx, SpecId::CANCUN), without);
+    }
+
+    #[test]
+    fn with_spec_switches_base_fee_check() {
+        let context = Context::builder()
+            .with_db(BenchmarkDB::new_bytecode(Bytecode::new()))
+            .modify_block_chained(|block| block.basefee = 10)
+            .modify_tx_chained(|tx| {
+                tx.caller = Address::with_last_byte(1);
+                tx.transact_to = TxKind::Call(Address::ZERO);
+                tx.gas_limit = 100_000;
+            });
+        let mut evm = MainEvm::new(context, EthHandler::default()).with_spec(SpecId::ISTANBUL);
+        assert!(evm.transact().is_ok());
+
+        evm.set_spec(SpecId::LONDON);
+        assert!(matches!(
+            evm.transact(),
+            Err(EVMError::Transaction(
+                InvalidTransaction::GasPriceLessThanBasefee
+            ))
+        ));
+    }
+
+    #[test]
+    fn nonce_overflow_at_max() {
+        let caller = Address::with_last_byte(1);
+        let contract = Address::with_last_byte(0xaa);
+
+        // Senders at the maximum nonce are rejected.
+        let mut db = InMemoryDB::default();
+        db.insert_account_info(
+            caller,
+            AccountInfo {
+                nonce: u64::MAX,
+                ..Default::default()
+            },
+        );
+        let context = Context::builder().with_db(db).modify_tx_chained(|tx| {
+            tx.caller = caller;
+            tx.transact_to = TxKind::Call(contract);
+            tx.nonce = u64::MAX;
+            tx.gas_limit = 100_000;
+        });
+        let mut evm = MainEvm::new(context, EthHandler::default());
+        assert!(matches!(
+            evm.transact(),
+            Err(EVMError::Transaction(
+                InvalidTransaction::NonceOverflowInTransaction
+            ))
+        ));
+        // Unless nonce checks are disabled, then the nonce stays at the maximum.
+        evm.context.modify_cfg(|cfg| cfg.disable_nonce_check = true);
+        let ResultAndState { result, state } = evm.transact().unwrap();
+        assert!(result.is_success());
+        assert_eq!(state[&caller].info.nonce, u64::MAX);
+
+        // Creates twice from a contract one below the maximum nonce, storing the addresses.
+        let bytecode = Bytecode::new_raw(Bytes::from(vec![
+            opcode::PUSH1,
+            0x00,
+            opcode::PUSH1,
+            0x00,
+            opcode::PUSH1,
+            0x00,
+            opcode::CREATE,
+            opcode::PUSH1,
+            0x00,
+            opcode::SSTORE,
+            opcode::PUSH1,
+            0x00,
+            opcode::PUSH1,
+            0x00,
+            opcode::PUSH1,
+            0x00,
+            opcode::CREATE,
+            opcode::PUSH1,
+            0x01,
+            opcode::SSTORE,
+            opcode::STOP,
+        ]));
+        let mut db = InMemoryDB::default();
+        db.insert_account_info(
+            contract,
+            AccountInfo::new(U256::ZERO, u64::MAX - 1, bytecode.hash_slow(), bytecode),
+        );
+        let context = Context::builder().with_db(db).modify_tx_chained(|tx| {
+            tx.caller = caller;
+            tx.transact_to = TxKind::Call(contract);
+            tx.gas_limit = 1_000_000;
+        });
+        let mut evm = MainEvm::new(context, EthHandler::default());
+        let ResultAndState { result, state } = evm.transact().unwrap();
+        assert!(result.is_success());
+
+        let account = &state[&contract];
+        assert_eq!(account.info.nonce, u64::MAX);
+        let slot = |key: u64| account.storage[&U256::from(key)].present_value;
+        let created = contract.create(u64::MAX - 1);
+        assert_eq!(slot(0), created.into_word().into());
+        assert_eq!(slot(1), U256::ZERO);
+        // The second create halts, consuming the gas forwarded to it.
+        assert!(result.gas_used() > 900_000);
+    }
+
+    #[test]
+    fn zero_basefee_skips_base_fee() {
+        let caller = Address::with_last_byte(1);
+        let contract = Address::with_last_byte(0xaa);
+        let balance = U256::from(10_000_000);
+        // Stores BASEFEE in slot zero.
+        let bytecode = Bytecode::new_raw(Bytes::from(vec![
+            opcode::BASEFEE,
+            opcode::PUSH1,
+            0x00,
+            opcode::SSTORE,
+            opcode::STOP,
+        ]));
+
+        let transact = |zero_basefee: bool| {
+            let mut db = InMemoryDB::default();
+            db.insert_account_info(caller, AccountInfo::from_balance(balance));
+            db.insert_account_info(
+                contract,
+                AccountInfo::new(U256::ZERO, 1, bytecode.hash_slow(), bytecode.clone()),
+            );
+            let context = Context::builder()
+                .with_db(db)
+                .modify_block_chained(|block| {
+                    block.basefee = 10;
+                    block.zero_basefee = zero_basefee;
+                })
+                .modify_tx_chained(|tx| {
+                    tx.tx_type = TransactionType::Eip1559;
+                    tx.caller = caller;
+                    tx.transact_to = TxKind::Call(contract);
+                    tx.gas_limit = 100_000;
+                    tx.gas_price = U256::from(10);
+                    tx.gas_priority_fee = Some(U256::ZERO);
+                });
+            let mut evm = MainEvm::new(context, EthHandler::default());
+            let result = evm.transact().unwrap();
+            assert!(result.result.is_success());
+            (
+                result.state[&contract].storage[&U256::ZERO].present_value,
+                result.state[&caller].info.balance,
+            )
+        };
+
+        assert_eq!(
+            transact(false),
+            (U256::from(10), balance - U256::from(10 * 43_105))
+        );
+        assert_eq!(transact(true), (U256::ZERO, balance));
+    }
+
+    #[test]
+    fn disabled_call_gas_retention() {
+        // Recursively calls itself forwarding all remaining gas.
+        let bytecode = Bytecode::new_raw(Bytes::from(vec![
+            opcode::PUSH1,
+            0x00,
+            opcode::PUSH1,
+            0x00,
+            opcode::PUSH1,
+            0x00,
+            opcode::PUSH1,
+            0x00,
+            opcode::PUSH1,
+            0x00,
+            opcode::ADDRESS,
+            opcode::GAS,
+            opcode::CALL,
+            opcode::STOP,
+        ]));
+        let context = Context::builder()
+            .with_db(BenchmarkDB::new_bytecode(bytecode))
+            .modify_tx_chained(|tx| {
+                tx.caller = Address::with_last_byte(1);
+                tx.transact_to = TxKind::Call(Address::ZERO);
+            });
+        let mut evm = MainEvm::new(context, EthHandler::default());
+
+        // With the 63/64 rule each level retains gas, so recursion ends gracefully.
+        let result = evm.transact().unwrap().result;
+        assert!(result.is_success());
+
+        // Without it the requested gas exceeds the remaining gas after the call cost.
+        evm.context
+            .modify_cfg(|cfg| cfg.disable_call_gas_retention = true);
+        let result = evm.transact().unwrap().result;
+        assert!(matches!(
+            result,
+            ExecutionResult::Halt {
+                reason: HaltReason::OutOfGas(_),
+                ..
+            }
+        ));
+    }
+
+    #[test]
+    fn custom_blob_params() {
+        let params = BlobParams {
+            target_blob_gas_per_block: GAS_PER_BLOB,
+            max_blob_gas_per_block: GAS_PER_BLOB,
+            update_fraction: 2,
+        };
+        let mut blob_hash = B256::ZERO;
+        blob_hash[0] = VERSIONED_HASH_VERSION_KZG;
+
+        let context = Context::builder()
+            .with_db(BenchmarkDB::new_bytecode(Bytecode::new()))
+            .modify_cfg_chained(|cfg| cfg.blob_params = params)
+            .modify_block_chained(|block| {
+                block.set_blob_excess_gas_and_price_with_params(5, &params)
+            })
+            .modify_tx_chained(|tx| {
+                tx.tx_type = TransactionType::Eip4844;
+                tx.caller = Address::with_last_byte(1);
+                tx.transact_to = TxKind::Call(Address::ZERO);
+                tx.gas_limit = 100_000;
+                tx.blob_hashes = vec![blob_hash];
+                tx.max_fee_per_blob_gas = Some(U256::from(11));
+            });
+        let mut evm = MainEvm::new(context, EthHandler::default());
+        assert_eq!(evm.context.block.blob_gasprice(), Some(11));
+        assert!(evm.transact().is_ok());
+
+        evm.context
+            .modify_tx(|tx| tx.max_fee_per_blob_gas = Some(U256::from(10)));
+        assert!(matches!(
+            evm.transact(),
+            Err(EVMError::Transaction(
+                InvalidTransaction::BlobGasPriceGreaterThanMax
+            ))
+        ));
+
+        evm.context.modify_tx(|tx| {
+            tx.max_fee_per_blob_gas = Some(U256::from(11));
+            tx.blob_hashes = vec![blob_hash; 2];
+        });
+        assert!(matches!(
+            evm.transact(),
+            Err(EVMError::Transaction(InvalidTransaction::TooManyBlobs {
+                have: 2,
+                max: 1
+            }))
+        ));
+
+        // Blob gas price computed with the mainnet schedule.
+        evm.context.modify_block(|block| {
+            block.set_blob_excess_gas_and_price(5);
+        });
+        assert!(matches!(
+            evm.transact(),
+            Err(EVMError::Header(InvalidHeader::BlobGasPriceMismatch))
+        ));
+    }
+
+    #[test]
+    fn max_calldata_size_rejects_oversized_input() {
+        let context = Context::builder()
+            .with_db(BenchmarkDB::new_bytecode(Bytecode::new()))
+            .modify_cfg_chained(|cfg| cfg.max_calldata_size = Some(4))
+            .modify_tx_chained(|tx| {
+                tx.caller = Address::with_last_byte(1);
+                tx.transact_to = TxKind::Call(Address::ZERO);
+                tx.gas_limit = 100_000;
+                tx.data = bytes!("01020304");
+            });
+        let mut evm = MainEvm::new(context, EthHandler::default());
+        assert!(evm.transact().is_ok());
+
+        evm.context.modify_tx(|tx| tx.data = bytes!("0102030405"));
+        assert!(matches!(
+            evm.transact(),
+            Err(EVMError::Transaction(
+                InvalidTransaction::CalldataSizeLimit { max: 4, have: 5 }
+            ))
+        ));
+    }
+
+    #[test]
+    fn blockhash_served_from_history_contract() {
+        // Stores the hash of block 700 at slot zero.
+        let bytecode = Bytecode::new_raw(Bytes::from(vec![
+            opcode::PUSH2,
+            0x02,
+            0xbc,
+            opcode::BLOCKHASH,
+            opcode::PUSH1,
+            0x00,
+            opcode::SSTORE,
+            opcode::STOP,
+        ]));
+        // Stores the calldata at slot `(NUMBER - 1) % 8192`, like the history contract.
+        let history = Bytecode::new_raw(Bytes::from(vec![
+            opcode::PUSH1,
+            0x00,
+            opcode::CALLDATALOAD,
+            opcode::PUSH2,
+            0x20,
+            0x00,
+            opcode::PUSH1,
+            0x01,
+            opcode::NUMBER,
+            opcode::SUB,
+            opcode::MOD,
+            opcode::SSTORE,
+            opcode::STOP,
+        ]));
+        let contract = Address::with_last_byte(0xcc);
+        let caller = Address::with_last_byte(1);
+
+        let mut db = InMemoryDB::default();
+        db.insert_account_info(
+            contract,
+            AccountInfo::new(U256::ZERO, 1, bytecode.hash_slow(), bytecode),
+        );
+        db.insert_account_info(
+            BLOCKHASH_STORAGE_ADDRESS,
+            AccountInfo::new(U256::ZERO, 1, history.hash_slow(), history),
+        );
+        db.insert_account_info(caller, AccountInfo::from_balance(U256::from(1_000_000)));
+        let expected = U256::from_be_bytes(db.block_hash(700).unwrap().0);
+
+        let context = Context::builder()
+            .with_db(db)
+            .modify_cfg_chained(|cfg| cfg.spec = SpecId::PRAGUE)
+            .modify_block_chained(|block| block.number = 701)
+            .modify_tx_chained(|tx| {
+                tx.caller = caller;
+                tx.transact_to = TxKind::Call(contract);
+                tx.gas_limit = 100_000;
+            });
+        let mut evm = MainEvm::new(context, EthHandler::default());
+
+        let state = evm.apply_pre_block().unwrap();
+        assert_eq!(
+            state[&BLOCKHASH_STORAGE_ADDRESS].storage[&U256::from(700)].present_value,
+            expected
+        );
+        evm.context.db().commit(state);
+
+        // Block 700 is out of the BLOCKHASH window and is served from the history contract,
+        // without loading it into the transaction state.
+        evm.context.modify_block(|block| block.number = 1000);
+        let state = evm.transact().unwrap().state;
+        assert_eq!(
+            state[&contract].storage[&U256::ZERO].present_value,
+            expected
+        );
+        assert!(!state.contains_key(&BLOCKHASH_STORAGE_ADDRESS));
+
+        evm.set_spec(SpecId::CANCUN);
+        let state = evm.transact().unwrap().state;
+        assert_eq!(
+            state[&contract].storage[&U256::ZERO].present_value,
+            U256::ZERO
+        );
+    }
+
+    #[test]
+    fn pre_block_skips_undeployed_contracts() {
+        let context = Context::builder()
+            .with_db(InMemoryDB::default())
+            .modify_cfg_chained(|cfg| cfg.spec = SpecId::PRAGUE)
+            .modify_block_chained(|block| {
+                block.number = 701;
+                block.parent_beacon_block_root = Some(B256::with_last_byte(0x42));
+            });
+        let mut evm = MainEvm::new(context, EthHandler::default());
+
+        assert!(evm.apply_pre_block().unwrap().is_empty());
+    }
+
+    #[test]
+    fn beacon_root_written_at_block_start() {
+        // Runtime code of the EIP-4788 beacon roots contract.
+        let bytecode = Bytecode::new_raw(bytes!("3373fffffffffffffffffffffffffffffffffffffffe14604d57602036146024575f5ffd5b5f35801560495762001fff810690815414603c575f5ffd5b62001fff01545f5260205ff35b5f5ffd5b62001fff42064281555f359062001fff015500"));
+        let caller = Address::with_last_byte(1);
+        let root = B256::with_last_byte(0x42);
+        let timestamp = 12;
+
+        let mut db = InMemoryDB::default();
+        db.insert_account_info(
+            BEACON_ROOTS_ADDRESS,
+            AccountInfo::new(U256::ZERO, 1, bytecode.hash_slow(), bytecode),
+        );
+        db.insert_account_info(caller, AccountInfo::from_balance(U256::from(1_000_000)));
+
+        let context = Context::builder()
+            .with_db(db)
+            .modify_cfg_chained(|cfg| cfg.spec = SpecId::CANCUN)
+            .modify_block_chained(|block| {
+                block.timestamp = timestamp;
+                block.parent_beacon_block_root = Some(root);
+            })
+            .modify_tx_chained(|tx| {
+                tx.caller = caller;
+                tx.transact_to = TxKind::Call(BEACON_ROOTS_ADDRESS);
+                tx.data = Bytes::copy_from_slice(&U256::from(timestamp).to_be_bytes::<32>());
+                tx.gas_limit = 100_000;
+            });
+        let mut evm = MainEvm::new(context, EthHandler::default());
+
+        let state = evm.apply_pre_block().unwrap();
+        assert!(!state.contains_key(&SYSTEM_ADDRESS));
+        let storage = &state[&BEACON_ROOTS_ADDRESS].storage;
+        assert_eq!(
+            storage[&U256::from(timestamp)].present_value,
+            U256::from(timestamp)
+        );
+        assert_eq!(
+            storage[&U256::from(timestamp + BEACON_ROOTS_HISTORY_BUFFER_LENGTH)].present_value,
+            U256::from_be_bytes(root.0)
+        );
+        evm.context.db().commit(state);
+
+        // The contract returns the root for the block timestamp.
+        let result = evm.transact().unwrap().result;
+        assert_eq!(
+            result.output(),
+            Some(&Bytes::copy_from_slice(root.as_slice()))
+        );
+
+        // The call is made by the configured system caller, which the contract rejects.
+        evm.context
+            .modify_cfg(|cfg| cfg.system_caller = Address::with_last_byte(0x5c));
+        assert!(matches!(
+            evm.apply_pre_block(),
+            Err(EVMError::Header(InvalidHeader::SystemCallFailed {
+                address: BEACON_ROOTS_ADDRESS
+            }))
+        ));
+    }
+
+    #[test]
+    fn post_block_requests_dequeued() {
+        // Returns the request stored at slot zero and clears it.
+        let bytecode = Bytecode::new_raw(Bytes::from(vec![
+            opcode::PUSH1,
+            0x00,
+            opcode::SLOAD,
+            opcode::PUSH1,
+            0x00,
+            opcode::MSTORE,
+            opcode::PUSH1,
+            0x00,
+            opcode::PUSH1,
+            0x00,
+            opcode::SSTORE,
+            opcode::PUSH1,
+            0x20,
+            opcode::PUSH1,
+            0x00,
+            opcode::RETURN,
+        ]));
+        let withdrawal = U256::from(0x7002);
+        let consolidation = U256::from(0x7251);
+
+        let mut db = InMemoryDB::default();
+        for (address, request) in [
+            (WITHDRAWAL_REQUEST_PREDEPLOY_ADDRESS, withdrawal),
+            (CONSOLIDATION_REQUEST_PREDEPLOY_ADDRESS, consolidation),
+        ] {
+            db.insert_account_info(
+                address,
+                AccountInfo::new(U256::ZERO, 1, bytecode.hash_slow(), bytecode.clone()),
+            );
+            db.insert_account_storage(address, U256::ZERO, request)
+                .unwrap();
+        }
+        let context = Context::builder()
+            .with_db(db)
+            .modify_cfg_chained(|cfg| cfg.spec = SpecId::PRAGUE);
+        let mut evm = MainEvm::new(context, EthHandler::default());
+
+        let (requests, state) = evm.apply_post_block().unwrap();
+        assert_eq!(
+            requests,
+            BlockRequests {
+                withdrawals: Bytes::copy_from_slice(&withdrawal.to_be_bytes::<32>()),
+                consolidations: Bytes::copy_from_slice(&consolidation.to_be_bytes::<32>()),
+            }
+        );
+        assert!(!state.contains_key(&SYSTEM_ADDRESS));
+        evm.context.db().commit(state);
+
+        // Both queues are empty once dequeued.
+        let (requests, _) = evm.apply_post_block().unwrap();
+        assert_eq!(requests.withdrawals, Bytes::from(vec![0; 32]));
+        assert_eq!(requests.consolidations, Bytes::from(vec![0; 32]));
+    }
+
+    #[test]
+    fn system_call_leaves_caller_and_coinbase_unchanged() {
+        // Stores the caller at slot zero.
+        let bytecode = Bytecode::new_raw(Bytes::from(vec![
+            opcode::CALLER,
+            opcode::PUSH1,
+            0x00,
+            opcode::SSTORE,
+            opcode::STOP,
+        ]));
+        let contract = Address::with_last_byte(0xcc);
+        let system_caller = Address::with_last_byte(0x5c);
+        let coinbase = Address::with_last_byte(0xcb);
+        let balance = U256::from(1_000_000);
+
+        let mut db = InMemoryDB::default();
+        db.insert_account_info(
+            contract,
+            AccountInfo::new(U256::ZERO, 1, bytecode.hash_slow(), bytecode),
+        );
+        db.insert_account_info(system_caller, AccountInfo::from_balance(balance));
+        db.insert_account_info(coinbase, AccountInfo::from_balance(balance));
+
+        let context = Context::builder()
+            .with_db(db)
+            .modify_cfg_chained(|cfg| {
+                cfg.system_caller = system_caller;
+                cfg.system_call_gas_limit = 100_000;
+            })
+            .modify_block_chained(|block| {
+                block.beneficiary = coinbase;
+                block.basefee = 10;
+            });
+        let mut evm = MainEvm::new(context, EthHandler::default());
+
+        let output = evm.system_call(contract, Bytes::new()).unwrap();
+        assert!(output.result.is_success());
+        assert_eq!(
+            output.state[&contract].storage[&U256::ZERO].present_value,
+            U256::from_be_slice(system_caller.as_slice())
+        );
+        assert!(!output.state.contains_key(&system_caller));
+        evm.context.db().commit(output.state);
+
+        for address in [system_caller, coinbase] {
+            let account = evm.context.db().basic(address).unwrap().unwrap();
+            assert_eq!(account.balance, balance);
+            assert_eq!(account.nonce, 0);
+        }
+    }
+
+    #[test]
+    fn max_refund_quotient_caps_refund() {
+        // Clears storage slots zero to four.
+        let mut code = Vec::new();
+        for slot in 0..5 {
+            code.extend([opcode::PUSH1, 0x00, opcode::PUSH1, slot, opcode::SSTORE]);
+        }
+        code.push(opcode::STOP);
+        let bytecode = Bytecode::new_raw(Bytes::from(code));
+        let contract = Address::with_last_byte(0xcc);
+        let caller = Address::with_last_byte(0x01);
+
+        let mut db = InMemoryDB::default();
+        db.insert_account_info(
+            contract,
+            AccountInfo::new(U256::ZERO, 1, bytecode.hash_slow(), bytecode),
+        );
+        for slot in 0..5 {
+            db.insert_account_storage(contract, U256::from(slot), U256::from(1))
+                .unwrap();
+        }
+        db.insert_account_info(caller, AccountInfo::from_balance(U256::from(1_000_000)));
+
+        let gas_refunded = |quotient| {
+            let context = Context::builder()
+                .with_db(db.clone())
+                .modify_cfg_chained(|cfg| cfg.max_refund_quotient = quotient)
+                .modify_tx_chained(|tx| {
+                    tx.caller = caller;
+                    tx.transact_to = TxKind::Call(contract);
+                    tx.gas_limit = 100_000;
+                });
+            let mut evm = MainEvm::new(context, EthHandler::default());
+            let ExecutionResult::Success {
+                gas_used,
+                gas_refunded,
+                ..
+            } = evm.transact().unwrap().result
+            else {
+                panic!("transaction failed");
+            };
+            (gas_used + gas_refunded, gas_refunded)
+        };
+
+        // Refund exceeds both caps, so it is capped by the quotient.
+        let (spent, default_refund) = gas_refunded(None);
+        assert_eq!(default_refund, spent / 5);
+        assert_eq!(gas_refunded(Some(5)), (spent, spent / 5));
+        assert_eq!(gas_refunded(Some(2)), (spent, spent / 2));
+        assert_ne!(spent / 2, spent / 5);
+        // A zero quotient disables the refund instead of dividing by zero.
+        assert_eq!(gas_refunded(Some(0)), (spent, 0));
+    }
+
+    #[test]
+    fn gas_used_before_refund() {
+        // Clears storage slot zero.
+        let bytecode = Bytecode::new_raw(Bytes::from(vec![
+            opcode::PUSH1,
+            0x00,
+            opcode::PUSH1,
+            0x00,
+            opcode::SSTORE,
+            opcode::STOP,
+        ]));
+        let contract = Address::with_last_byte(0xcc);
+        let caller = Address::with_last_byte(0x01);
+
+        let mut db = InMemoryDB::default();
+        db.insert_account_info(
+            contract,
+            AccountInfo::new(U256::ZERO, 1, bytecode.hash_slow(), bytecode),
+        );
+        db.insert_account_storage(contract, U256::ZERO, U256::from(1))
+            .unwrap();
+        db.insert_account_info(caller, AccountInfo::from_balance(U256::from(1_000_000)));
+
+        let context = Context::builder().with_db(db).modify_tx_chained(|tx| {
+            tx.caller = caller;
+            tx.transact_to = TxKind::Call(contract);
+            tx.gas_limit = 100_000;
+        });
+        let mut evm = MainEvm::new(context, EthHandler::default());
+        let result = evm.transact().unwrap().result;
+        let ExecutionResult::Success { gas_refunded, .. } = result else {
+            panic!("transaction failed");
+        };
+
+        assert!(gas_refunded > 0);
+        assert_eq!(
+            result.gas_used_before_refund(),
+            result.gas_used() + gas_refunded
+        );
+    }
+
+    #[test]
+    fn call_base_cost_override() {
+        // Calls the identity precompile.
+        let bytecode = Bytecode::new_raw(Bytes::from(vec![
+            opcode::PUSH1,
+            0x00,
+            opcode::PUSH1,
+            0x00,
+            opcode::PUSH1,
+            0x00,
+            opcode::PUSH1,
+            0x00,
+            opcode::PUSH1,
+            0x00,
+            opcode::PUSH1,
+            0x04,
+            opcode::GAS,
+            opcode::CALL,
+            opcode::STOP,
+        ]));
+        let gas_used = |call_base_cost| {
+            let context = Context::builder()
+                .with_db(BenchmarkDB::new_bytecode(bytecode.clone()))
+                .modify_cfg_chained(|cfg| cfg.call_base_cost = call_base_cost)
+                .modify_tx_chained(|tx| {
+                    tx.caller = Address::with_last_byte(1);
+                    tx.transact_to = TxKind::Call(Address::ZERO);
+                    tx.gas_limit = 100_000;
+                });
+            let mut evm = MainEvm::new(context, EthHandler::default());
+            let result = evm.transact().unwrap().result;
+            assert!(result.is_success());
+            result.gas_used()
+        };
+
+        let default = gas_used(None);
+        // Precompiles are warm, so the default base cost is the warm access cost.
+        assert_eq!(gas_used(Some(100)), default);
+        assert_eq!(gas_used(Some(1_100)), default + 1_000);
+    }
+
+    #[test]
+    fn deduct_caller_hook_redirects_payment() {
+        const PAYMASTER: Address = Address::with_last_byte(0xbb);
+        let caller = Address::with_last_byte(1);
+        let receiver = Address::with_last_byte(0xaa);
+
+        // The caller can only afford the value, not the gas.
+        let mut db = InMemoryDB::default();
+        db.insert_account_info(caller, AccountInfo::from_balance(U256::from(1_000)));
+        db.insert_account_info(PAYMASTER, AccountInfo::from_balance(U256::from(1_000_000)));
+        let context = Context::builder().with_db(db).modify_tx_chained(|tx| {
+            tx.caller = caller;
+            tx.transact_to = TxKind::Call(receiver);
+            tx.value = U256::from(100);
+            tx.gas_price = U256::from(10);
+            tx.gas_limit = 50_000;
+        });
+        let mut evm = MainEvm::new(
+            context,
+            EthHandler::default().with_gas_payment_hooks(
+                |context, payer, amount| {
+                    assert_eq!(payer, Address::with_last_byte(1));
+                    deduct_caller_balance(context, PAYMASTER, amount)
+                },
+                |context, payer, amount| {
+                    assert_eq!(payer, Address::with_last_byte(1));
+                    reimburse_caller_balance(context, PAYMASTER, amount)
+                },
+            ),
+        );
+
+        let ResultAndState { result, state } = evm.transact().unwrap();
+        assert!(result.is_success());
+        assert_eq!(result.gas_used(), 21_000);
+        assert_eq!(state[&caller].info.balance, U256::from(1_000 - 100));
+        assert_eq!(state[&caller].info.nonce, 1);
+        assert_eq!(
+            state[&PAYMASTER].info.balance,
+            U256::from(1_000_000 - 21_000 * 10)
+        );
+        assert_eq!(state[&receiver].info.balance, U256::from(100));
+    }
+
+    #[test]
+    fn empty_account_call_intercepted() {
+        const VIRTUAL: Address = Address::with_last_byte(0xff);
+        let transact = |to| {
+            let context = Context::builder()
+                .with_db(InMemoryDB::default())
+                .with_empty_account_call_handler(|address, input, _gas_limit| {
+                    (address == VIRTUAL).then(|| EmptyAccountCallResult {
+                        success: true,
+                        gas_used: 100,
+                        output: input.iter().rev().copied().collect(),
+                    })
+                })
+                .modify_tx_chained(|tx| {
+                    tx.caller = Address::with_last_byte(1);
+                    tx.transact_to = TxKind::Call(to);
+                    tx.data = bytes!("0102");
+                    tx.gas_limit = 100_000;
+                });
+            let mut evm = MainEvm::new(context, EthHandler::default());
+            evm.transact().unwrap().result
+        };
+
+        let intercepted = transact(VIRTUAL);
+        assert!(intercepted.is_success());
+        assert_eq!(intercepted.output(), Some(&bytes!("0201")));
+
+        let empty = transact(Address::with_last_byte(0xaa));
+        assert!(empty.is_success());
+        assert_eq!(empty.output(), Some(&Bytes::new()));
+        assert_eq!(intercepted.gas_used(), empty.gas_used() + 100);
+    }
+
+    #[test]
+    fn zero_address_call_burns_value() {
+        let caller = Address::with_last_byte(1);
+        let burned = Arc::new(Mutex::new(Vec::new()));
+        let sink = burned.clone();
+
+        let mut db = InMemoryDB::default();
+        db.insert_account_info(caller, AccountInfo::from_balance(U256::from(1_000_000)));
+        let context = Context::builder()
+            .with_db(db)
+            .with_zero_address_call_handler(move |caller, value, _input, _gas_limit| {
+                sink.lock().unwrap().push((caller, value));
+                EmptyAccountCallResult {
+                    success: true,
+                    gas_used: 0,
+                    output: Bytes::new(),
+                }
+            })
+            .modify_tx_chained(|tx| {
+                tx.caller = caller;
+                tx.transact_to = TxKind::Call(Address::ZERO);
+                tx.value = U256::from(100);
+                tx.gas_limit = 100_000;
+            });
+        let mut evm = MainEvm::new(context, EthHandler::default());
+
+        assert!(evm.transact().unwrap().result.is_success());
+        assert_eq!(*burned.lock().unwrap(), vec![(caller, U256::from(100))]);
+
+        // Calls to other addresses are not routed to the handler.
+        evm.context
+            .modify_tx(|tx| tx.transact_to = TxKind::Call(Address::with_last_byte(0xaa)));
+        assert!(evm.transact().unwrap().result.is_success());
+        assert_eq!(burned.lock().unwrap().len(), 1);
+    }
+
+    #[test]
+    #[cfg(feature = "optional_create_nonce")]
+    fn create_nonce_override_collides() {
+        use crate::EvmCommit;
+
+        let caller = Address::with_last_byte(1);
+        let context = Context::builder()
+            .with_db(InMemoryDB::default())
+            .modify_cfg_chained(|cfg| cfg.create_nonce_override = Some(7))
+            .modify_tx_chained(|tx| {
+                tx.caller = caller;
+                tx.transact_to = TxKind::Create;
+                tx.gas_limit = 100_000;
+            });
+        let mut evm = MainEvm::new(context, EthHandler::default());
+
+        let first = evm.exec_commit().unwrap();
+        assert_eq!(first.created_address(), Some(caller.create(7)));
+
+        evm.context.modify_tx(|tx| tx.nonce = 1);
+        let second = evm.exec_commit().unwrap();
+        assert!(matches!(
+            second,
+            ExecutionResult::Halt {
+                reason: HaltReason::CreateCollision,
+                ..
+            }
+        ));
+    }
+
+    #[test]
+    fn revert_gas_hook_charges_policy() {
+        let caller = Address::with_last_byte(1);
+        let contract = Address::with_last_byte(0xaa);
+        let transact = |half_refund: bool| {
+            let mut db = InMemoryDB::default();
+            db.insert_account_info(caller, AccountInfo::from_balance(U256::from(1_000_000)));
+            // Reverts without output.
+            let bytecode = Bytecode::new_raw(Bytes::from_static(&[
+                opcode::PUSH1,
+                0x00,
+                opcode::PUSH1,
+                0x00,
+                opcode::REVERT,
+            ]));
+            db.insert_account_info(
+                contract,
+                AccountInfo::new(U256::ZERO, 1, bytecode.hash_slow(), bytecode),
+            );
+            let context = Context::builder().with_db(db).modify_tx_chained(|tx| {
+                tx.caller = caller;
+                tx.transact_to = TxKind::Call(contract);
+                tx.gas_price = U256::from(1);
+                tx.gas_limit = 100_000;
+            });
+            let mut evm = MainEvm::new(context, EthHandler::default());
+            if half_refund {
+                // Only half of the unused gas is reimbursed.
+                evm.handler.post_execution.revert_gas_hook =
+                    Some(|_, gas| gas.spent() + gas.remaining() / 2);
+            }
+            evm.transact().unwrap()
+        };
+
+        let ResultAndState { result, state } = transact(false);
+        assert!(matches!(result, ExecutionResult::Revert { .. }));
+        assert_eq!(result.gas_used(), 21_006);
+        assert_eq!(state[&caller].info.balance, U256::from(1_000_000 - 21_006));
+
+        let charged = 21_006 + (100_000 - 21_006) / 2;
+        let ResultAndState { result, state } = transact(true);
+        assert!(matches!(result, ExecutionResult::Revert { .. }));
+        assert_eq!(result.gas_used(), charged);
+        assert_eq!(state[&caller].info.balance, U256::from(1_000_000 - charged));
+    }
+
+    #[test]
+    fn force_cold_access_prices_repeated_sloads_cold() {
+        // Loads slot zero twice.
+        let bytecode = Bytecode::new_raw(Bytes::from(vec![
+            opcode::PUSH1,
+            0x00,
+            opcode::SLOAD,
+            opcode::POP,
+            opcode::PUSH1,
+            0x00,
+            opcode::SLOAD,
+            opcode::POP,
+            opcode::STOP,
+        ]));
+        let gas_used = |force_cold_access| {
+            let context = Context::builder()
+                .with_db(BenchmarkDB::new_bytecode(bytecode.clone()))
+                .modify_cfg_chained(|cfg| cfg.force_cold_access = force_cold_access)
+                .modify_tx_chained(|tx| {
+                    tx.caller = Address::with_last_byte(1);
+                    tx.transact_to = TxKind::Call(Address::ZERO);
+                    tx.gas_limit = 100_000;
+                });
+            let mut evm = MainEvm::new(context, EthHandler::default());
+            let result = evm.transact().unwrap().result;
+            assert!(result.is_success());
+            result.gas_used()
+        };
+
+        // PUSH1 and POP cost 5 per load.
+        assert_eq!(gas_used(false), 21_000 + 2 * 5 + 2_100 + 100);
+        assert_eq!(gas_used(true), 21_000 + 2 * 5 + 2 * 2_100);
+    }
+
+    #[test]
+    fn seeded_transient_storage_is_read() {
+        // Stores transient slot one into storage slot zero.
+        let bytecode = Bytecode::new_raw(Bytes::from(vec![
+            opcode::PUSH1,
+            0x01,
+            opcode::TLOAD,
+            opcode::PUSH1,
+            0x00,
+            opcode::SSTORE,
+            opcode::STOP,
+        ]));
+        let context = Context::builder()
+            .with_db(BenchmarkDB::new_bytecode(bytecode))
+            .modify_cfg_chained(|cfg| cfg.spec = SpecId::CANCUN)
+            .modify_tx_chained(|tx| {
+                tx.caller = Address::with_last_byte(1);
+                tx.transact_to = TxKind::Call(Address::ZERO);
+                tx.gas_limit = 100_000;
+            });
+        let mut evm = MainEvm::new(context, EthHandler::default());
+        evm.context
+            .set_transient(Address::ZERO, U256::from(1), U256::from(42));
+        assert_eq!(
+            evm.context.get_transient(Address::ZERO, U256::from(1)),
+            U256::from(42)
+        );
+
+        let output = evm.transact().unwrap();
+        assert!(output.result.is_success());
+        assert_eq!(
+            output.state[&Address::ZERO].storage[&U256::ZERO].present_value,
+            U256::from(42)
+        );
+        // Transient storage does not outlive the transaction.
+        assert_eq!(
+            evm.context.get_transient(Address::ZERO, U256::from(1)),
+            U256::ZERO
+        );
+    }
+
+    #[test]
+    fn max_logs_per_tx_halts_at_limit() {
+        // Emits empty logs in an infinite loop.
+        let bytecode = Bytecode::new_raw(Bytes::from(vec![
+            opcode::JUMPDEST,
+            opcode::PUSH1,
+            0x00,
+            opcode::PUSH1,
+            0x00,
+            opcode::LOG0,
+            opcode::PUSH1,
+            0x00,
+            opcode::JUMP,
+        ]));
+        let context = Context::builder()
+            .with_db(BenchmarkDB::new_bytecode(bytecode))
+            .modify_cfg_chained(|cfg| cfg.max_logs_per_tx = Some(3))
+            .modify_tx_chained(|tx| {
+                tx.caller = Address::with_last_byte(1);
+                tx.transact_to = TxKind::Call(Address::ZERO);
+                tx.gas_limit = 100_000;
+            });
+        let mut evm = MainEvm::new(context, EthHandler::default());
+
+        assert!(matches!(
+            evm.transact().unwrap().result,
+            ExecutionResult::Halt {
+                reason: HaltReason::LogLimitExceeded,
+                gas_used: 100_000,
+            }
+        ));
+    }
+
+    #[test]
+    fn selfbalance_is_not_priced_cold() {
+        // Returns the balance of the executing account.
+        let bytecode = Bytecode::new_raw(Bytes::from(vec![
+            opcode::SELFBALANCE,
+            opcode::PUSH1,
+            0x00,
+            opcode::MSTORE,
+            opcode::PUSH1,
+            0x20,
+            opcode::PUSH1,
+            0x00,
+            opcode::RETURN,
+        ]));
+        let context = Context::builder()
+            .with_db(BenchmarkDB::new_bytecode(bytecode))
+            .modify_cfg_chained(|cfg| cfg.force_cold_access = true)
+            .modify_tx_chained(|tx| {
+                tx.caller = Address::with_last_byte(1);
+                tx.transact_to = TxKind::Call(Address::ZERO);
+                tx.gas_limit = 100_000;
+            });
+        let mut evm = MainEvm::new(context, EthHandler::default());
+
+        let result = evm.transact().unwrap().result;
+        // SELFBALANCE costs 5, the rest 15 with one word of memory.
+        assert_eq!(result.gas_used(), 21_000 + 5 + 15);
+        assert_eq!(
+            result.output().map(|output| U256::from_be_slice(output)),
+            Some(U256::from(10_000_000))
+        );
+    }
+
+    #[test]
+    fn identity_per_word_gas_override() {
+        // 32 words of input.
+        let input = Bytes::from(vec![0xab; 1024]);
+        let gas_used = |identity_per_word_gas| {
+            let context = Context::builder()
+                .with_db(BenchmarkDB::new_bytecode(Bytecode::new()))
+                .modify_cfg_chained(|cfg| cfg.identity_per_word_gas = identity_per_word_gas)
+                .modify_tx_chained(|tx| {
+                    tx.caller = Address::with_last_byte(1);
+                    tx.transact_to = TxKind::Call(Address::with_last_byte(4));
+                    tx.data = input.clone();
+                    tx.gas_limit = 100_000;
+                });
+            let mut evm = MainEvm::new(context, EthHandler::default());
+            let result = evm.transact().unwrap().result;
+            assert_eq!(result.output(), Some(&input));
+            result.gas_used()
+        };
+
+        let default = gas_used(None);
+        assert_eq!(default, gas_used(Some(3)));
+        assert_eq!(gas_used(Some(10)), default + 32 * (10 - 3));
+    }
+
+    #[test]
+    fn precompile_max_input_size_guard() {
+        let modexp = Address::with_last_byte(5);
+        // Zero base, exponent and modulus lengths followed by padding.
+        let input = Bytes::from(vec![0; 200]);
+        let result = |max_input_size: Option<usize>| {
+            let context = Context::builder()
+                .with_db(BenchmarkDB::new_bytecode(Bytecode::new()))
+                .modify_cfg_chained(|cfg| {
+                    cfg.precompile_max_input_size
+                        .extend(max_input_size.map(|max| (modexp, max)))
+                })
+                .modify_tx_chained(|tx| {
+                    tx.caller = Address::with_last_byte(1);
+                    tx.transact_to = TxKind::Call(modexp);
+                    tx.data = input.clone();
+                    tx.gas_limit = 100_000;
+                });
+            let mut evm = MainEvm::new(context, EthHandler::default());
+            evm.transact().unwrap().result
+        };
+
+        assert!(result(None).is_success());
+        assert!(result(Some(200)).is_success());
+        assert!(matches!(
+            result(Some(128)),
+            ExecutionResult::Halt {
+                reason: HaltReason::PrecompileError,
+                ..
+            }
+        ));
+    }
+
+    #[test]
+    fn bn254_pairing_gas_override() {
+        // Two pairings, which check to true.
+        let input = Bytes::from(
+            hex::decode(
+                "1c76476f4def4bb94541d57ebba1193381ffa7aa76ada664dd31c16024c43f59\
+                3034dd2920f673e204fee2811c678745fc819b55d3e9d294e45c9b03a76aef41\
+                209dd15ebff5d46c4bd888e51a93cf99a7329636c63514396b4a452003a35bf7\
+                04bf11ca01483bfa8b34b43561848d28905960114c8ac04049af4b6315a41678\
+                2bb8324af6cfc93537a2ad1a445cfd0ca2a71acd7ac41fadbf933c2a51be344d\
+                120a2a4cf30c1bf9845f20c6fe39e07ea2cce61f0c9bb048165fe5e4de877550\
+                111e129f1cf1097710d41c4ac70fcdfa5ba2023c6ff1cbeac322de49d1b6df7c\
+                2032c61a830e3c17286de9462bf242fca2883585b93870a73853face6a6bf411\
+                198e9393920d483a7260bfb731fb5d25f1aa493335a9e71297e485b7aef312c2\
+                1800deef121f1e76426a00665e5c4479674322d4f75edadd46debd5cd992f6ed\
+                090689d0585ff075ec9e99ad690c3395bc4b313370b38ef355acdadcd122975b\
+                12c85ea5db8c6deb4aab71808dcb408fe3d1e7690c43d37b4ce6cc0166fa7daa",
+            )
+            .unwrap(),
+        );
+        let gas_used = |spec: SpecId, pair_gas: Option<(u64, u64)>| {
+            let context = Context::builder()
+                .with_db(BenchmarkDB::new_bytecode(Bytecode::new()))
+                .modify_cfg_chained(|cfg| {
+                    cfg.spec = spec;
+                    cfg.bn254_pair_base_gas = pair_gas.map(|(base, _)| base);
+                    cfg.bn254_pair_per_point_gas = pair_gas.map(|(_, per_point)| per_point);
+                })
+                .modify_tx_chained(|tx| {
+                    tx.caller = Address::with_last_byte(1);
+                    tx.transact_to = TxKind::Call(bn128::pair::ADDRESS);
+                    tx.data = input.clone();
+                    tx.gas_limit = 1_000_000;
+                });
+            let mut evm = MainEvm::new(context, EthHandler::default());
+            let result = evm.transact().unwrap().result;
+            assert_eq!(
+                result.output(),
+                Some(&Bytes::from(U256::from(1).to_be_bytes_vec()))
+            );
+            result.gas_used()
+        };
+
+        let byzantium_pricing = (
+            bn128::pair::BYZANTIUM_PAIR_BASE,
+            bn128::pair::BYZANTIUM_PAIR_PER_POINT,
+        );
+        let istanbul_pricing = (
+            bn128::pair::ISTANBUL_PAIR_BASE,
+            bn128::pair::ISTANBUL_PAIR_PER_POINT,
+        );
+        // 100_000 + 2 * 80_000 against 45_000 + 2 * 34_000.
+        let difference = 260_000 - 113_000;
+
+        let byzantium = gas_used(SpecId::BYZANTIUM, None);
+        assert_eq!(
+            byzantium,
+            gas_used(SpecId::BYZANTIUM, Some(byzantium_pricing))
+        );
+        assert_eq!(
+            byzantium - gas_used(SpecId::BYZANTIUM, Some(istanbul_pricing)),
+            difference
+        );
+
+        let istanbul = gas_used(SpecId::ISTANBUL, None);
+        assert_eq!(istanbul, gas_used(SpecId::ISTANBUL, Some(istanbul_pricing)));
+        assert_eq!(
+            gas_used(SpecId::ISTANBUL, Some(byzantium_pricing)) - istanbul,
+            difference
+        );
+    }
+
+    #[test]
+    fn retain_empty_accounts_in_committed_state() {
+        let empty = Address::with_last_byte(0xee);
+        let committed = |retain_empty_accounts| {
+            // Empty account with a storage slot, which is only reachable before EIP-161.
+            let mut db = InMemoryDB::default();
+            db.insert_account_storage(empty, U256::from(1), U256::from(7))
+                .unwrap();
+            let context = Context::builder()
+                .with_db(
+                    State::builder()
+                        .with_database(db)
+                        .with_bundle_update()
+                        .build(),
+                )
+                .modify_cfg_chained(|cfg| cfg.retain_empty_accounts = retain_empty_accounts)
+                .modify_tx_chained(|tx| {
+                    tx.caller = Address::with_last_byte(1);
+                    tx.transact_to = TxKind::Call(empty);
+                    tx.gas_limit = 100_000;
+                });
+            let mut evm = MainEvm::new(context, EthHandler::default());
+            let ResultAndState { result, state } = evm.transact().unwrap();
+            assert!(result.is_success());
+            assert!(state[&empty].is_touched());
+            assert!(!state[&empty].is_created());
+            assert_eq!(state[&empty].is_retained_empty(), retain_empty_accounts);
+
+            let db = evm.context.db();
+            db.commit(state);
+            db.merge_transitions(BundleRetention::PlainState);
+            let account = db.bundle_state.account(&empty).unwrap();
+            (account.info.clone(), account.was_destroyed())
+        };
+
+        // Zero value call touches the empty account, which is removed by default.
+        assert_eq!(committed(false), (None, true));
+        let (info, was_destroyed) = committed(true);
+        assert!(info.is_some_and(|info| info.is_empty()));
+        assert!(!was_destroyed);
+    }
+
+    #[test]
+    fn ignore_revert_state_changes_keeps_sstore() {
+        // Stores one in slot zero and reverts.
+        let bytecode = Bytecode::new_raw(Bytes::from(vec![
+            opcode::PUSH1,
+            0x01,
+            opcode::PUSH1,
+            0x00,
+            opcode::SSTORE,
+            opcode::PUSH1,
+            0x00,
+            opcode::PUSH1,
+            0x00,
+            opcode::REVERT,
+        ]));
+        let slot_zero = |ignore_revert_state_changes| {
+            let context = Context::builder()
+                .with_db(BenchmarkDB::new_bytecode(bytecode.clone()))
+                .modify_cfg_chained(|cfg| {
+                    cfg.ignore_revert_state_changes = ignore_revert_state_changes
+                })
+                .modify_tx_chained(|tx| {
+                    tx.caller = Address::with_last_byte(1);
+                    tx.transact_to = TxKind::Call(Address::ZERO);
+                    tx.gas_limit = 100_000;
+                });
+            let mut evm = MainEvm::new(context, EthHandler::default());
+            let output = evm.transact().unwrap();
+            assert!(matches!(output.result, ExecutionResult::Revert { .. }));
+            output.state[&Address::ZERO].storage[&U256::ZERO].present_value
+        };
+
+        assert_eq!(slot_zero(false), U256::ZERO);
+        assert_eq!(slot_zero(true), U256::from(1));
+    }
+
+    #[test]
+    fn log_depths_skip_reverted_frame() {
+        let outer = Address::with_last_byte(0xa0);
+        let middle = Address::with_last_byte(0xb0);
+        let inner = Address::with_last_byte(0xc0);
+        let log0 = [opcode::PUSH1, 0x00, opcode::PUSH1, 0x00, opcode::LOG0];
+        let call = |target| {
+            [
+                opcode::PUSH1,
+                0x00,
+                opcode::PUSH1,
+                0x00,
+                opcode::PUSH1,
+                0x00,
+                opcode::PUSH1,
+                0x00,
+                opcode::PUSH1,
+                0x00,
+                opcode::PUSH1,
+                target,
+                opcode::GAS,
+                opcode::CALL,
+                opcode::POP,
+            ]
+        };
+        // Logs before and after calling the next frame, the innermost one logs and reverts.
+        let outer_code = [&log0[..], &call(0xb0), &log0, &[opcode::STOP]].concat();
+        let middle_code = [&log0[..], &call(0xc0), &log0, &[opcode::STOP]].concat();
+        let inner_code = [
+            &log0[..],
+            &[opcode::PUSH1, 0x00, opcode::PUSH1, 0x00, opcode::REVERT],
+        ]
+        .concat();
+
+        let mut db = InMemoryDB::default();
+        for (address, code) in [
+            (outer, outer_code),
+            (middle, middle_code),
+            (inner, inner_code),
+        ] {
+            let bytecode = Bytecode::new_raw(Bytes::from(code));
+            db.insert_account_info(
+                address,
+                AccountInfo::new(U256::ZERO, 1, bytecode.hash_slow(), bytecode),
+            );
+        }
+        let context = Context::builder().with_db(db).modify_tx_chained(|tx| {
+            tx.caller = Address::with_last_byte(1);
+            tx.transact_to = TxKind::Call(outer);
+            tx.gas_limit = 1_000_000;
+        });
+        let mut evm = MainEvm::new(context, EthHandler::default());
+
+        let result = evm.transact().unwrap().result;
+        assert!(result.is_success());
+        let emitters = result
+            .logs()
+            .iter()
+            .map(|log| log.address)
+            .collect::<Vec<_>>();
+        assert_eq!(emitters, [outer, middle, middle, outer]);
+        assert_eq!(result.log_depths(), [1, 2, 2, 1]);
+    }
+
+    #[test]
+    fn call_with_block_gas_limit_runs_out_at_block_limit() {
+        // Infinite loop.
+        let bytecode = Bytecode::new_raw(Bytes::from(vec![
+            opcode::JUMPDEST,
+            opcode::PUSH1,
+            0x00,
+            opcode::JUMP,
+        ]));
+        let context = Context::builder()
+            .with_db(BenchmarkDB::new_bytecode(bytecode))
+            .modify_block_chained(|block| block.gas_limit = 1_000_000);
+        let mut evm = MainEvm::new(context, EthHandler::default());
+
+        let output = evm
+            .call_with_block_gas_limit(TxEnv {
+                caller: Address::with_last_byte(1),
+                transact_to: TxKind::Call(Address::ZERO),
+                gas_limit: 30_000,
+                ..Default::default()
+            })
+            .unwrap();
+        assert!(matches!(
+            output.result,
+            ExecutionResult::Halt {
+                reason: HaltReason::OutOfGas(_),
+                gas_used: 1_000_000,
+            }
+        ));
+        assert_eq!(evm.context.tx.gas_limit, 1_000_000);
+    }
+
+    #[test]
+    fn call_with_block_gas_limit_from_unfunded_caller() {
+        let contract = Address::with_last_byte(0xcc);
+        let bytecode = Bytecode::new_raw(Bytes::from(vec![opcode::STOP]));
+        let mut db = InMemoryDB::default();
+        db.insert_account_info(
+            contract,
+            AccountInfo::new(U256::ZERO, 1, bytecode.hash_slow(), bytecode),
+        );
+        let context = Context::builder()
+            .with_db(db)
+            .modify_block_chained(|block| {
+                block.gas_limit = 1_000_000;
+                block.basefee = 10;
+            });
+        let mut evm = MainEvm::new(context, EthHandler::default());
+
+        // The caller has no balance, a wrong nonce and a gas price below the base fee.
+        let tx = TxEnv {
+            caller: Address::with_last_byte(1),
+            transact_to: TxKind::Call(contract),
+            nonce: 5,
+            gas_price: U256::from(1),
+            ..Default::default()
+        };
+        evm.context.tx = tx.clone();
+        evm.context.tx.gas_limit = 1_000_000;
+        assert!(evm.transact().is_err());
+
+        let output = evm.call_with_block_gas_limit(tx).unwrap();
+        assert!(output.result.is_success());
+    }
+
+    #[test]
+    fn set_spec_enables_point_evaluation() {
+        let context = Context::builder()
+            .with_db(InMemoryDB::default())
+            .modify_tx_chained(|tx| {
+                tx.caller = Address::with_last_byte(1);
+                tx.transact_to = TxKind::Call(Address::with_last_byte(0x0a));
+                tx.gas_limit = 100_000;
+            });
+        let mut evm = MainEvm::new(context, EthHandler::default()).with_spec(SpecId::LONDON);
+
+        // Before Cancun 0x0a is a plain account without code.
+        let result = evm.transact().unwrap().result;
+        assert!(result.is_success());
+
+        // The precompiles are rebuilt from the spec when the next transaction starts.
+        evm.set_spec(SpecId::CANCUN);
+        let result = evm.transact().unwrap().result;
+        // Empty input is not a valid point evaluation.
+        assert!(matches!(
+            result,
+            ExecutionResult::Halt {
+                reason: HaltReason::PrecompileError,
+                gas_used: 100_000,
+            }
+        ));
+    }
+
+    #[test]
+    fn storage_changes_are_net() {
+        let caller = Address::with_last_byte(1);
+        let contract = Address::with_last_byte(0x11);
+
+        let mut code = Vec::new();
+        // Slot zero goes 5 -> 1 -> 2 -> 0 and slot one goes 0 -> 7 -> 0.
+        for (value, slot) in [(1, 0), (2, 0), (0, 0), (7, 1), (0, 1)] {
+            code.extend([opcode::PUSH1, value, opcode::PUSH1, slot, opcode::SSTORE]);
+        }
+        // Slot two is only read.
+        code.extend([opcode::PUSH1, 2, opcode::SLOAD, opcode::STOP]);
+        let bytecode = Bytecode::new_raw(Bytes::from(code));
+
+        let mut db = InMemoryDB::default();
+        db.insert_account_info(caller, AccountInfo::from_balance(U256::from(10_000_000)));
+        db.insert_account_info(
+            contract,
+            AccountInfo::new(U256::ZERO, 1, bytecode.hash_slow(), bytecode),
+        );
+        db.insert_account_storage(contract, U256::ZERO, U256::from(5))
+            .unwrap();
+        db.insert_account_storage(contract, U256::from(2), U256::from(9))
+            .unwrap();
+
+        let context = Context::builder().with_db(db).modify_tx_chained(|tx| {
+            tx.caller = caller;
+            tx.transact_to = TxKind::Call(contract);
+            tx.gas_limit = 1_000_000;
+        });
+        let mut evm = MainEvm::new(context, EthHandler::default());
+
+        let output = evm.transact().unwrap();
+        assert!(output.result.is_success());
+        assert_eq!(
+            output.storage_changes().into_iter().collect::<Vec<_>>(),
+            vec![((contract, U256::ZERO), (U256::from(5), U256::ZERO))]
+        );
+    }
+
+    #[test]
+    fn post_block_missing_predeploy() {
+        let context = Context::builder()
+            .with_db(InMemoryDB::default())
+            .modify_cfg_chained(|cfg| cfg.spec = SpecId::PRAGUE);
+        let mut evm = MainEvm::new(context, EthHandler::default());
+
+        assert_eq!(
+            evm.apply_post_block(),
+            Err(EVMError::Header(InvalidHeader::SystemCallFailed {
+                address: WITHDRAWAL_REQUEST_PREDEPLOY_ADDRESS
+            }))
+        );
+    }
+
+    #[test]
+    fn custom_opcode_with_gas() {
+        type Ctx = Context<BlockEnv, TxEnv, CfgEnv, InMemoryDB>;
+
+        fn push_constant(interpreter: &mut Interpreter<EthInterpreter>, _context: &mut Ctx) {
+            if !interpreter.stack.push(U256::from(0x2a)) {
+                interpreter
+                    .control
+                    .set_instruction_result(InstructionResult::StackOverflow);
+            }
+        }
+
+        let table = InstructionTableBuilder::new()
+            .with_instruction(0x0c, push_constant, 7)
+            .build();
+        let bytecode =
+            Bytecode::new_raw(Bytes::from(vec![0x0c, opcode::PUSH1, 0x01, opcode::STOP]));
+
+        let mut context: Ctx = Context::builder().with_db(InMemoryDB::default());
+        let mut interpreter = Interpreter::<EthInterpreter>::new(
+            Rc::new(RefCell::new(SharedMemory::new())),
+            bytecode,
+            InputsImpl::default(),
+            false,
+            false,
+            SpecId::LATEST,
+            100,
+        );
+
+        let _ = interpreter.run(&table, &mut context);
+        assert_eq!(
+            interpreter.control.instruction_result(),
+            InstructionResult::Stop
+        );
+        assert_eq!(
+            interpreter.stack.data(),
+            &vec![U256::from(0x2a), U256::from(1)]
+        );
+        // Custom opcode charge plus `PUSH1`.
+        assert_eq!(interpreter.control.gas().spent(), 7 + 3);
+
+        let mut interpreter = Interpreter::<EthInterpreter>::new(
+            Rc::new(RefCell::new(SharedMemory::new())),
+            Bytecode::new_raw(Bytes::from(vec![0x0c])),
+            InputsImpl::default(),
+            false,
+            false,
+            SpecId::LATEST,
+            6,
+        );
+        let _ = interpreter.run(&table, &mut context);
+        assert_eq!(
+            interpreter.control.instruction_result(),
+            InstructionResult::OutOfGas
+        );
+        assert!(interpreter.stack.data().is_empty());
+    }
+}
+
 /*
 
 #[cfg(test)]
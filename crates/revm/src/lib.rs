@@ -20,13 +20,16 @@ pub use state;
 
 // Modules.
 
+mod bundle;
+mod decode;
 mod evm;
-mod exec;
 
 // Export items.
 
+pub use bundle::{AccountOverride, BundleOutput, BundleTx};
 pub use context::journaled_state::{JournalEntry, JournaledState};
 pub use context::Context;
 pub use database_interface::{Database, DatabaseCommit, DatabaseRef};
-pub use evm::{Error, EthContext, Evm, MainEvm};
-pub use exec::{EvmCommit, EvmExec};
+pub use decode::{decode_tx, DecodeError};
+pub use evm::{BlockRequests, Error, EthContext, Evm, MainEvm};
+pub use handler::{EvmCommit, EvmExec};
@@ -2,6 +2,9 @@
 #![cfg_attr(not(test), warn(unused_crate_dependencies))]
 #![cfg_attr(not(feature = "std"), no_std)]
 
+#[cfg(not(feature = "std"))]
+extern crate alloc as std;
+
 mod account_info;
 mod types;
 pub use bytecode;
@@ -9,7 +12,7 @@ pub use bytecode;
 pub use account_info::AccountInfo;
 pub use bytecode::Bytecode;
 pub use primitives;
-pub use types::{EvmState, EvmStorage, TransientStorage};
+pub use types::{ordered_state, EvmState, EvmStorage, OrderedAccount, TransientStorage};
 
 use bitflags::bitflags;
 use core::hash::Hash;
@@ -94,6 +97,11 @@ impl Account {
         self.status |= AccountStatus::Cold;
     }
 
+    /// Marks the account as kept in the state even if it is empty.
+    pub fn mark_retained_empty(&mut self) {
+        self.status |= AccountStatus::RetainedEmpty;
+    }
+
     /// Marks the account as warm and return true if it was previously cold.
     pub fn mark_warm(&mut self) -> bool {
         if self.status.contains(AccountStatus::Cold) {
@@ -117,6 +125,11 @@ impl Account {
         self.status.contains(AccountStatus::Created)
     }
 
+    /// Is account kept in the state when empty, instead of being removed by EIP-161.
+    pub fn is_retained_empty(&self) -> bool {
+        self.status.contains(AccountStatus::RetainedEmpty)
+    }
+
     /// Is account empty, check if nonce and balance are zero and code is empty.
     pub fn is_empty(&self) -> bool {
         self.info.is_empty()
@@ -161,6 +174,9 @@ bitflags! {
         const LoadedAsNotExisting = 0b0001000;
         /// used to mark account as cold
         const Cold = 0b0010000;
+        /// Empty account that is kept in the state instead of being removed by
+        /// EIP-161: State trie clearing. Its storage is not cleared.
+        const RetainedEmpty = 0b0100000;
     }
 }
 
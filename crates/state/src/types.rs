@@ -1,5 +1,6 @@
-use super::{Account, EvmStorageSlot};
+use super::{Account, AccountInfo, AccountStatus, EvmStorageSlot};
 use primitives::{Address, HashMap, U256};
+use std::collections::BTreeMap;
 
 /// EVM State is a mapping from addresses to accounts.
 pub type EvmState = HashMap<Address, Account>;
@@ -9,3 +10,87 @@ pub type TransientStorage = HashMap<(Address, U256), U256>;
 
 /// An account's Storage is a mapping from 256-bit integer keys to [EvmStorageSlot]s.
 pub type EvmStorage = HashMap<U256, EvmStorageSlot>;
+
+/// Borrowed view of an [`Account`] with storage ordered by slot.
+///
+/// See [`ordered_state`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct OrderedAccount<'a> {
+    /// Balance, nonce, and code
+    pub info: &'a AccountInfo,
+    /// Storage ordered by slot
+    pub storage: BTreeMap<U256, &'a EvmStorageSlot>,
+    /// Account status flags
+    pub status: AccountStatus,
+}
+
+impl From<OrderedAccount<'_>> for Account {
+    fn from(account: OrderedAccount<'_>) -> Self {
+        Self {
+            info: account.info.clone(),
+            storage: account
+                .storage
+                .into_iter()
+                .map(|(key, slot)| (key, slot.clone()))
+                .collect(),
+            status: account.status,
+        }
+    }
+}
+
+/// Returns a view of `state` ordered by address, with storage ordered by slot.
+///
+/// Useful for deterministic `Debug` output and serialization, e.g. in snapshot tests.
+pub fn ordered_state(state: &EvmState) -> BTreeMap<Address, OrderedAccount<'_>> {
+    state
+        .iter()
+        .map(|(address, account)| {
+            let ordered = OrderedAccount {
+                info: &account.info,
+                storage: account.storage.iter().map(|(k, v)| (*k, v)).collect(),
+                status: account.status,
+            };
+            (*address, ordered)
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn ordered_state_sorted_and_round_trips() {
+        let mut state = EvmState::default();
+        for byte in [0x30, 0x10, 0x20] {
+            let mut account = Account::default();
+            account.info.nonce = byte as u64;
+            for slot in [3u64, 1, 2] {
+                account.storage.insert(
+                    U256::from(slot),
+                    EvmStorageSlot::new(U256::from(slot * byte as u64)),
+                );
+            }
+            account.mark_touch();
+            state.insert(Address::with_last_byte(byte), account);
+        }
+
+        let ordered = ordered_state(&state);
+        let addresses: Vec<_> = ordered.keys().copied().collect();
+        assert_eq!(
+            addresses,
+            [0x10, 0x20, 0x30].map(Address::with_last_byte).to_vec()
+        );
+        for account in ordered.values() {
+            let slots: Vec<_> = account.storage.keys().copied().collect();
+            assert_eq!(slots, [1u64, 2, 3].map(U256::from).to_vec());
+        }
+
+        let round_trip: EvmState = ordered
+            .into_iter()
+            .map(|(address, account)| (address, account.into()))
+            .collect();
+        assert_eq!(round_trip, state);
+    }
+}
@@ -9,7 +9,10 @@ use alloy_provider::{
 };
 use database::{AlloyDB, CacheDB, StateBuilder};
 use indicatif::ProgressBar;
-use inspector::{inspectors::TracerEip3155, InspectorContext, InspectorEthFrame, InspectorMainEvm};
+use inspector::{
+    inspectors::TracerEip3155, InspectorContext, InspectorEthFrame, InspectorExecution,
+    InspectorMainEvm,
+};
 use revm::{
     database_interface::WrapDatabaseAsync,
     handler::{
@@ -101,7 +104,11 @@ async fn main() -> anyhow::Result<()> {
         EthHandler::new(
             EthValidation::new(),
             EthPreExecution::new(),
-            EthExecution::<_, _, InspectorEthFrame<_, _, EthPrecompileProvider<_, _>>>::new(),
+            InspectorExecution::new(EthExecution::<
+                _,
+                _,
+                InspectorEthFrame<_, _, EthPrecompileProvider<_, _>>,
+            >::new()),
             EthPostExecution::new(),
         ),
     );